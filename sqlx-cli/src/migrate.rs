@@ -111,10 +111,10 @@ pub async fn info(migration_source: &str, uri: &str) -> anyhow::Result<()> {
     let migrator = Migrator::new(Path::new(migration_source)).await?;
     let mut conn = AnyConnection::connect(uri).await?;
 
-    conn.ensure_migrations_table().await?;
+    conn.ensure_migrations_table(&migrator.table_name).await?;
 
     let applied_migrations: HashMap<_, _> = conn
-        .list_applied_migrations()
+        .list_applied_migrations(&migrator.table_name)
         .await?
         .into_iter()
         .map(|m| (m.version, m))
@@ -165,14 +165,14 @@ pub async fn run(
     let migrator = Migrator::new(Path::new(migration_source)).await?;
     let mut conn = AnyConnection::connect(uri).await?;
 
-    conn.ensure_migrations_table().await?;
+    conn.ensure_migrations_table(&migrator.table_name).await?;
 
-    let version = conn.dirty_version().await?;
+    let version = conn.dirty_version(&migrator.table_name).await?;
     if let Some(version) = version {
         bail!(MigrateError::Dirty(version));
     }
 
-    let applied_migrations = conn.list_applied_migrations().await?;
+    let applied_migrations = conn.list_applied_migrations(&migrator.table_name).await?;
     validate_applied_migrations(&applied_migrations, &migrator, ignore_missing)?;
 
     let applied_migrations: HashMap<_, _> = applied_migrations
@@ -196,7 +196,7 @@ pub async fn run(
                 let elapsed = if dry_run {
                     Duration::new(0, 0)
                 } else {
-                    conn.apply(migration).await?
+                    conn.apply(&migrator.table_name, migration).await?
                 };
                 let text = if dry_run { "Can apply" } else { "Applied" };
 
@@ -224,14 +224,14 @@ pub async fn revert(
     let migrator = Migrator::new(Path::new(migration_source)).await?;
     let mut conn = AnyConnection::connect(uri).await?;
 
-    conn.ensure_migrations_table().await?;
+    conn.ensure_migrations_table(&migrator.table_name).await?;
 
-    let version = conn.dirty_version().await?;
+    let version = conn.dirty_version(&migrator.table_name).await?;
     if let Some(version) = version {
         bail!(MigrateError::Dirty(version));
     }
 
-    let applied_migrations = conn.list_applied_migrations().await?;
+    let applied_migrations = conn.list_applied_migrations(&migrator.table_name).await?;
     validate_applied_migrations(&applied_migrations, &migrator, ignore_missing)?;
 
     let applied_migrations: HashMap<_, _> = applied_migrations
@@ -251,7 +251,7 @@ pub async fn revert(
             let elapsed = if dry_run {
                 Duration::new(0, 0)
             } else {
-                conn.revert(migration).await?
+                conn.revert(&migrator.table_name, migration).await?
             };
             let text = if dry_run { "Can apply" } else { "Applied" };
 
@@ -33,20 +33,24 @@ pub fn run(url: &str, merge: bool, cargo_args: Vec<String>) -> anyhow::Result<()
         );
     }
 
+    let file_name = data_file_name(db_kind, read_db_kind("sqlx-data.json".as_ref()).as_deref());
+
     serde_json::to_writer_pretty(
         BufWriter::new(
-            File::create("sqlx-data.json").context("failed to create/open `sqlx-data.json`")?,
+            File::create(&file_name)
+                .with_context(|| format!("failed to create/open `{}`", file_name))?,
         ),
         &DataFile {
             db: db_kind.to_owned(),
             data,
         },
     )
-    .context("failed to write to `sqlx-data.json`")?;
+    .with_context(|| format!("failed to write to `{}`", file_name))?;
 
     println!(
-        "query data written to `sqlx-data.json` in the current directory; \
-         please check this into version control"
+        "query data written to `{}` in the current directory; \
+         please check this into version control",
+        file_name
     );
 
     Ok(())
@@ -56,9 +60,14 @@ pub fn check(url: &str, merge: bool, cargo_args: Vec<String>) -> anyhow::Result<
     let db_kind = get_db_kind(url)?;
     let data = run_prepare_step(merge, cargo_args)?;
 
-    let data_file = File::open("sqlx-data.json").context(
-        "failed to open `sqlx-data.json`; you may need to run `cargo sqlx prepare` first",
-    )?;
+    let file_name = data_file_name(db_kind, read_db_kind("sqlx-data.json".as_ref()).as_deref());
+
+    let data_file = File::open(&file_name).with_context(|| {
+        format!(
+            "failed to open `{}`; you may need to run `cargo sqlx prepare` first",
+            file_name
+        )
+    })?;
 
     let DataFile {
         db: expected_db,
@@ -167,7 +176,7 @@ hint: This command only works in the manifest directory of a Cargo package."#
             .context("expected key `hash` in query data")?;
 
         if let serde_json::Value::String(hash) = hash {
-            data.insert(hash, serde_json::Value::Object(query_data));
+            merge_query_data(&mut data, hash, query_data)?;
         } else {
             bail!(
                 "expected key `hash` in query data to be string, was {:?} instead; file: {}",
@@ -183,6 +192,63 @@ hint: This command only works in the manifest directory of a Cargo package."#
     Ok(data)
 }
 
+/// The file the query data for `db_kind` is kept in.
+///
+/// Queries prepared against a second backend go to a backend-specific file, e.g.
+/// `sqlx-data-sqlite.json`, so that two databases in one workspace do not clobber
+/// each other's caches; the query macros check for a file matching the backend of
+/// `DATABASE_URL` before falling back to `sqlx-data.json`.
+fn data_file_name(db_kind: &str, existing_db: Option<&str>) -> String {
+    match existing_db {
+        Some(existing) if existing != db_kind => {
+            format!("sqlx-data-{}.json", db_kind.to_lowercase())
+        }
+        _ => "sqlx-data.json".to_owned(),
+    }
+}
+
+/// Read which backend an existing data file was prepared against, if it exists.
+fn read_db_kind(path: &Path) -> Option<String> {
+    #[derive(Deserialize)]
+    struct DbOnly {
+        db: String,
+    }
+
+    let file = File::open(path).ok()?;
+
+    serde_json::from_reader::<_, DbOnly>(BufReader::new(file))
+        .ok()
+        .map(|data| data.db)
+}
+
+/// Merge a single query's data into the combined map.
+///
+/// The same query may legitimately appear in several crates of a workspace and dedupes
+/// cleanly; two *different* entries under one hash mean a hash collision (or corrupted
+/// query data) and would otherwise silently drop one of the queries.
+fn merge_query_data(
+    data: &mut QueryData,
+    hash: String,
+    query_data: JsonObject,
+) -> anyhow::Result<()> {
+    let query_data = serde_json::Value::Object(query_data);
+
+    if let Some(existing) = data.get(&hash) {
+        if existing != &query_data {
+            bail!(
+                "hash collision in query data for hash {}: {:?} vs {:?}",
+                hash,
+                existing.get("query"),
+                query_data.get("query"),
+            );
+        }
+    } else {
+        data.insert(hash, query_data);
+    }
+
+    Ok(())
+}
+
 fn get_db_kind(url: &str) -> anyhow::Result<&'static str> {
     let options = AnyConnectOptions::from_str(&url)?;
 
@@ -242,6 +308,58 @@ mod tests {
         assert_eq!(data.get("z"), Some(&json!({"key2": "value2"})));
     }
 
+    #[test]
+    fn data_files_are_separated_by_backend() {
+        assert_eq!(data_file_name("SQLite", None), "sqlx-data.json");
+        assert_eq!(data_file_name("SQLite", Some("SQLite")), "sqlx-data.json");
+        assert_eq!(
+            data_file_name("SQLite", Some("PostgreSQL")),
+            "sqlx-data-sqlite.json"
+        );
+        assert_eq!(
+            data_file_name("PostgreSQL", Some("SQLite")),
+            "sqlx-data-postgresql.json"
+        );
+    }
+
+    #[test]
+    fn merge_dedupes_identical_queries() {
+        let entry = |query: &str| {
+            if let serde_json::Value::Object(map) = json!({ "query": query }) {
+                map
+            } else {
+                unreachable!()
+            }
+        };
+
+        let mut data = BTreeMap::new();
+
+        merge_query_data(&mut data, "a".to_owned(), entry("SELECT 1")).unwrap();
+        merge_query_data(&mut data, "a".to_owned(), entry("SELECT 1")).unwrap();
+        merge_query_data(&mut data, "b".to_owned(), entry("SELECT 2")).unwrap();
+
+        assert_eq!(data.len(), 2);
+    }
+
+    #[test]
+    fn merge_reports_hash_collisions() {
+        let entry = |query: &str| {
+            if let serde_json::Value::Object(map) = json!({ "query": query }) {
+                map
+            } else {
+                unreachable!()
+            }
+        };
+
+        let mut data = BTreeMap::new();
+
+        merge_query_data(&mut data, "a".to_owned(), entry("SELECT 1")).unwrap();
+
+        let err = merge_query_data(&mut data, "a".to_owned(), entry("SELECT 2")).unwrap_err();
+
+        assert!(err.to_string().contains("hash collision"));
+    }
+
     #[test]
     fn data_file_deserialization_works_for_ordered_keys() {
         let data_file =
@@ -159,6 +159,7 @@ where
     Describe {
         parameters,
         nullable: info.nullable,
+        read_only: info.read_only,
         columns: info.columns.into_iter().map(Into::into).collect(),
     }
 }
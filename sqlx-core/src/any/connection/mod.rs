@@ -3,6 +3,7 @@ use futures_core::future::BoxFuture;
 use crate::any::{Any, AnyConnectOptions, AnyKind};
 use crate::connection::Connection;
 use crate::error::Error;
+use crate::metrics::ConnectionMetrics;
 
 #[cfg(feature = "postgres")]
 use crate::postgres;
@@ -69,6 +70,14 @@ impl AnyConnection {
     pub fn kind(&self) -> AnyKind {
         self.0.kind()
     }
+
+    /// Returns the name of the database backend in use, e.g. "SQLite".
+    ///
+    /// This lets generic code pick the right SQL dialect without re-parsing
+    /// the connection URL.
+    pub fn backend_name(&self) -> &'static str {
+        self.kind().backend_name()
+    }
 }
 
 macro_rules! delegate_to {
@@ -173,6 +182,26 @@ impl Connection for AnyConnection {
         }
     }
 
+    fn metrics(&self) -> ConnectionMetrics {
+        delegate_to!(self.metrics())
+    }
+
+    fn shrink_buffers(&mut self) {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.shrink_buffers(),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.shrink_buffers(),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn.shrink_buffers(),
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(conn) => conn.shrink_buffers(),
+        }
+    }
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         delegate_to_mut!(self.flush())
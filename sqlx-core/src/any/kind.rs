@@ -16,6 +16,25 @@ pub enum AnyKind {
     Mssql,
 }
 
+impl AnyKind {
+    /// Returns the human-readable name of the database backend, e.g. "PostgreSQL".
+    pub fn backend_name(&self) -> &'static str {
+        match self {
+            #[cfg(feature = "postgres")]
+            AnyKind::Postgres => "PostgreSQL",
+
+            #[cfg(feature = "mysql")]
+            AnyKind::MySql => "MySQL",
+
+            #[cfg(feature = "sqlite")]
+            AnyKind::Sqlite => "SQLite",
+
+            #[cfg(feature = "mssql")]
+            AnyKind::Mssql => "MSSQL",
+        }
+    }
+}
+
 impl FromStr for AnyKind {
     type Err = Error;
 
@@ -2,7 +2,9 @@ use crate::any::connection::AnyConnectionKind;
 use crate::any::kind::AnyKind;
 use crate::any::{Any, AnyConnection};
 use crate::error::Error;
-use crate::migrate::{AppliedMigration, Migrate, MigrateDatabase, MigrateError, Migration};
+use crate::migrate::{
+    AppliedMigration, AppliedMigrationDetail, Migrate, MigrateDatabase, MigrateError, Migration,
+};
 use futures_core::future::BoxFuture;
 use std::str::FromStr;
 use std::time::Duration;
@@ -64,16 +66,19 @@ impl MigrateDatabase for Any {
 }
 
 impl Migrate for AnyConnection {
-    fn ensure_migrations_table(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
+    fn ensure_migrations_table<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
         match &mut self.0 {
             #[cfg(feature = "postgres")]
-            AnyConnectionKind::Postgres(conn) => conn.ensure_migrations_table(),
+            AnyConnectionKind::Postgres(conn) => conn.ensure_migrations_table(table_name),
 
             #[cfg(feature = "sqlite")]
-            AnyConnectionKind::Sqlite(conn) => conn.ensure_migrations_table(),
+            AnyConnectionKind::Sqlite(conn) => conn.ensure_migrations_table(table_name),
 
             #[cfg(feature = "mysql")]
-            AnyConnectionKind::MySql(conn) => conn.ensure_migrations_table(),
+            AnyConnectionKind::MySql(conn) => conn.ensure_migrations_table(table_name),
 
             #[cfg(feature = "mssql")]
             AnyConnectionKind::Mssql(_conn) => unimplemented!(),
@@ -97,16 +102,19 @@ impl Migrate for AnyConnection {
         }
     }
 
-    fn dirty_version(&mut self) -> BoxFuture<'_, Result<Option<i64>, MigrateError>> {
+    fn dirty_version<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+    ) -> BoxFuture<'m, Result<Option<i64>, MigrateError>> {
         match &mut self.0 {
             #[cfg(feature = "postgres")]
-            AnyConnectionKind::Postgres(conn) => conn.dirty_version(),
+            AnyConnectionKind::Postgres(conn) => conn.dirty_version(table_name),
 
             #[cfg(feature = "sqlite")]
-            AnyConnectionKind::Sqlite(conn) => conn.dirty_version(),
+            AnyConnectionKind::Sqlite(conn) => conn.dirty_version(table_name),
 
             #[cfg(feature = "mysql")]
-            AnyConnectionKind::MySql(conn) => conn.dirty_version(),
+            AnyConnectionKind::MySql(conn) => conn.dirty_version(table_name),
 
             #[cfg(feature = "mssql")]
             AnyConnectionKind::Mssql(_conn) => unimplemented!(),
@@ -136,18 +144,38 @@ impl Migrate for AnyConnection {
         }
     }
 
-    fn list_applied_migrations(
-        &mut self,
-    ) -> BoxFuture<'_, Result<Vec<AppliedMigration>, MigrateError>> {
+    fn list_applied_migrations<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+    ) -> BoxFuture<'m, Result<Vec<AppliedMigration>, MigrateError>> {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.list_applied_migrations(table_name),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn.list_applied_migrations(table_name),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.list_applied_migrations(table_name),
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(_conn) => unimplemented!(),
+        }
+    }
+
+    fn list_applied_migrations_detailed<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+    ) -> BoxFuture<'m, Result<Vec<AppliedMigrationDetail>, MigrateError>> {
         match &mut self.0 {
             #[cfg(feature = "postgres")]
-            AnyConnectionKind::Postgres(conn) => conn.list_applied_migrations(),
+            AnyConnectionKind::Postgres(conn) => conn.list_applied_migrations_detailed(table_name),
 
             #[cfg(feature = "sqlite")]
-            AnyConnectionKind::Sqlite(conn) => conn.list_applied_migrations(),
+            AnyConnectionKind::Sqlite(conn) => conn.list_applied_migrations_detailed(table_name),
 
             #[cfg(feature = "mysql")]
-            AnyConnectionKind::MySql(conn) => conn.list_applied_migrations(),
+            AnyConnectionKind::MySql(conn) => conn.list_applied_migrations_detailed(table_name),
 
             #[cfg(feature = "mssql")]
             AnyConnectionKind::Mssql(_conn) => unimplemented!(),
@@ -170,6 +198,25 @@ impl Migrate for AnyConnection {
         }
     }
 
+    fn lock_with_timeout(
+        &mut self,
+        timeout: Option<std::time::Duration>,
+    ) -> BoxFuture<'_, Result<(), MigrateError>> {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.lock_with_timeout(timeout),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn.lock_with_timeout(timeout),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.lock_with_timeout(timeout),
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(_conn) => unimplemented!(),
+        }
+    }
+
     fn unlock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
         match &mut self.0 {
             #[cfg(feature = "postgres")]
@@ -188,17 +235,87 @@ impl Migrate for AnyConnection {
 
     fn apply<'e: 'm, 'm>(
         &'e mut self,
+        table_name: &'m str,
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
         match &mut self.0 {
             #[cfg(feature = "postgres")]
-            AnyConnectionKind::Postgres(conn) => conn.apply(migration),
+            AnyConnectionKind::Postgres(conn) => conn.apply(table_name, migration),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn.apply(table_name, migration),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.apply(table_name, migration),
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(_conn) => {
+                let _ = migration;
+                unimplemented!()
+            }
+        }
+    }
+
+    fn mark_applied<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.mark_applied(table_name, migration),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn.mark_applied(table_name, migration),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.mark_applied(table_name, migration),
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(_conn) => {
+                let _ = migration;
+                unimplemented!()
+            }
+        }
+    }
+
+    fn reapply<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.reapply(table_name, migration),
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectionKind::Sqlite(conn) => conn.reapply(table_name, migration),
+
+            #[cfg(feature = "mysql")]
+            AnyConnectionKind::MySql(conn) => conn.reapply(table_name, migration),
+
+            #[cfg(feature = "mssql")]
+            AnyConnectionKind::Mssql(_conn) => {
+                let _ = migration;
+                unimplemented!()
+            }
+        }
+    }
+
+    fn update_checksum<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectionKind::Postgres(conn) => conn.update_checksum(table_name, migration),
 
             #[cfg(feature = "sqlite")]
-            AnyConnectionKind::Sqlite(conn) => conn.apply(migration),
+            AnyConnectionKind::Sqlite(conn) => conn.update_checksum(table_name, migration),
 
             #[cfg(feature = "mysql")]
-            AnyConnectionKind::MySql(conn) => conn.apply(migration),
+            AnyConnectionKind::MySql(conn) => conn.update_checksum(table_name, migration),
 
             #[cfg(feature = "mssql")]
             AnyConnectionKind::Mssql(_conn) => {
@@ -210,17 +327,18 @@ impl Migrate for AnyConnection {
 
     fn revert<'e: 'm, 'm>(
         &'e mut self,
+        table_name: &'m str,
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
         match &mut self.0 {
             #[cfg(feature = "postgres")]
-            AnyConnectionKind::Postgres(conn) => conn.revert(migration),
+            AnyConnectionKind::Postgres(conn) => conn.revert(table_name, migration),
 
             #[cfg(feature = "sqlite")]
-            AnyConnectionKind::Sqlite(conn) => conn.revert(migration),
+            AnyConnectionKind::Sqlite(conn) => conn.revert(table_name, migration),
 
             #[cfg(feature = "mysql")]
-            AnyConnectionKind::MySql(conn) => conn.revert(migration),
+            AnyConnectionKind::MySql(conn) => conn.revert(table_name, migration),
 
             #[cfg(feature = "mssql")]
             AnyConnectionKind::Mssql(_conn) => {
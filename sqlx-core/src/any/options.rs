@@ -172,4 +172,57 @@ impl ConnectOptions for AnyConnectOptions {
         };
         self
     }
+
+    fn on_slow_statement(
+        &mut self,
+        callback: impl Fn(&str, Duration, usize) + Send + Sync + 'static,
+    ) -> &mut Self {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectOptionsKind::Postgres(o) => {
+                o.on_slow_statement(callback);
+            }
+
+            #[cfg(feature = "mysql")]
+            AnyConnectOptionsKind::MySql(o) => {
+                o.on_slow_statement(callback);
+            }
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectOptionsKind::Sqlite(o) => {
+                o.on_slow_statement(callback);
+            }
+
+            #[cfg(feature = "mssql")]
+            AnyConnectOptionsKind::Mssql(o) => {
+                o.on_slow_statement(callback);
+            }
+        };
+        self
+    }
+
+    fn redact_parameters(&mut self, redact: bool) -> &mut Self {
+        match &mut self.0 {
+            #[cfg(feature = "postgres")]
+            AnyConnectOptionsKind::Postgres(o) => {
+                o.redact_parameters(redact);
+            }
+
+            #[cfg(feature = "mysql")]
+            AnyConnectOptionsKind::MySql(o) => {
+                o.redact_parameters(redact);
+            }
+
+            #[cfg(feature = "sqlite")]
+            AnyConnectOptionsKind::Sqlite(o) => {
+                o.redact_parameters(redact);
+            }
+
+            #[cfg(feature = "mssql")]
+            AnyConnectOptionsKind::Mssql(o) => {
+                o.redact_parameters(redact);
+            }
+        };
+        self
+    }
 }
@@ -10,6 +10,9 @@ use std::sync::Arc;
 
 pub struct AnyStatement<'q> {
     pub(crate) sql: Cow<'q, str>,
+    // `Left` carries the backend's parameter type information mapped through [`AnyTypeInfo`]
+    // (Postgres); backends that cannot report parameter types (MySQL, SQLite) surface
+    // `Right` with only the parameter count.
     pub(crate) parameters: Option<Either<Vec<AnyTypeInfo>, usize>>,
     pub(crate) column_names: Arc<HashMap<UStr, usize>>,
     pub(crate) columns: Vec<AnyColumn>,
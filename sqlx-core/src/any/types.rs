@@ -189,3 +189,31 @@ impl_any_decode!(chrono::DateTime<chrono::offset::Utc>);
     not(feature = "mssql")
 ))]
 impl_any_decode!(chrono::DateTime<chrono::offset::Local>);
+
+// Conversions for Numeric SQL types
+// MSSQL has no `Decimal` support; elsewhere a NUMERIC/DECIMAL column (or its text
+// serialization, for SQLite) decodes losslessly.
+
+// Type
+#[cfg(all(
+    feature = "decimal",
+    any(feature = "mysql", feature = "sqlite", feature = "postgres"),
+    not(feature = "mssql")
+))]
+impl_any_type!(rust_decimal::Decimal);
+
+// Encode
+#[cfg(all(
+    feature = "decimal",
+    any(feature = "mysql", feature = "sqlite", feature = "postgres"),
+    not(feature = "mssql")
+))]
+impl_any_encode!(rust_decimal::Decimal);
+
+// Decode
+#[cfg(all(
+    feature = "decimal",
+    any(feature = "mysql", feature = "sqlite", feature = "postgres"),
+    not(feature = "mssql")
+))]
+impl_any_decode!(rust_decimal::Decimal);
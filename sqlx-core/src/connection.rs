@@ -1,10 +1,15 @@
+use crate::common::DebugFn;
 use crate::database::{Database, HasStatementCache};
 use crate::error::Error;
+use crate::executor::{Execute, Executor};
+use crate::metrics::ConnectionMetrics;
 use crate::transaction::Transaction;
 use futures_core::future::BoxFuture;
+use futures_core::stream::BoxStream;
 use log::LevelFilter;
 use std::fmt::Debug;
 use std::str::FromStr;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Represents a single database connection.
@@ -79,6 +84,59 @@ pub trait Connection: Send {
         })
     }
 
+    /// Execute each query in sequence on this connection, returning a stream of
+    /// their results.
+    ///
+    /// Unlike [`Executor::execute_many`], which splits a single SQL string into its
+    /// component statements, every item here is a full [`Execute`] and may carry its
+    /// own bound arguments.
+    ///
+    /// The stream ends at the first error; the position of the failing query in the
+    /// batch is the number of results yielded before it.
+    fn execute_batch<'c, 'q: 'c, E, I>(
+        &'c mut self,
+        queries: I,
+    ) -> BoxStream<'c, Result<<Self::Database as Database>::QueryResult, Error>>
+    where
+        Self: Sized,
+        I: IntoIterator<Item = E> + Send + 'c,
+        I::IntoIter: Send,
+        E: Execute<'q, Self::Database> + Send + 'q,
+        for<'e> &'e mut Self: Executor<'e, Database = Self::Database>,
+    {
+        Box::pin(try_stream! {
+            for query in queries {
+                r#yield!((&mut *self).execute(query).await?);
+            }
+
+            Ok(())
+        })
+    }
+
+    /// Prepare each statement in order, storing it in the connection's statement cache.
+    ///
+    /// This can be used at startup to warm the cache with a known set of queries,
+    /// amortizing the parse/plan cost before traffic arrives. Preparation stops at the
+    /// first failure; the offending statement is logged and its error returned.
+    fn prepare_all<'c>(&'c mut self, statements: &'c [&'c str]) -> BoxFuture<'c, Result<(), Error>>
+    where
+        Self: Sized,
+        Self::Database: HasStatementCache,
+        for<'e> &'e mut Self: Executor<'e, Database = Self::Database>,
+    {
+        Box::pin(async move {
+            for sql in statements {
+                if let Err(error) = (&mut *self).prepare(sql).await {
+                    log::error!("failed to prepare statement {:?}: {}", sql, error);
+
+                    return Err(error);
+                }
+            }
+
+            Ok(())
+        })
+    }
+
     /// The number of statements currently cached in the connection.
     fn cached_statements_size(&self) -> usize
     where
@@ -96,6 +154,31 @@ pub trait Connection: Send {
         Box::pin(async move { Ok(()) })
     }
 
+    /// Release excess memory held by this connection's internal read and write buffers.
+    ///
+    /// A connection's buffers stay as large as the biggest query they have served, which
+    /// wastes memory on a connection that is mostly idle. Shrinking trades a bit of
+    /// reallocation on the next large query for a lower idle footprint.
+    ///
+    /// See also [`PoolOptions::shrink_buffers_on_release`][crate::pool::PoolOptions::shrink_buffers_on_release].
+    ///
+    /// This is a no-op for drivers without network buffers (e.g. SQLite).
+    fn shrink_buffers(&mut self) {}
+
+    /// Returns cumulative activity counters for this connection: the number of
+    /// queries executed, the total time spent executing them, and the time the
+    /// most recent query finished.
+    ///
+    /// The returned handle shares the live counters and can be held while the
+    /// connection continues to be used; see [`ConnectionMetrics`].
+    ///
+    /// The built-in drivers all record metrics; the default implementation
+    /// returns a detached handle whose counters stay at zero, so that external
+    /// implementors of this trait are unaffected.
+    fn metrics(&self) -> ConnectionMetrics {
+        ConnectionMetrics::default()
+    }
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>>;
 
@@ -125,11 +208,16 @@ pub trait Connection: Send {
     }
 }
 
+pub(crate) type SlowStatementCallback =
+    Arc<DebugFn<dyn Fn(&str, Duration, usize) + Send + Sync + 'static>>;
+
 #[derive(Clone, Debug)]
 pub(crate) struct LogSettings {
     pub(crate) statements_level: LevelFilter,
     pub(crate) slow_statements_level: LevelFilter,
     pub(crate) slow_statements_duration: Duration,
+    pub(crate) slow_statements_callback: Option<SlowStatementCallback>,
+    pub(crate) redact_parameters: bool,
 }
 
 impl Default for LogSettings {
@@ -138,6 +226,8 @@ impl Default for LogSettings {
             statements_level: LevelFilter::Info,
             slow_statements_level: LevelFilter::Warn,
             slow_statements_duration: Duration::from_secs(1),
+            slow_statements_callback: None,
+            redact_parameters: true,
         }
     }
 }
@@ -150,6 +240,17 @@ impl LogSettings {
         self.slow_statements_level = level;
         self.slow_statements_duration = duration;
     }
+
+    pub(crate) fn on_slow_statement(
+        &mut self,
+        callback: impl Fn(&str, Duration, usize) + Send + Sync + 'static,
+    ) {
+        self.slow_statements_callback = Some(Arc::new(DebugFn(callback)));
+    }
+
+    pub(crate) fn redact_parameters(&mut self, redact: bool) {
+        self.redact_parameters = redact;
+    }
 }
 
 pub trait ConnectOptions: 'static + Send + Sync + FromStr<Err = Error> + Debug {
@@ -167,6 +268,21 @@ pub trait ConnectOptions: 'static + Send + Sync + FromStr<Err = Error> + Debug {
     /// at the specified `level`.
     fn log_slow_statements(&mut self, level: LevelFilter, duration: Duration) -> &mut Self;
 
+    /// Register a callback invoked with the SQL, the elapsed time, and the number
+    /// of rows returned whenever an executed statement takes longer than the
+    /// `duration` set by [`log_slow_statements`][Self::log_slow_statements].
+    fn on_slow_statement(
+        &mut self,
+        callback: impl Fn(&str, Duration, usize) + Send + Sync + 'static,
+    ) -> &mut Self;
+
+    /// Sets whether bound parameter values are redacted from statement logs.
+    ///
+    /// Defaults to `true`; logged statements only show placeholders so that
+    /// sensitive values cannot leak into logs. Not every driver is able to
+    /// render its bound values.
+    fn redact_parameters(&mut self, redact: bool) -> &mut Self;
+
     /// Entirely disables statement logging (both slow and regular).
     fn disable_statement_logging(&mut self) -> &mut Self {
         self.log_statements(LevelFilter::Off)
@@ -98,6 +98,17 @@ pub trait Database:
     /// The concrete type used to hold an owned copy of the not-yet-decoded value that was
     /// received from the database.
     type Value: Value<Database = Self> + 'static;
+
+    /// Write the placeholder for the bind parameter at the 1-based `index`
+    /// into `sql`, in this database's SQL dialect.
+    ///
+    /// Most databases use `?` regardless of position; drivers with positional
+    /// placeholders (e.g., `$1 .. $N` for PostgreSQL) override this.
+    fn fmt_placeholder(sql: &mut String, index: usize) {
+        let _ = index;
+
+        sql.push('?');
+    }
 }
 
 /// Associate [`Database`] with a [`ValueRef`](crate::value::ValueRef) of a generic lifetime.
@@ -22,6 +22,22 @@ pub struct Describe<DB: Database> {
     pub(crate) columns: Vec<DB::Column>,
     pub(crate) parameters: Option<Either<Vec<DB::TypeInfo>, usize>>,
     pub(crate) nullable: Vec<Option<bool>>,
+    #[cfg_attr(feature = "offline", serde(default))]
+    pub(crate) read_only: Option<bool>,
+}
+
+impl<DB: Database> Clone for Describe<DB>
+where
+    DB::Column: Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            columns: self.columns.clone(),
+            parameters: self.parameters.clone(),
+            nullable: self.nullable.clone(),
+            read_only: self.read_only,
+        }
+    }
 }
 
 impl<DB: Database> Describe<DB> {
@@ -53,4 +69,13 @@ impl<DB: Database> Describe<DB> {
     pub fn nullable(&self, column: usize) -> Option<bool> {
         self.nullable.get(column).copied().and_then(identity)
     }
+
+    /// Gets whether this statement only reads from the database, if this information
+    /// is available.
+    ///
+    /// **SQLite** reports this via `sqlite3_stmt_readonly`; other drivers currently
+    /// return `None`.
+    pub fn read_only(&self) -> Option<bool> {
+        self.read_only
+    }
 }
@@ -96,6 +96,12 @@ pub enum Error {
     #[error("attempted to acquire a connection on a closed pool")]
     PoolClosed,
 
+    /// [`Pool::begin_drain`] was called and the pool is no longer accepting new acquisitions.
+    ///
+    /// [`Pool::begin_drain`]: crate::pool::Pool::begin_drain
+    #[error("attempted to acquire a connection on a draining pool")]
+    PoolDraining,
+
     /// A background worker has crashed.
     #[error("attempted to communicate with a crashed background worker")]
     WorkerCrashed,
@@ -173,6 +179,27 @@ pub trait DatabaseError: 'static + Send + Sync + StdError {
     fn constraint(&self) -> Option<&str> {
         None
     }
+
+    /// Returns `true` if the error was caused by a violation of a `UNIQUE`
+    /// (or `PRIMARY KEY`) constraint.
+    fn is_unique_violation(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if the error was caused by a violation of a `FOREIGN KEY` constraint.
+    fn is_foreign_key_violation(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if the error was caused by a violation of a `CHECK` constraint.
+    fn is_check_violation(&self) -> bool {
+        false
+    }
+
+    /// Returns `true` if the error was caused by a violation of a `NOT NULL` constraint.
+    fn is_not_null_violation(&self) -> bool {
+        false
+    }
 }
 
 impl dyn DatabaseError {
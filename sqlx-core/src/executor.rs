@@ -6,6 +6,7 @@ use futures_core::future::BoxFuture;
 use futures_core::stream::BoxStream;
 use futures_util::{future, FutureExt, StreamExt, TryFutureExt, TryStreamExt};
 use std::fmt::Debug;
+use std::mem;
 
 /// A type that contains or can provide a database
 /// connection to use for executing queries against the database.
@@ -91,6 +92,44 @@ pub trait Executor<'c>: Send + Debug + Sized {
         'c: 'e,
         E: Execute<'q, Self::Database>;
 
+    /// Execute the query and return the generated results as a stream of [`Vec`] chunks,
+    /// each containing at most `chunk_size` rows.
+    ///
+    /// This allows processing a large result set in bounded memory, unlike
+    /// [`fetch_all`][Self::fetch_all] which materializes every row at once. The final
+    /// chunk may contain fewer than `chunk_size` rows. A `chunk_size` of zero is
+    /// treated as one.
+    fn fetch_chunked<'e, 'q: 'e, E: 'q>(
+        self,
+        query: E,
+        chunk_size: usize,
+    ) -> BoxStream<'e, Result<Vec<<Self::Database as Database>::Row>, Error>>
+    where
+        'c: 'e,
+        E: Execute<'q, Self::Database>,
+    {
+        let chunk_size = std::cmp::max(chunk_size, 1);
+        let mut rows = self.fetch(query);
+
+        Box::pin(try_stream! {
+            let mut chunk = Vec::with_capacity(chunk_size);
+
+            while let Some(row) = rows.try_next().await? {
+                chunk.push(row);
+
+                if chunk.len() == chunk_size {
+                    r#yield!(mem::replace(&mut chunk, Vec::with_capacity(chunk_size)));
+                }
+            }
+
+            if !chunk.is_empty() {
+                r#yield!(chunk);
+            }
+
+            Ok(())
+        })
+    }
+
     /// Execute the query and return all the generated results, collected into a [`Vec`].
     fn fetch_all<'e, 'q: 'e, E: 'q>(
         self,
@@ -82,6 +82,20 @@ where
     pub async fn read_raw_into(&mut self, buf: &mut BytesMut, cnt: usize) -> Result<(), Error> {
         read_raw_into(&mut self.stream, buf, cnt).await
     }
+
+    /// Release excess capacity held by the read and write buffers, returning them
+    /// to their initial sizes.
+    ///
+    /// A buffer is only shrunk while it holds no buffered data.
+    pub fn shrink_buffers(&mut self) {
+        if self.wbuf.is_empty() && self.wbuf.capacity() > 512 {
+            self.wbuf = Vec::with_capacity(512);
+        }
+
+        if self.rbuf.is_empty() && self.rbuf.capacity() > 4096 {
+            self.rbuf = BytesMut::with_capacity(4096);
+        }
+    }
 }
 
 impl<S> Deref for BufStream<S>
@@ -65,6 +65,7 @@ pub mod executor;
 pub mod from_row;
 mod io;
 mod logger;
+pub mod metrics;
 mod net;
 pub mod query_as;
 pub mod query_scalar;
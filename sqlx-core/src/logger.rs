@@ -1,4 +1,5 @@
 use crate::connection::LogSettings;
+use crate::metrics::ConnectionMetrics;
 use std::time::Instant;
 
 pub(crate) struct QueryLogger<'q> {
@@ -6,15 +7,30 @@ pub(crate) struct QueryLogger<'q> {
     rows: usize,
     start: Instant,
     settings: LogSettings,
+    parameters: Option<String>,
+    metrics: ConnectionMetrics,
 }
 
 impl<'q> QueryLogger<'q> {
-    pub(crate) fn new(sql: &'q str, settings: LogSettings) -> Self {
+    pub(crate) fn new(sql: &'q str, settings: LogSettings, metrics: ConnectionMetrics) -> Self {
         Self {
             sql,
             rows: 0,
             start: Instant::now(),
             settings,
+            parameters: None,
+            metrics,
+        }
+    }
+
+    /// Record a rendering of the bound parameter values, produced lazily and
+    /// only when parameter redaction has been disabled.
+    pub(crate) fn set_parameters<F>(&mut self, render: F)
+    where
+        F: FnOnce() -> String,
+    {
+        if !self.settings.redact_parameters {
+            self.parameters = Some(render());
         }
     }
 
@@ -25,6 +41,14 @@ impl<'q> QueryLogger<'q> {
     pub(crate) fn finish(&self) {
         let elapsed = self.start.elapsed();
 
+        self.metrics.record_query(elapsed);
+
+        if elapsed >= self.settings.slow_statements_duration {
+            if let Some(callback) = &self.settings.slow_statements_callback {
+                callback(self.sql, elapsed, self.rows);
+            }
+        }
+
         let lvl = if elapsed >= self.settings.slow_statements_duration {
             self.settings.slow_statements_level
         } else {
@@ -53,11 +77,17 @@ impl<'q> QueryLogger<'q> {
 
             let rows = self.rows;
 
+            let parameters = self
+                .parameters
+                .as_ref()
+                .map(|p| format!(", parameters: {}", p))
+                .unwrap_or_default();
+
             log::logger().log(
                 &log::Record::builder()
                     .args(format_args!(
-                        "{}; rows: {}, elapsed: {:.3?}{}",
-                        summary, rows, elapsed, sql
+                        "{}; rows: {}, elapsed: {:.3?}{}{}",
+                        summary, rows, elapsed, parameters, sql
                     ))
                     .level(lvl)
                     .module_path_static(Some("sqlx::query"))
@@ -81,3 +111,59 @@ fn parse_query_summary(sql: &str) -> String {
         .collect::<Vec<&str>>()
         .join(" ")
 }
+
+#[cfg(test)]
+mod tests {
+    use super::QueryLogger;
+    use crate::connection::LogSettings;
+    use crate::metrics::ConnectionMetrics;
+    use std::sync::Mutex;
+
+    static RECORDS: Mutex<Vec<String>> = Mutex::new(Vec::new());
+
+    struct Capture;
+
+    impl log::Log for Capture {
+        fn enabled(&self, _: &log::Metadata) -> bool {
+            true
+        }
+
+        fn log(&self, record: &log::Record) {
+            if record.target() == "sqlx::query" {
+                RECORDS.lock().unwrap().push(record.args().to_string());
+            }
+        }
+
+        fn flush(&self) {}
+    }
+
+    #[test]
+    fn it_redacts_parameters_unless_disabled() {
+        log::set_logger(&Capture).ok();
+        log::set_max_level(log::LevelFilter::Info);
+
+        let mut settings = LogSettings::default();
+
+        // redaction defaults to on: the parameters are never rendered
+        let mut logger = QueryLogger::new("SELECT 'one'", settings.clone(), ConnectionMetrics::default());
+        logger.set_parameters(|| r#"["hunter2"]"#.to_owned());
+        drop(logger);
+
+        let records = RECORDS.lock().unwrap();
+        assert!(records.iter().any(|r| r.contains("SELECT 'one'")));
+        assert!(!records.iter().any(|r| r.contains("hunter2")));
+        drop(records);
+
+        // with redaction off, the parameter values are shown
+        settings.redact_parameters(false);
+
+        let mut logger = QueryLogger::new("SELECT 'two'", settings, ConnectionMetrics::default());
+        logger.set_parameters(|| r#"["hunter2"]"#.to_owned());
+        drop(logger);
+
+        let records = RECORDS.lock().unwrap();
+        assert!(records
+            .iter()
+            .any(|r| r.contains("SELECT 'two'") && r.contains("hunter2")));
+    }
+}
@@ -0,0 +1,88 @@
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Cumulative activity counters for a single database connection.
+///
+/// Returned by [`Connection::metrics`][crate::connection::Connection::metrics].
+/// Cloning is cheap; all clones observe (and [reset][Self::reset]) the same
+/// underlying counters, which are updated as each query finishes.
+#[derive(Debug, Clone)]
+pub struct ConnectionMetrics {
+    shared: Arc<Shared>,
+}
+
+#[derive(Debug)]
+struct Shared {
+    // reference point for `last_activity`; set when the connection is established
+    created_at: Instant,
+
+    queries: AtomicU64,
+
+    // total time spent executing queries, in nanoseconds
+    busy_nanos: AtomicU64,
+
+    // nanoseconds between `created_at` and the end of the most recent query,
+    // or `u64::MAX` if no query has completed yet
+    last_activity_nanos: AtomicU64,
+}
+
+impl Default for ConnectionMetrics {
+    fn default() -> Self {
+        ConnectionMetrics {
+            shared: Arc::new(Shared {
+                created_at: Instant::now(),
+                queries: AtomicU64::new(0),
+                busy_nanos: AtomicU64::new(0),
+                last_activity_nanos: AtomicU64::new(u64::MAX),
+            }),
+        }
+    }
+}
+
+impl ConnectionMetrics {
+    /// Returns the number of queries that have finished executing on this connection.
+    pub fn query_count(&self) -> u64 {
+        self.shared.queries.load(Ordering::Relaxed)
+    }
+
+    /// Returns the total time this connection has spent executing queries.
+    ///
+    /// For fetches this includes the time taken to stream the results back,
+    /// as it is measured until the last row is received.
+    pub fn total_query_time(&self) -> Duration {
+        Duration::from_nanos(self.shared.busy_nanos.load(Ordering::Relaxed))
+    }
+
+    /// Returns the instant at which the most recent query finished, or `None` if
+    /// no query has completed since the connection was established or the
+    /// counters were last [reset][Self::reset].
+    pub fn last_activity(&self) -> Option<Instant> {
+        match self.shared.last_activity_nanos.load(Ordering::Relaxed) {
+            u64::MAX => None,
+            nanos => Some(self.shared.created_at + Duration::from_nanos(nanos)),
+        }
+    }
+
+    /// Clear all counters back to their initial state.
+    pub fn reset(&self) {
+        self.shared.queries.store(0, Ordering::Relaxed);
+        self.shared.busy_nanos.store(0, Ordering::Relaxed);
+        self.shared
+            .last_activity_nanos
+            .store(u64::MAX, Ordering::Relaxed);
+    }
+
+    pub(crate) fn record_query(&self, elapsed: Duration) {
+        self.shared.queries.fetch_add(1, Ordering::Relaxed);
+
+        self.shared
+            .busy_nanos
+            .fetch_add(elapsed.as_nanos() as u64, Ordering::Relaxed);
+
+        self.shared.last_activity_nanos.store(
+            self.shared.created_at.elapsed().as_nanos() as u64,
+            Ordering::Relaxed,
+        );
+    }
+}
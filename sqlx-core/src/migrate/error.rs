@@ -18,6 +18,20 @@ pub enum MigrateError {
     #[error("cannot mix reversible migrations with simple migrations. All migrations should be reversible or simple migrations")]
     InvalidMixReversibleAndSimple,
 
+    #[error("migration {0} is not reversible; it has no down migration")]
+    Irreversible(i64),
+
+    #[error("migration {0} does not exist in the resolved migrations")]
+    VersionNotPresent(i64),
+
+    #[error("timed out while waiting for the migration lock")]
+    LockTimeout,
+
+    #[error(
+        "invalid migrations table name `{0}`; expected an identifier matching [A-Za-z_][A-Za-z0-9_]*"
+    )]
+    InvalidTableName(String),
+
     // NOTE: this will only happen with a database that does not have transactional DDL (.e.g, MySQL or Oracle)
     #[error(
         "migration {0} is partially applied; fix and remove row from `_sqlx_migrations` table"
@@ -1,5 +1,5 @@
 use crate::error::Error;
-use crate::migrate::{AppliedMigration, MigrateError, Migration};
+use crate::migrate::{AppliedMigration, AppliedMigrationDetail, MigrateError, Migration};
 use futures_core::future::BoxFuture;
 use std::time::Duration;
 
@@ -19,13 +19,19 @@ pub trait MigrateDatabase {
 
 // 'e = Executor
 pub trait Migrate {
-    // ensure migrations table exists
+    // ensure migrations table with the given name exists
     // will create or migrate it if needed
-    fn ensure_migrations_table(&mut self) -> BoxFuture<'_, Result<(), MigrateError>>;
+    fn ensure_migrations_table<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+    ) -> BoxFuture<'m, Result<(), MigrateError>>;
 
     // Return the version on which the database is dirty or None otherwise.
     // "dirty" means there is a partially applied migration that failed.
-    fn dirty_version(&mut self) -> BoxFuture<'_, Result<Option<i64>, MigrateError>>;
+    fn dirty_version<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+    ) -> BoxFuture<'m, Result<Option<i64>, MigrateError>>;
 
     // Return the current version and if the database is "dirty".
     // "dirty" means there is a partially applied migration that failed.
@@ -41,15 +47,30 @@ pub trait Migrate {
     ) -> BoxFuture<'m, Result<(), MigrateError>>;
 
     // Return the ordered list of applied migrations
-    fn list_applied_migrations(
-        &mut self,
-    ) -> BoxFuture<'_, Result<Vec<AppliedMigration>, MigrateError>>;
+    fn list_applied_migrations<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+    ) -> BoxFuture<'m, Result<Vec<AppliedMigration>, MigrateError>>;
+
+    // Return the ordered list of applied migrations together with their bookkeeping
+    // metadata (description, applied-at timestamp, execution time)
+    fn list_applied_migrations_detailed<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+    ) -> BoxFuture<'m, Result<Vec<AppliedMigrationDetail>, MigrateError>>;
 
     // Should acquire a database lock so that only one migration process
     // can run at a time. [`Migrate`] will call this function before applying
     // any migrations.
     fn lock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>>;
 
+    // Like [`lock`][Migrate::lock], but gives up with [`MigrateError::LockTimeout`]
+    // if the lock cannot be acquired within `timeout`; `None` waits indefinitely.
+    fn lock_with_timeout(
+        &mut self,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'_, Result<(), MigrateError>>;
+
     // Should release the lock. [`Migrate`] will call this function after all
     // migrations have been run.
     fn unlock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>>;
@@ -59,14 +80,41 @@ pub trait Migrate {
     // returns the time taking to run the migration SQL
     fn apply<'e: 'm, 'm>(
         &'e mut self,
+        table_name: &'m str,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<Duration, MigrateError>>;
+
+    // record a migration as applied in the bookkeeping table without running its SQL,
+    // for baselining a database whose schema already exists
+    fn mark_applied<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>>;
+
+    // re-run the SQL of an already-applied (repeatable) migration and refresh its
+    // bookkeeping row (checksum, timestamp, execution time) in place
+    // returns the time taking to run the migration SQL
+    fn reapply<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>>;
 
+    // overwrite the stored checksum for an applied migration with the checksum
+    // of the given migration, without re-running its SQL
+    fn update_checksum<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>>;
+
     // run a revert SQL from migration in a DDL transaction
     // deletes the row in [_migrations] table with specified migration version on completion (success or failure)
     // returns the time taking to run the migration SQL
     fn revert<'e: 'm, 'm>(
         &'e mut self,
+        table_name: &'m str,
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>>;
 }
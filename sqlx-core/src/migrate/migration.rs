@@ -4,6 +4,10 @@ use sha2::{Digest, Sha384};
 
 use super::MigrationType;
 
+/// The directive that, on the first non-empty line of a migration, marks it to run
+/// outside of the usual transaction wrapper.
+const NO_TX_DIRECTIVE: &str = "-- sqlx:no-transaction";
+
 #[derive(Debug, Clone)]
 pub struct Migration {
     pub version: i64,
@@ -11,6 +15,11 @@ pub struct Migration {
     pub migration_type: MigrationType,
     pub sql: Cow<'static, str>,
     pub checksum: Cow<'static, [u8]>,
+
+    /// If `true`, this migration runs without a surrounding transaction; statements like
+    /// `CREATE INDEX CONCURRENTLY` or `VACUUM` require this. A failure part-way through
+    /// such a migration is *not* rolled back.
+    pub no_tx: bool,
 }
 
 impl Migration {
@@ -21,6 +30,7 @@ impl Migration {
         sql: Cow<'static, str>,
     ) -> Self {
         let checksum = Cow::Owned(Vec::from(Sha384::digest(sql.as_bytes()).as_slice()));
+        let no_tx = parse_no_tx(&sql);
 
         Migration {
             version,
@@ -28,12 +38,49 @@ impl Migration {
             migration_type,
             sql,
             checksum,
+            no_tx,
         }
     }
 }
 
+/// Computes the synthetic version under which a repeatable migration is tracked in the
+/// bookkeeping table.
+///
+/// Repeatable migrations have no version of their own, but the bookkeeping table is
+/// keyed by version, so a stable negative one is derived from the description to keep
+/// it clear of the versioned (positive) range.
+pub fn repeatable_version(description: &str) -> i64 {
+    let digest = Sha384::digest(description.as_bytes());
+
+    let mut bytes = [0_u8; 8];
+    bytes.copy_from_slice(&digest[..8]);
+
+    -(i64::from_be_bytes(bytes) & i64::MAX)
+}
+
+/// Returns `true` if the first non-empty line of the migration SQL is the
+/// `-- sqlx:no-transaction` directive.
+pub fn parse_no_tx(sql: &str) -> bool {
+    sql.lines()
+        .map(str::trim)
+        .find(|line| !line.is_empty())
+        .map_or(false, |line| line == NO_TX_DIRECTIVE)
+}
+
 #[derive(Debug, Clone)]
 pub struct AppliedMigration {
     pub version: i64,
     pub checksum: Cow<'static, [u8]>,
 }
+
+/// A row of the bookkeeping table with its full metadata, as returned by
+/// [`Migrate::list_applied_migrations_detailed`][super::Migrate::list_applied_migrations_detailed].
+#[derive(Debug, Clone)]
+pub struct AppliedMigrationDetail {
+    pub version: i64,
+    pub description: String,
+    /// When the migration was applied, formatted by the database.
+    pub applied_at: String,
+    /// How long the migration SQL took to execute.
+    pub execution_time: std::time::Duration,
+}
@@ -11,6 +11,10 @@ pub enum MigrationType {
     /// ReversibleDown migrations represents the  delete or downgrade part of a reversible migrations
     /// It is expected the every migration of this type will have a corresponding up file
     ReversibleDown,
+
+    /// Repeatable migrations (`R__<DESCRIPTION>.sql`) have no version of their own; they run
+    /// after all versioned migrations and are re-run whenever their content changes
+    Repeatable,
 }
 
 impl MigrationType {
@@ -29,6 +33,7 @@ impl MigrationType {
             MigrationType::Simple => false,
             MigrationType::ReversibleUp => true,
             MigrationType::ReversibleDown => true,
+            MigrationType::Repeatable => false,
         }
     }
 
@@ -37,14 +42,20 @@ impl MigrationType {
             MigrationType::Simple => false,
             MigrationType::ReversibleUp => false,
             MigrationType::ReversibleDown => true,
+            MigrationType::Repeatable => false,
         }
     }
 
+    pub fn is_repeatable(&self) -> bool {
+        matches!(self, MigrationType::Repeatable)
+    }
+
     pub fn label(&self) -> &'static str {
         match self {
             MigrationType::Simple => "migrate",
             MigrationType::ReversibleUp => "migrate",
             MigrationType::ReversibleDown => "revert",
+            MigrationType::Repeatable => "repeat",
         }
     }
 
@@ -53,6 +64,7 @@ impl MigrationType {
             MigrationType::Simple => ".sql",
             MigrationType::ReversibleUp => ".up.sql",
             MigrationType::ReversibleDown => ".down.sql",
+            MigrationType::Repeatable => ".sql",
         }
     }
 
@@ -61,6 +73,7 @@ impl MigrationType {
             MigrationType::Simple => "-- Add migration script here\n",
             MigrationType::ReversibleUp => "-- Add up migration script here\n",
             MigrationType::ReversibleDown => "-- Add down migration script here\n",
+            MigrationType::Repeatable => "-- Add repeatable migration script here\n",
         }
     }
 }
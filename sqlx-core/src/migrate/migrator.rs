@@ -1,14 +1,81 @@
 use crate::acquire::Acquire;
 use crate::migrate::{AppliedMigration, Migrate, MigrateError, Migration, MigrationSource};
+use futures_core::future::BoxFuture;
 use std::borrow::Cow;
 use std::collections::{HashMap, HashSet};
+use std::fmt;
 use std::ops::Deref;
 use std::slice;
+use std::time::Duration;
+
+/// The default name for the table tracking applied migrations.
+pub const DEFAULT_TABLE_NAME: &str = "_sqlx_migrations";
+
+/// An async callback invoked before a migration is applied; see [`Migrator::before_each`].
+pub type BeforeEachHook =
+    Box<dyn for<'m> Fn(&'m Migration) -> BoxFuture<'m, Result<(), MigrateError>> + Send + Sync>;
+
+/// An async callback invoked after a migration was applied; see [`Migrator::after_each`].
+pub type AfterEachHook = Box<
+    dyn for<'m> Fn(&'m Migration, Duration) -> BoxFuture<'m, Result<(), MigrateError>>
+        + Send
+        + Sync,
+>;
+
+/// The state of a single migration, as reported by [`Migrator::status`].
+#[derive(Debug, Clone)]
+pub struct MigrationStatus {
+    pub version: i64,
+    pub description: String,
+    /// Whether this migration has been applied to the database.
+    pub applied: bool,
+    /// When the migration was applied, formatted by the database, if applied.
+    pub applied_at: Option<String>,
+    /// How long the migration SQL took to execute, if applied.
+    pub execution_time: Option<Duration>,
+    /// `true` if the version is recorded as applied but missing from the migration source.
+    pub missing: bool,
+}
+
+/// The result of [`Migrator::validate`]: how the database differs from the migration
+/// source, without anything having been executed.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct ValidationReport {
+    /// Versions in the migration source that have not been applied yet.
+    pub pending: Vec<i64>,
+    /// Applied versions whose stored checksum no longer matches the source file.
+    pub mismatched: Vec<i64>,
+    /// Applied versions that are missing from the migration source entirely.
+    pub missing: Vec<i64>,
+}
+
+impl ValidationReport {
+    /// Returns `true` if the database is fully migrated and consistent with the source.
+    pub fn is_clean(&self) -> bool {
+        self.pending.is_empty() && self.mismatched.is_empty() && self.missing.is_empty()
+    }
+}
 
-#[derive(Debug)]
 pub struct Migrator {
     pub migrations: Cow<'static, [Migration]>,
     pub ignore_missing: bool,
+    pub table_name: Cow<'static, str>,
+    pub lock_timeout: Option<Duration>,
+    pub before_each: Vec<BeforeEachHook>,
+    pub after_each: Vec<AfterEachHook>,
+}
+
+impl fmt::Debug for Migrator {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Migrator")
+            .field("migrations", &self.migrations)
+            .field("ignore_missing", &self.ignore_missing)
+            .field("table_name", &self.table_name)
+            .field("lock_timeout", &self.lock_timeout)
+            .field("before_each", &self.before_each.len())
+            .field("after_each", &self.after_each.len())
+            .finish()
+    }
 }
 
 fn validate_applied_migrations(
@@ -56,9 +123,82 @@ impl Migrator {
         Ok(Self {
             migrations: Cow::Owned(source.resolve().await.map_err(MigrateError::Source)?),
             ignore_missing: false,
+            table_name: Cow::Borrowed(DEFAULT_TABLE_NAME),
+            lock_timeout: None,
+            before_each: Vec::new(),
+            after_each: Vec::new(),
         })
     }
 
+    /// Give up with [`MigrateError::LockTimeout`] if the migration lock cannot be
+    /// acquired within `timeout`, instead of waiting indefinitely behind another
+    /// migrator holding it.
+    ///
+    /// On Postgres this bounds the advisory-lock wait with `SET lock_timeout`; on MySQL
+    /// it is passed to `GET_LOCK` (with one-second granularity). SQLite takes no
+    /// explicit migration lock, so the timeout has no effect there.
+    pub fn lock_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.lock_timeout = Some(timeout);
+        self
+    }
+
+    /// Register an async hook to run before each migration that [`run`][Self::run]
+    /// applies. A hook returning an error aborts that migration (and the run) before
+    /// any of its SQL has executed.
+    ///
+    /// Hooks fire in registration order and receive the migration about to be applied.
+    /// A hook that needs a database connection can capture a pool.
+    pub fn before_each<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: for<'m> Fn(&'m Migration) -> BoxFuture<'m, Result<(), MigrateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.before_each.push(Box::new(hook));
+        self
+    }
+
+    /// Register an async hook to run after each migration that [`run`][Self::run]
+    /// applies, receiving the migration and the time its SQL took to execute.
+    ///
+    /// Hooks fire in registration order; an error aborts the remainder of the run,
+    /// but the migration itself has already been applied and recorded.
+    pub fn after_each<F>(&mut self, hook: F) -> &mut Self
+    where
+        F: for<'m> Fn(&'m Migration, Duration) -> BoxFuture<'m, Result<(), MigrateError>>
+            + Send
+            + Sync
+            + 'static,
+    {
+        self.after_each.push(Box::new(hook));
+        self
+    }
+
+    /// Track applied migrations in a table with the given name instead of
+    /// [`_sqlx_migrations`][DEFAULT_TABLE_NAME], allowing several independent migrators
+    /// to coexist in the same database.
+    ///
+    /// The name must be an identifier of the form `[A-Za-z_][A-Za-z0-9_]*` as it is
+    /// interpolated into the bookkeeping queries verbatim.
+    pub fn with_table_name(
+        &mut self,
+        table_name: impl Into<Cow<'static, str>>,
+    ) -> Result<&mut Self, MigrateError> {
+        let table_name = table_name.into();
+
+        let mut chars = table_name.chars();
+        let valid = matches!(chars.next(), Some(c) if c.is_ascii_alphabetic() || c == '_')
+            && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+        if !valid {
+            return Err(MigrateError::InvalidTableName(table_name.into_owned()));
+        }
+
+        self.table_name = table_name;
+        Ok(self)
+    }
+
     /// Specify should ignore applied migrations that missing in the resolved migrations.
     pub fn set_ignore_missing(&mut self, ignore_missing: bool) -> &Self {
         self.ignore_missing = ignore_missing;
@@ -95,18 +235,18 @@ impl Migrator {
         let mut conn = migrator.acquire().await?;
 
         // lock the database for exclusive access by the migrator
-        conn.lock().await?;
+        conn.lock_with_timeout(self.lock_timeout).await?;
 
         // creates [_migrations] table only if needed
         // eventually this will likely migrate previous versions of the table
-        conn.ensure_migrations_table().await?;
+        conn.ensure_migrations_table(&self.table_name).await?;
 
-        let version = conn.dirty_version().await?;
+        let version = conn.dirty_version(&self.table_name).await?;
         if let Some(version) = version {
             return Err(MigrateError::Dirty(version));
         }
 
-        let applied_migrations = conn.list_applied_migrations().await?;
+        let applied_migrations = conn.list_applied_migrations(&self.table_name).await?;
         validate_applied_migrations(&applied_migrations, self)?;
 
         let applied_migrations: HashMap<_, _> = applied_migrations
@@ -115,7 +255,9 @@ impl Migrator {
             .collect();
 
         for migration in self.iter() {
-            if migration.migration_type.is_down_migration() {
+            if migration.migration_type.is_down_migration()
+                || migration.migration_type.is_repeatable()
+            {
                 continue;
             }
 
@@ -126,8 +268,51 @@ impl Migrator {
                     }
                 }
                 None => {
-                    conn.apply(migration).await?;
+                    for hook in &self.before_each {
+                        hook(migration).await?;
+                    }
+
+                    let elapsed = conn.apply(&self.table_name, migration).await?;
+
+                    for hook in &self.after_each {
+                        hook(migration, elapsed).await?;
+                    }
+                }
+            }
+        }
+
+        // repeatable migrations run after the versioned set, in description order,
+        // and are re-run whenever their content changes
+        let mut repeatable: Vec<_> = self
+            .iter()
+            .filter(|m| m.migration_type.is_repeatable())
+            .collect();
+        repeatable.sort_by(|a, b| a.description.cmp(&b.description));
+
+        for migration in repeatable {
+            let reapply = match applied_migrations.get(&migration.version) {
+                Some(applied_migration) => {
+                    if migration.checksum == applied_migration.checksum {
+                        continue;
+                    }
+
+                    true
                 }
+                None => false,
+            };
+
+            for hook in &self.before_each {
+                hook(migration).await?;
+            }
+
+            let elapsed = if reapply {
+                conn.reapply(&self.table_name, migration).await?
+            } else {
+                conn.apply(&self.table_name, migration).await?
+            };
+
+            for hook in &self.after_each {
+                hook(migration, elapsed).await?;
             }
         }
 
@@ -137,4 +322,461 @@ impl Migrator {
 
         Ok(())
     }
+
+    /// Run pending migrations in order, stopping after `target` has been applied;
+    /// migrations with a greater version are left pending.
+    ///
+    /// This is [`run`][Self::run] bounded to a chosen version, the counterpart of
+    /// [`undo`][Self::undo] for staged rollouts. An unknown `target` fails with
+    /// [`MigrateError::VersionNotPresent`] before anything is applied.
+    pub async fn run_to<'a, A>(&self, migrator: A, target: i64) -> Result<(), MigrateError>
+    where
+        A: Acquire<'a>,
+        <A::Connection as Deref>::Target: Migrate,
+    {
+        if !self
+            .iter()
+            .any(|m| !m.migration_type.is_down_migration() && m.version == target)
+        {
+            return Err(MigrateError::VersionNotPresent(target));
+        }
+
+        let mut conn = migrator.acquire().await?;
+
+        // lock the database for exclusive access by the migrator
+        conn.lock_with_timeout(self.lock_timeout).await?;
+
+        // creates [_migrations] table only if needed
+        // eventually this will likely migrate previous versions of the table
+        conn.ensure_migrations_table(&self.table_name).await?;
+
+        let version = conn.dirty_version(&self.table_name).await?;
+        if let Some(version) = version {
+            return Err(MigrateError::Dirty(version));
+        }
+
+        let applied_migrations = conn.list_applied_migrations(&self.table_name).await?;
+        validate_applied_migrations(&applied_migrations, self)?;
+
+        let applied_migrations: HashMap<_, _> = applied_migrations
+            .into_iter()
+            .map(|m| (m.version, m))
+            .collect();
+
+        for migration in self.iter() {
+            // repeatable migrations only run as part of a full `run`
+            if migration.migration_type.is_down_migration()
+                || migration.migration_type.is_repeatable()
+                || migration.version > target
+            {
+                continue;
+            }
+
+            match applied_migrations.get(&migration.version) {
+                Some(applied_migration) => {
+                    if migration.checksum != applied_migration.checksum {
+                        return Err(MigrateError::VersionMismatch(migration.version));
+                    }
+                }
+                None => {
+                    for hook in &self.before_each {
+                        hook(migration).await?;
+                    }
+
+                    let elapsed = conn.apply(&self.table_name, migration).await?;
+
+                    for hook in &self.after_each {
+                        hook(migration, elapsed).await?;
+                    }
+                }
+            }
+        }
+
+        // unlock the migrator to allow other migrators to run
+        // but do nothing as we already migrated
+        conn.unlock().await?;
+
+        Ok(())
+    }
+
+    /// Report which migrations [`run`][Self::run] would apply, without applying any of them.
+    ///
+    /// The pending migrations are returned in the order they would run and each one is
+    /// logged at `INFO` level together with its SQL. No migration SQL is executed and no
+    /// rows are written to the bookkeeping table, so this is safe to point at a production
+    /// database. Previously applied migrations are still validated against the current
+    /// source, so checksum mismatches surface here just like in `run`.
+    pub async fn dry_run<'a, A>(&self, migrator: A) -> Result<Vec<&Migration>, MigrateError>
+    where
+        A: Acquire<'a>,
+        <A::Connection as Deref>::Target: Migrate,
+    {
+        let mut conn = migrator.acquire().await?;
+
+        // creates [_migrations] table only if needed
+        conn.ensure_migrations_table(&self.table_name).await?;
+
+        let version = conn.dirty_version(&self.table_name).await?;
+        if let Some(version) = version {
+            return Err(MigrateError::Dirty(version));
+        }
+
+        let applied_migrations = conn.list_applied_migrations(&self.table_name).await?;
+        validate_applied_migrations(&applied_migrations, self)?;
+
+        let applied_migrations: HashMap<_, _> = applied_migrations
+            .into_iter()
+            .map(|m| (m.version, m))
+            .collect();
+
+        let mut pending = Vec::new();
+
+        for migration in self.iter() {
+            if migration.migration_type.is_down_migration() {
+                continue;
+            }
+
+            match applied_migrations.get(&migration.version) {
+                Some(applied_migration) => {
+                    if migration.checksum != applied_migration.checksum {
+                        // a changed repeatable migration is due to re-run, not an error
+                        if migration.migration_type.is_repeatable() {
+                            log::info!(
+                                "would re-run migration {}/{} {}\n{}",
+                                migration.version,
+                                migration.migration_type.label(),
+                                migration.description,
+                                migration.sql,
+                            );
+
+                            pending.push(migration);
+                        } else {
+                            return Err(MigrateError::VersionMismatch(migration.version));
+                        }
+                    }
+                }
+                None => {
+                    log::info!(
+                        "would apply migration {}/{} {}\n{}",
+                        migration.version,
+                        migration.migration_type.label(),
+                        migration.description,
+                        migration.sql,
+                    );
+
+                    pending.push(migration);
+                }
+            }
+        }
+
+        Ok(pending)
+    }
+
+    /// Report the state of every known migration, merging the migration source with the
+    /// bookkeeping table, ordered by version.
+    ///
+    /// Pending migrations appear with `applied: false`; applied ones carry the
+    /// applied-at timestamp and execution time recorded by the database. A version that
+    /// is recorded as applied but no longer present in the source is flagged `missing`.
+    /// Nothing is executed; the only write is creating the bookkeeping table if it does
+    /// not exist yet.
+    pub async fn status<'a, A>(&self, migrator: A) -> Result<Vec<MigrationStatus>, MigrateError>
+    where
+        A: Acquire<'a>,
+        <A::Connection as Deref>::Target: Migrate,
+    {
+        let mut conn = migrator.acquire().await?;
+
+        // creates [_migrations] table only if needed
+        conn.ensure_migrations_table(&self.table_name).await?;
+
+        let applied_migrations: HashMap<_, _> = conn
+            .list_applied_migrations_detailed(&self.table_name)
+            .await?
+            .into_iter()
+            .map(|m| (m.version, m))
+            .collect();
+
+        let known: HashSet<_> = self
+            .iter()
+            .filter(|m| !m.migration_type.is_down_migration())
+            .map(|m| m.version)
+            .collect();
+
+        let mut statuses = Vec::new();
+
+        for migration in self.iter() {
+            if migration.migration_type.is_down_migration() {
+                continue;
+            }
+
+            let applied = applied_migrations.get(&migration.version);
+
+            statuses.push(MigrationStatus {
+                version: migration.version,
+                description: migration.description.clone().into_owned(),
+                applied: applied.is_some(),
+                applied_at: applied.map(|a| a.applied_at.clone()),
+                execution_time: applied.map(|a| a.execution_time),
+                missing: false,
+            });
+        }
+
+        for applied in applied_migrations.into_values() {
+            if !known.contains(&applied.version) {
+                statuses.push(MigrationStatus {
+                    version: applied.version,
+                    description: applied.description,
+                    applied: true,
+                    applied_at: Some(applied.applied_at),
+                    execution_time: Some(applied.execution_time),
+                    missing: true,
+                });
+            }
+        }
+
+        statuses.sort_by_key(|s| s.version);
+
+        Ok(statuses)
+    }
+
+    /// Compare the database against the migration source without executing anything,
+    /// returning a [`ValidationReport`] of pending versions, checksum mismatches on
+    /// applied migrations, and applied migrations missing from the source.
+    ///
+    /// Unlike [`run`][Self::run] this collects every discrepancy instead of failing on
+    /// the first one, making it suitable for a CI gate before a deploy. No migration
+    /// SQL is executed; the only write is creating the bookkeeping table if it does
+    /// not exist yet.
+    pub async fn validate<'a, A>(&self, migrator: A) -> Result<ValidationReport, MigrateError>
+    where
+        A: Acquire<'a>,
+        <A::Connection as Deref>::Target: Migrate,
+    {
+        let mut conn = migrator.acquire().await?;
+
+        // creates [_migrations] table only if needed
+        conn.ensure_migrations_table(&self.table_name).await?;
+
+        let applied_migrations = conn.list_applied_migrations(&self.table_name).await?;
+
+        let resolved: HashMap<_, _> = self
+            .iter()
+            .filter(|m| !m.migration_type.is_down_migration())
+            .map(|m| (m.version, m))
+            .collect();
+
+        let mut report = ValidationReport::default();
+
+        for applied_migration in &applied_migrations {
+            match resolved.get(&applied_migration.version) {
+                Some(migration) => {
+                    if migration.checksum != applied_migration.checksum {
+                        report.mismatched.push(applied_migration.version);
+                    }
+                }
+                None => report.missing.push(applied_migration.version),
+            }
+        }
+
+        let applied: HashSet<_> = applied_migrations.iter().map(|m| m.version).collect();
+
+        for migration in self.iter() {
+            if migration.migration_type.is_down_migration() {
+                continue;
+            }
+
+            if !applied.contains(&migration.version) {
+                report.pending.push(migration.version);
+            }
+        }
+
+        Ok(report)
+    }
+
+    /// Recompute the stored checksums of already-applied migrations to match the current
+    /// migration source, without re-running any SQL.
+    ///
+    /// This is the escape hatch for when a historical migration file was edited in a way
+    /// that does not change the applied schema (whitespace, comments) and the checksum
+    /// validation in [`run`][Self::run] now refuses to proceed. Returns the versions whose
+    /// stored checksum was updated.
+    pub async fn repair<'a, A>(&self, migrator: A) -> Result<Vec<i64>, MigrateError>
+    where
+        A: Acquire<'a>,
+        <A::Connection as Deref>::Target: Migrate,
+    {
+        let mut conn = migrator.acquire().await?;
+
+        // lock the database for exclusive access by the migrator
+        conn.lock_with_timeout(self.lock_timeout).await?;
+
+        // creates [_migrations] table only if needed
+        conn.ensure_migrations_table(&self.table_name).await?;
+
+        let applied_migrations = conn.list_applied_migrations(&self.table_name).await?;
+        validate_applied_migrations(&applied_migrations, self)?;
+
+        let applied_migrations: HashMap<_, _> = applied_migrations
+            .into_iter()
+            .map(|m| (m.version, m))
+            .collect();
+
+        let mut repaired = Vec::new();
+
+        for migration in self.iter() {
+            if migration.migration_type.is_down_migration() {
+                continue;
+            }
+
+            if let Some(applied_migration) = applied_migrations.get(&migration.version) {
+                if migration.checksum != applied_migration.checksum {
+                    conn.update_checksum(&self.table_name, migration).await?;
+
+                    log::info!("repaired checksum of migration {}", migration.version);
+
+                    repaired.push(migration.version);
+                }
+            }
+        }
+
+        // unlock the migrator to allow other migrators to run
+        conn.unlock().await?;
+
+        Ok(repaired)
+    }
+
+    /// Record migrations up to and including `up_to_version` as applied, with their
+    /// checksums, without executing any of their SQL.
+    ///
+    /// This is for adopting migrations on a database whose schema already exists: after
+    /// baselining, [`run`][Self::run] only applies newer migrations. Versions that are
+    /// already recorded are left untouched; the newly recorded versions are returned.
+    /// An unknown `up_to_version` fails with [`MigrateError::VersionNotPresent`].
+    pub async fn baseline<'a, A>(
+        &self,
+        migrator: A,
+        up_to_version: i64,
+    ) -> Result<Vec<i64>, MigrateError>
+    where
+        A: Acquire<'a>,
+        <A::Connection as Deref>::Target: Migrate,
+    {
+        if !self
+            .iter()
+            .any(|m| !m.migration_type.is_down_migration() && m.version == up_to_version)
+        {
+            return Err(MigrateError::VersionNotPresent(up_to_version));
+        }
+
+        let mut conn = migrator.acquire().await?;
+
+        // lock the database for exclusive access by the migrator
+        conn.lock_with_timeout(self.lock_timeout).await?;
+
+        // creates [_migrations] table only if needed
+        conn.ensure_migrations_table(&self.table_name).await?;
+
+        let applied: HashSet<_> = conn
+            .list_applied_migrations(&self.table_name)
+            .await?
+            .into_iter()
+            .map(|m| m.version)
+            .collect();
+
+        let mut baselined = Vec::new();
+
+        for migration in self.iter() {
+            if migration.migration_type.is_down_migration()
+                || migration.migration_type.is_repeatable()
+                || migration.version > up_to_version
+            {
+                continue;
+            }
+
+            if !applied.contains(&migration.version) {
+                conn.mark_applied(&self.table_name, migration).await?;
+
+                baselined.push(migration.version);
+            }
+        }
+
+        // unlock the migrator to allow other migrators to run
+        conn.unlock().await?;
+
+        Ok(baselined)
+    }
+
+    /// Run down migrations, in reverse version order, until the database is back at
+    /// `target`; the migration with version `target` itself is left applied.
+    ///
+    /// An applied migration above `target` that has no down migration is irreversible
+    /// and aborts the undo with [`MigrateError::Irreversible`] before anything is
+    /// reverted.
+    pub async fn undo<'a, A>(&self, migrator: A, target: i64) -> Result<(), MigrateError>
+    where
+        A: Acquire<'a>,
+        <A::Connection as Deref>::Target: Migrate,
+    {
+        let mut conn = migrator.acquire().await?;
+
+        // lock the database for exclusive access by the migrator
+        conn.lock_with_timeout(self.lock_timeout).await?;
+
+        // creates [_migrations] table only if needed
+        // eventually this will likely migrate previous versions of the table
+        conn.ensure_migrations_table(&self.table_name).await?;
+
+        let version = conn.dirty_version(&self.table_name).await?;
+        if let Some(version) = version {
+            return Err(MigrateError::Dirty(version));
+        }
+
+        let applied_migrations = conn.list_applied_migrations(&self.table_name).await?;
+        validate_applied_migrations(&applied_migrations, self)?;
+
+        let applied_migrations: HashMap<_, _> = applied_migrations
+            .into_iter()
+            .map(|m| (m.version, m))
+            .collect();
+
+        let down_migrations: HashSet<_> = self
+            .iter()
+            .filter(|m| m.migration_type.is_down_migration())
+            .map(|m| m.version)
+            .collect();
+
+        // refuse to revert anything if any migration on the way down is irreversible;
+        // repeatable migrations are never reverted
+        for migration in self.iter().rev() {
+            if migration.migration_type.is_down_migration()
+                || migration.migration_type.is_repeatable()
+                || migration.version <= target
+            {
+                continue;
+            }
+
+            if applied_migrations.contains_key(&migration.version)
+                && !down_migrations.contains(&migration.version)
+            {
+                return Err(MigrateError::Irreversible(migration.version));
+            }
+        }
+
+        for migration in self.iter().rev() {
+            if !migration.migration_type.is_down_migration() || migration.version <= target {
+                continue;
+            }
+
+            if applied_migrations.contains_key(&migration.version) {
+                conn.revert(&self.table_name, migration).await?;
+            }
+        }
+
+        // unlock the migrator to allow other migrators to run
+        // but do nothing as we already reverted
+        conn.unlock().await?;
+
+        Ok(())
+    }
 }
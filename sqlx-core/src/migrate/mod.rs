@@ -8,7 +8,11 @@ mod source;
 
 pub use error::MigrateError;
 pub use migrate::{Migrate, MigrateDatabase};
-pub use migration::{AppliedMigration, Migration};
+pub use migration::{
+    parse_no_tx, repeatable_version, AppliedMigration, AppliedMigrationDetail, Migration,
+};
 pub use migration_type::MigrationType;
-pub use migrator::Migrator;
+pub use migrator::{
+    AfterEachHook, BeforeEachHook, MigrationStatus, Migrator, ValidationReport, DEFAULT_TABLE_NAME,
+};
 pub use source::MigrationSource;
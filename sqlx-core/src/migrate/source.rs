@@ -1,9 +1,10 @@
 use crate::error::BoxDynError;
-use crate::migrate::{Migration, MigrationType};
+use crate::migrate::{repeatable_version, Migration, MigrationType};
 use futures_core::future::BoxFuture;
 use futures_util::TryStreamExt;
 use sqlx_rt::fs;
 use std::borrow::Cow;
+use std::collections::HashSet;
 use std::fmt::Debug;
 use std::path::{Path, PathBuf};
 
@@ -36,6 +37,25 @@ impl<'s> MigrationSource<'s> for &'s Path {
                 let file_name = entry.file_name();
                 let file_name = file_name.to_string_lossy();
 
+                // repeatable migrations have no version prefix: R__<DESCRIPTION>.sql
+                if let Some(rest) = file_name.strip_prefix("R__") {
+                    if !rest.ends_with(".sql") {
+                        continue;
+                    }
+
+                    let description = rest.trim_end_matches(".sql").replace('_', " ");
+                    let sql = fs::read_to_string(&entry.path()).await?;
+
+                    migrations.push(Migration::new(
+                        repeatable_version(&description),
+                        Cow::Owned(description),
+                        MigrationType::Repeatable,
+                        Cow::Owned(sql),
+                    ));
+
+                    continue;
+                }
+
                 let parts = file_name.splitn(2, '_').collect::<Vec<_>>();
 
                 if parts.len() != 2 || !parts[1].ends_with(".sql") {
@@ -75,3 +95,37 @@ impl MigrationSource<'static> for PathBuf {
         Box::pin(async move { self.as_path().resolve().await })
     }
 }
+
+/// Merges the migrations of several directories into a single version-ordered set.
+///
+/// A version may only be defined in one of the directories; a collision is an error.
+impl MigrationSource<'static> for Vec<PathBuf> {
+    fn resolve(self) -> BoxFuture<'static, Result<Vec<Migration>, BoxDynError>> {
+        Box::pin(async move {
+            let mut migrations = Vec::new();
+            let mut seen = HashSet::new();
+
+            for path in self {
+                for migration in path.clone().resolve().await? {
+                    if !seen.insert((
+                        migration.version,
+                        migration.migration_type.is_down_migration(),
+                    )) {
+                        return Err(format!(
+                            "migration version {} is defined in more than one source directory",
+                            migration.version
+                        )
+                        .into());
+                    }
+
+                    migrations.push(migration);
+                }
+            }
+
+            // ensure that we are sorted by `VERSION ASC`
+            migrations.sort_by_key(|m| m.version);
+
+            Ok(migrations)
+        })
+    }
+}
@@ -1,6 +1,7 @@
 use crate::common::StatementCache;
 use crate::error::Error;
 use crate::io::Decode;
+use crate::metrics::ConnectionMetrics;
 use crate::mssql::connection::stream::MssqlStream;
 use crate::mssql::protocol::login::Login7;
 use crate::mssql::protocol::message::Message;
@@ -83,6 +84,7 @@ impl MssqlConnection {
             stream,
             cache_statement: StatementCache::new(1024),
             log_settings: options.log_settings.clone(),
+            metrics: ConnectionMetrics::default(),
         })
     }
 }
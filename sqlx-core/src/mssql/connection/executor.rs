@@ -79,7 +79,7 @@ impl<'c> Executor<'c> for &'c mut MssqlConnection {
     {
         let sql = query.sql();
         let arguments = query.take_arguments();
-        let mut logger = QueryLogger::new(sql, self.log_settings.clone());
+        let mut logger = QueryLogger::new(sql, self.log_settings.clone(), self.metrics.clone());
 
         Box::pin(try_stream! {
             self.run(sql, arguments).await?;
@@ -188,6 +188,7 @@ impl<'c> Executor<'c> for &'c mut MssqlConnection {
                 nullable,
                 columns: (metadata.columns).clone(),
                 parameters: None,
+                read_only: None,
             })
         })
     }
@@ -2,6 +2,7 @@ use crate::common::StatementCache;
 use crate::connection::{Connection, LogSettings};
 use crate::error::Error;
 use crate::executor::Executor;
+use crate::metrics::ConnectionMetrics;
 use crate::mssql::connection::stream::MssqlStream;
 use crate::mssql::statement::MssqlStatementMetadata;
 use crate::mssql::{Mssql, MssqlConnectOptions};
@@ -20,6 +21,7 @@ pub struct MssqlConnection {
     pub(crate) stream: MssqlStream,
     pub(crate) cache_statement: StatementCache<Arc<MssqlStatementMetadata>>,
     log_settings: LogSettings,
+    metrics: ConnectionMetrics,
 }
 
 impl Debug for MssqlConnection {
@@ -67,6 +69,14 @@ impl Connection for MssqlConnection {
         Transaction::begin(self)
     }
 
+    fn metrics(&self) -> ConnectionMetrics {
+        self.metrics.clone()
+    }
+
+    fn shrink_buffers(&mut self) {
+        self.stream.shrink_buffers();
+    }
+
     #[doc(hidden)]
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         self.stream.wait_until_ready().boxed()
@@ -22,6 +22,11 @@ impl Database for Mssql {
     type TypeInfo = MssqlTypeInfo;
 
     type Value = MssqlValue;
+    fn fmt_placeholder(sql: &mut String, index: usize) {
+        use std::fmt::Write;
+
+        let _ = write!(sql, "@p{}", index);
+    }
 }
 
 impl<'r> HasValueRef<'r> for Mssql {
@@ -24,4 +24,17 @@ impl ConnectOptions for MssqlConnectOptions {
         self.log_settings.log_slow_statements(level, duration);
         self
     }
+
+    fn on_slow_statement(
+        &mut self,
+        callback: impl Fn(&str, Duration, usize) + Send + Sync + 'static,
+    ) -> &mut Self {
+        self.log_settings.on_slow_statement(callback);
+        self
+    }
+
+    fn redact_parameters(&mut self, redact: bool) -> &mut Self {
+        self.log_settings.redact_parameters(redact);
+        self
+    }
 }
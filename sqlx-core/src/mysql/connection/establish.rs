@@ -3,6 +3,7 @@ use bytes::Bytes;
 
 use crate::common::StatementCache;
 use crate::error::Error;
+use crate::metrics::ConnectionMetrics;
 use crate::mysql::connection::{tls, MySqlStream, MAX_PACKET_SIZE};
 use crate::mysql::protocol::connect::{
     AuthSwitchRequest, AuthSwitchResponse, Handshake, HandshakeResponse,
@@ -129,7 +130,15 @@ impl MySqlConnection {
             stream,
             transaction_depth: 0,
             cache_statement: StatementCache::new(options.statement_cache_capacity),
+            cache_describe: StatementCache::new(options.describe_cache_capacity),
             log_settings: options.log_settings.clone(),
+            metrics: ConnectionMetrics::default(),
+            pending_stmt_reset: None,
+            reconnect_options: if options.reconnect {
+                Some(Box::new(options.clone()))
+            } else {
+                None
+            },
         })
     }
 }
@@ -8,7 +8,7 @@ use crate::mysql::connection::stream::Waiting;
 use crate::mysql::io::MySqlBufExt;
 use crate::mysql::protocol::response::Status;
 use crate::mysql::protocol::statement::{
-    BinaryRow, Execute as StatementExecute, Prepare, PrepareOk, StmtClose,
+    BinaryRow, Execute as StatementExecute, Prepare, PrepareOk, StmtClose, StmtReset,
 };
 use crate::mysql::protocol::text::{ColumnDefinition, ColumnFlags, Query, TextRow};
 use crate::mysql::statement::{MySqlStatement, MySqlStatementMetadata};
@@ -90,9 +90,23 @@ impl MySqlConnection {
         persistent: bool,
     ) -> Result<impl Stream<Item = Result<Either<MySqlQueryResult, MySqlRow>, Error>> + 'e, Error>
     {
-        let mut logger = QueryLogger::new(sql, self.log_settings.clone());
+        let mut logger = QueryLogger::new(sql, self.log_settings.clone(), self.metrics.clone());
 
         self.stream.wait_until_ready().await?;
+
+        if let Some(statement_id) = self.pending_stmt_reset.take() {
+            // the last execution of this statement errored part-way through;
+            // return it to a clean prepared state before anything else runs
+            // https://dev.mysql.com/doc/internals/en/com-stmt-reset.html
+            self.stream
+                .send_packet(StmtReset {
+                    statement: statement_id,
+                })
+                .await?;
+
+            self.stream.recv_ok().await?;
+        }
+
         self.stream.waiting.push_back(Waiting::Result);
 
         Ok(Box::pin(try_stream! {
@@ -101,7 +115,7 @@ impl MySqlConnection {
             // to re-use this memory freely between result sets
             let mut columns = Arc::new(Vec::new());
 
-            let (mut column_names, format, mut needs_metadata) = if let Some(arguments) = arguments {
+            let (mut column_names, stmt_id, format, mut needs_metadata) = if let Some(arguments) = arguments {
                 let (id, metadata) = self.get_or_prepare(
                     sql,
                     persistent,
@@ -116,18 +130,30 @@ impl MySqlConnection {
                     })
                     .await?;
 
-                (metadata.column_names, MySqlValueFormat::Binary, false)
+                (metadata.column_names, Some(id), MySqlValueFormat::Binary, false)
             } else {
                 // https://dev.mysql.com/doc/internals/en/com-query.html
                 self.stream.send_packet(Query(sql)).await?;
 
-                (Arc::default(), MySqlValueFormat::Text, true)
+                (Arc::default(), None, MySqlValueFormat::Text, true)
             };
 
             loop {
                 // query response is a meta-packet which may be one of:
                 //  Ok, Err, ResultSet, or (unhandled) LocalInfileRequest
-                let mut packet = self.stream.recv_packet().await?;
+                let mut packet = match self.stream.recv_packet().await {
+                    Ok(packet) => packet,
+
+                    Err(error) => {
+                        // the statement errored mid-execution; schedule a reset
+                        // so it is usable again before it is next executed
+                        if let Some(id) = stmt_id {
+                            self.pending_stmt_reset = Some(id);
+                        }
+
+                        return Err(error);
+                    }
+                };
 
                 if packet[0] == 0x00 || packet[0] == 0xff {
                     // first packet in a query response is OK or ERR
@@ -167,7 +193,17 @@ impl MySqlConnection {
 
                 // finally, there will be none or many result-rows
                 loop {
-                    let packet = self.stream.recv_packet().await?;
+                    let packet = match self.stream.recv_packet().await {
+                        Ok(packet) => packet,
+
+                        Err(error) => {
+                            if let Some(id) = stmt_id {
+                                self.pending_stmt_reset = Some(id);
+                            }
+
+                            return Err(error);
+                        }
+                    };
 
                     if packet[0] == 0xfe && packet.len() < 9 {
                         let eof = packet.eof(self.stream.capabilities)?;
@@ -208,6 +244,20 @@ impl MySqlConnection {
     }
 }
 
+
+fn is_connection_lost(error: &Error) -> bool {
+    matches!(
+        error,
+        Error::Io(e) if matches!(
+            e.kind(),
+            std::io::ErrorKind::ConnectionAborted
+                | std::io::ErrorKind::ConnectionReset
+                | std::io::ErrorKind::BrokenPipe
+                | std::io::ErrorKind::UnexpectedEof
+        )
+    )
+}
+
 impl<'c> Executor<'c> for &'c mut MySqlConnection {
     type Database = MySql;
 
@@ -224,14 +274,54 @@ impl<'c> Executor<'c> for &'c mut MySqlConnection {
         let persistent = query.persistent();
 
         Box::pin(try_stream! {
-            let s = self.run(sql, arguments, persistent).await?;
-            pin_mut!(s);
+            let mut tried_reconnect = false;
 
-            while let Some(v) = s.try_next().await? {
-                r#yield!(v);
-            }
+            loop {
+                let mut yielded = false;
 
-            Ok(())
+                let result: Result<(), Error> = async {
+                    let s = self.run(sql, arguments.clone(), persistent).await?;
+                    pin_mut!(s);
+
+                    while let Some(v) = s.try_next().await? {
+                        yielded = true;
+                        r#yield!(v);
+                    }
+
+                    Ok(())
+                }
+                .await;
+
+                match result {
+                    Ok(()) => return Ok(()),
+
+                    Err(error) => {
+                        // a lost connection is only retried once, with a complete
+                        // result, and never inside a transaction where a silent
+                        // retry would be unsafe
+                        if tried_reconnect
+                            || yielded
+                            || self.transaction_depth > 0
+                            || !is_connection_lost(&error)
+                        {
+                            return Err(error);
+                        }
+
+                        let options = match &self.reconnect_options {
+                            Some(options) => (**options).clone(),
+                            None => return Err(error),
+                        };
+
+                        log::warn!(
+                            "connection lost; re-establishing and retrying: {}",
+                            error
+                        );
+
+                        *self = MySqlConnection::establish(&options).await?;
+                        tried_reconnect = true;
+                    }
+                }
+            }
         })
     }
 
@@ -285,6 +375,10 @@ impl<'c> Executor<'c> for &'c mut MySqlConnection {
         Box::pin(async move {
             self.stream.wait_until_ready().await?;
 
+            if let Some(describe) = self.cache_describe.get_mut(sql) {
+                return Ok(describe.clone());
+            }
+
             let (_, metadata) = self.get_or_prepare(sql, false).await?;
 
             let columns = (&*metadata.columns).clone();
@@ -297,11 +391,18 @@ impl<'c> Executor<'c> for &'c mut MySqlConnection {
                 })
                 .collect();
 
-            Ok(Describe {
+            let describe = Describe {
                 parameters: Some(Either::Right(metadata.parameters)),
                 columns,
                 nullable,
-            })
+                read_only: None,
+            };
+
+            if self.cache_describe.is_enabled() {
+                self.cache_describe.insert(sql, describe.clone());
+            }
+
+            Ok(describe)
         })
     }
 }
@@ -1,7 +1,9 @@
 use crate::common::StatementCache;
 use crate::connection::{Connection, LogSettings};
+use crate::describe::Describe;
 use crate::error::Error;
-use crate::mysql::protocol::statement::StmtClose;
+use crate::metrics::ConnectionMetrics;
+use crate::mysql::protocol::statement::{StmtClose, StmtReset};
 use crate::mysql::protocol::text::{Ping, Quit};
 use crate::mysql::statement::MySqlStatementMetadata;
 use crate::mysql::{MySql, MySqlConnectOptions};
@@ -33,7 +35,55 @@ pub struct MySqlConnection {
     // cache by query string to the statement id and metadata
     cache_statement: StatementCache<(u32, MySqlStatementMetadata)>,
 
+    // cache describe results by query string; disabled unless opted in
+    cache_describe: StatementCache<Describe<MySql>>,
+
     log_settings: LogSettings,
+
+    metrics: ConnectionMetrics,
+
+    // a prepared statement that errored mid-execution and should be sent a
+    // `COM_STMT_RESET` before it is executed again
+    pub(crate) pending_stmt_reset: Option<u32>,
+
+    // set when `MySqlConnectOptions::reconnect` is enabled; used to transparently
+    // re-establish the connection when the server closes it
+    pub(crate) reconnect_options: Option<Box<MySqlConnectOptions>>,
+}
+
+impl MySqlConnection {
+    /// Reset the cached prepared statement for `sql` to its freshly-prepared state.
+    ///
+    /// Sends `COM_STMT_RESET`, which discards any accumulated state for the
+    /// statement on the server, such as partially uploaded long data. This is
+    /// done automatically before a statement that errored mid-execution is
+    /// executed again; calling it by hand is only needed to release server-side
+    /// resources eagerly.
+    ///
+    /// Does nothing if `sql` does not refer to a statement in this connection's
+    /// statement cache.
+    pub async fn reset_statement(&mut self, sql: &str) -> Result<(), Error> {
+        let statement_id = match self.cache_statement.get_mut(sql) {
+            Some((id, _)) => *id,
+            None => return Ok(()),
+        };
+
+        self.stream.wait_until_ready().await?;
+
+        self.stream
+            .send_packet(StmtReset {
+                statement: statement_id,
+            })
+            .await?;
+
+        self.stream.recv_ok().await?;
+
+        if self.pending_stmt_reset == Some(statement_id) {
+            self.pending_stmt_reset = None;
+        }
+
+        Ok(())
+    }
 }
 
 impl Debug for MySqlConnection {
@@ -77,6 +127,9 @@ impl Connection for MySqlConnection {
 
     fn clear_cached_statements(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(async move {
+            self.cache_describe.clear();
+            self.pending_stmt_reset = None;
+
             while let Some((statement_id, _)) = self.cache_statement.remove_lru() {
                 self.stream
                     .send_packet(StmtClose {
@@ -89,6 +142,14 @@ impl Connection for MySqlConnection {
         })
     }
 
+    fn metrics(&self) -> ConnectionMetrics {
+        self.metrics.clone()
+    }
+
+    fn shrink_buffers(&mut self) {
+        self.stream.shrink_buffers();
+    }
+
     #[doc(hidden)]
     fn should_flush(&self) -> bool {
         !self.stream.wbuf.is_empty()
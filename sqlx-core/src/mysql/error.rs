@@ -76,4 +76,24 @@ impl DatabaseError for MySqlDatabaseError {
     fn into_error(self: Box<Self>) -> Box<dyn Error + Send + Sync + 'static> {
         self
     }
+
+    fn is_unique_violation(&self) -> bool {
+        // ER_DUP_ENTRY, ER_DUP_UNIQUE, ER_DUP_ENTRY_WITH_KEY_NAME
+        matches!(self.number(), 1062 | 1169 | 1586)
+    }
+
+    fn is_foreign_key_violation(&self) -> bool {
+        // ER_NO_REFERENCED_ROW{,_2}, ER_ROW_IS_REFERENCED{,_2}
+        matches!(self.number(), 1216 | 1217 | 1451 | 1452)
+    }
+
+    fn is_check_violation(&self) -> bool {
+        // ER_CHECK_CONSTRAINT_VIOLATED
+        self.number() == 3819
+    }
+
+    fn is_not_null_violation(&self) -> bool {
+        // ER_BAD_NULL_ERROR
+        self.number() == 1048
+    }
 }
@@ -2,7 +2,7 @@ use crate::connection::ConnectOptions;
 use crate::error::Error;
 use crate::executor::Executor;
 use crate::migrate::MigrateError;
-use crate::migrate::{AppliedMigration, Migration};
+use crate::migrate::{AppliedMigration, AppliedMigrationDetail, Migration};
 use crate::migrate::{Migrate, MigrateDatabase};
 use crate::mysql::{MySql, MySqlConnectOptions, MySqlConnection};
 use crate::query::query;
@@ -75,12 +75,15 @@ impl MigrateDatabase for MySql {
 }
 
 impl Migrate for MySqlConnection {
-    fn ensure_migrations_table(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
+    fn ensure_migrations_table<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
         Box::pin(async move {
             // language=MySQL
-            self.execute(
+            let create_table = format!(
                 r#"
-CREATE TABLE IF NOT EXISTS _sqlx_migrations (
+CREATE TABLE IF NOT EXISTS {} (
     version BIGINT PRIMARY KEY,
     description TEXT NOT NULL,
     installed_on TIMESTAMP NOT NULL DEFAULT CURRENT_TIMESTAMP,
@@ -89,8 +92,10 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
     execution_time BIGINT NOT NULL
 );
                 "#,
-            )
-            .await?;
+                table_name
+            );
+
+            self.execute(&*create_table).await?;
 
             Ok(())
         })
@@ -109,12 +114,16 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
-    fn dirty_version(&mut self) -> BoxFuture<'_, Result<Option<i64>, MigrateError>> {
+    fn dirty_version<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+    ) -> BoxFuture<'m, Result<Option<i64>, MigrateError>> {
         Box::pin(async move {
             // language=SQL
-            let row: Option<(i64,)> = query_as(
-                "SELECT version FROM _sqlx_migrations WHERE success = false ORDER BY version LIMIT 1",
-            )
+            let row: Option<(i64,)> = query_as(&format!(
+                "SELECT version FROM {} WHERE success = false ORDER BY version LIMIT 1",
+                table_name
+            ))
             .fetch_optional(self)
             .await?;
 
@@ -122,15 +131,18 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
-    fn list_applied_migrations(
-        &mut self,
-    ) -> BoxFuture<'_, Result<Vec<AppliedMigration>, MigrateError>> {
+    fn list_applied_migrations<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+    ) -> BoxFuture<'m, Result<Vec<AppliedMigration>, MigrateError>> {
         Box::pin(async move {
             // language=SQL
-            let rows: Vec<(i64, Vec<u8>)> =
-                query_as("SELECT version, checksum FROM _sqlx_migrations ORDER BY version")
-                    .fetch_all(self)
-                    .await?;
+            let rows: Vec<(i64, Vec<u8>)> = query_as(&format!(
+                "SELECT version, checksum FROM {} ORDER BY version",
+                table_name
+            ))
+            .fetch_all(self)
+            .await?;
 
             let migrations = rows
                 .into_iter()
@@ -144,6 +156,35 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn list_applied_migrations_detailed<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+    ) -> BoxFuture<'m, Result<Vec<AppliedMigrationDetail>, MigrateError>> {
+        Box::pin(async move {
+            // language=SQL
+            let rows: Vec<(i64, String, String, i64)> = query_as(&format!(
+                "SELECT version, description, CAST(installed_on AS CHAR), execution_time FROM {} ORDER BY version",
+                table_name
+            ))
+            .fetch_all(self)
+            .await?;
+
+            let migrations = rows
+                .into_iter()
+                .map(
+                    |(version, description, applied_at, execution_time)| AppliedMigrationDetail {
+                        version,
+                        description,
+                        applied_at,
+                        execution_time: Duration::from_nanos(std::cmp::max(execution_time, 0) as u64),
+                    },
+                )
+                .collect();
+
+            Ok(migrations)
+        })
+    }
+
     fn lock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move {
             let database_name = current_database(self).await?;
@@ -165,6 +206,35 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn lock_with_timeout(
+        &mut self,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'_, Result<(), MigrateError>> {
+        Box::pin(async move {
+            let timeout = match timeout {
+                Some(timeout) => timeout,
+                None => return self.lock().await,
+            };
+
+            let database_name = current_database(self).await?;
+            let lock_id = generate_lock_id(&database_name);
+
+            // GET_LOCK returns 0 (instead of raising an error) when it times out
+            // language=MySQL
+            let acquired: i64 = query_scalar("SELECT GET_LOCK(?, ?)")
+                .bind(lock_id)
+                .bind(std::cmp::max(timeout.as_secs(), 1) as i64)
+                .fetch_one(self)
+                .await?;
+
+            if acquired == 1 {
+                Ok(())
+            } else {
+                Err(MigrateError::LockTimeout)
+            }
+        })
+    }
+
     fn unlock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move {
             let database_name = current_database(self).await?;
@@ -206,6 +276,7 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
 
     fn apply<'e: 'm, 'm>(
         &'e mut self,
+        table_name: &'m str,
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
         Box::pin(async move {
@@ -216,12 +287,13 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
             let elapsed = start.elapsed();
 
             // language=MySQL
-            let _ = query(
+            let _ = query(&format!(
                 r#"
-    INSERT INTO _sqlx_migrations ( version, description, success, checksum, execution_time )
+    INSERT INTO {} ( version, description, success, checksum, execution_time )
     VALUES ( ?, ?, ?, ?, ? )
                 "#,
-            )
+                table_name
+            ))
             .bind(migration.version)
             .bind(&*migration.description)
             .bind(res.is_ok())
@@ -236,8 +308,80 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn mark_applied<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
+        Box::pin(async move {
+            // language=MySQL
+            let _ = query(&format!(
+                r#"
+    INSERT INTO {} ( version, description, success, checksum, execution_time )
+    VALUES ( ?, ?, TRUE, ?, 0 )
+                "#,
+                table_name
+            ))
+            .bind(migration.version)
+            .bind(&*migration.description)
+            .bind(&*migration.checksum)
+            .execute(self)
+            .await?;
+
+            Ok(())
+        })
+    }
+
+    fn reapply<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
+        Box::pin(async move {
+            let start = Instant::now();
+
+            self.execute(&*migration.sql).await?;
+
+            let elapsed = start.elapsed();
+
+            // language=SQL
+            let _ = query(&format!(
+                r#"UPDATE {} SET checksum = ?, execution_time = ?, installed_on = CURRENT_TIMESTAMP WHERE version = ?"#,
+                table_name
+            ))
+            .bind(&*migration.checksum)
+            .bind(elapsed.as_nanos() as i64)
+            .bind(migration.version)
+            .execute(self)
+            .await?;
+
+            Ok(elapsed)
+        })
+    }
+
+    fn update_checksum<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
+        Box::pin(async move {
+            // language=SQL
+            let _ = query(&format!(
+                r#"UPDATE {} SET checksum = ? WHERE version = ?"#,
+                table_name
+            ))
+            .bind(&*migration.checksum)
+            .bind(migration.version)
+            .execute(self)
+            .await?;
+
+            Ok(())
+        })
+    }
+
     fn revert<'e: 'm, 'm>(
         &'e mut self,
+        table_name: &'m str,
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
         Box::pin(async move {
@@ -248,7 +392,10 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
             let elapsed = start.elapsed();
 
             // language=SQL
-            let _ = query(r#"DELETE FROM _sqlx_migrations WHERE version = ?"#)
+            let _ = query(&format!(
+                r#"DELETE FROM {} WHERE version = ?"#,
+                table_name
+            ))
                 .bind(migration.version)
                 .execute(self)
                 .await?;
@@ -62,9 +62,11 @@ pub struct MySqlConnectOptions {
     pub(crate) ssl_mode: MySqlSslMode,
     pub(crate) ssl_ca: Option<CertificateInput>,
     pub(crate) statement_cache_capacity: usize,
+    pub(crate) describe_cache_capacity: usize,
     pub(crate) charset: String,
     pub(crate) collation: Option<String>,
     pub(crate) log_settings: LogSettings,
+    pub(crate) reconnect: bool,
 }
 
 impl Default for MySqlConnectOptions {
@@ -88,7 +90,9 @@ impl MySqlConnectOptions {
             ssl_mode: MySqlSslMode::Preferred,
             ssl_ca: None,
             statement_cache_capacity: 100,
+            describe_cache_capacity: 0,
             log_settings: Default::default(),
+            reconnect: false,
         }
     }
 
@@ -195,6 +199,38 @@ impl MySqlConnectOptions {
         self
     }
 
+    /// Sets whether a lost connection is transparently re-established.
+    ///
+    /// MySQL closes connections that idle past `wait_timeout`, and long-lived
+    /// connections may be killed by an administrator. With this enabled, a statement
+    /// that fails because the connection was lost is retried once on a freshly
+    /// established connection — but only *outside* of a transaction; inside one the
+    /// error is surfaced, as silently retrying part of a transaction would be unsafe.
+    ///
+    /// Statements are never retried if any part of their result has already been
+    /// received.
+    ///
+    /// Defaults to `false`.
+    pub fn reconnect(mut self, reconnect: bool) -> Self {
+        self.reconnect = reconnect;
+        self
+    }
+
+    /// Sets the capacity of the connection's describe cache in a number of
+    /// distinct SQL strings. When enabled, [`Executor::describe`] results are
+    /// cached per connection and reused instead of re-querying the server.
+    ///
+    /// The cache is cleared by [`Connection::clear_cached_statements`].
+    ///
+    /// The default capacity is 0 (caching disabled).
+    ///
+    /// [`Executor::describe`]: crate::executor::Executor::describe
+    /// [`Connection::clear_cached_statements`]: crate::connection::Connection::clear_cached_statements
+    pub fn describe_cache_capacity(mut self, capacity: usize) -> Self {
+        self.describe_cache_capacity = capacity;
+        self
+    }
+
     /// Sets the character set for the connection.
     ///
     /// The default character set is `utf8mb4`. This is supported from MySQL 5.5.3.
@@ -7,10 +7,22 @@ pub struct MySqlQueryResult {
 }
 
 impl MySqlQueryResult {
+    /// The `AUTO_INCREMENT` id generated by the statement, or `0` if it generated none.
+    ///
+    /// For a multi-row `INSERT` this is the id of the **first** inserted row; the ids of
+    /// the remaining rows follow consecutively, so the full range can be derived together
+    /// with [`rows_affected()`][Self::rows_affected]. For
+    /// `INSERT ... ON DUPLICATE KEY UPDATE` the value refers to the inserted or updated
+    /// row, which makes it unreliable when a single statement mixes inserts and updates.
     pub fn last_insert_id(&self) -> u64 {
         self.last_insert_id
     }
 
+    /// The number of rows inserted, updated or deleted by the statement.
+    ///
+    /// Note that `INSERT ... ON DUPLICATE KEY UPDATE` counts `2` for each row that was
+    /// updated rather than inserted; this count is therefore not interchangeable with
+    /// the number of ids consumed by [`last_insert_id()`][Self::last_insert_id].
     pub fn rows_affected(&self) -> u64 {
         self.rows_affected
     }
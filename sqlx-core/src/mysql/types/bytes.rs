@@ -5,6 +5,7 @@ use crate::mysql::io::MySqlBufMutExt;
 use crate::mysql::protocol::text::ColumnType;
 use crate::mysql::{MySql, MySqlTypeInfo, MySqlValueRef};
 use crate::types::Type;
+use std::convert::TryInto;
 
 impl Type<MySql> for [u8] {
     fn type_info() -> MySqlTypeInfo {
@@ -61,3 +62,36 @@ impl Decode<'_, MySql> for Vec<u8> {
         <&[u8] as Decode<MySql>>::decode(value).map(ToOwned::to_owned)
     }
 }
+
+impl<const N: usize> Type<MySql> for [u8; N] {
+    fn type_info() -> MySqlTypeInfo {
+        <[u8] as Type<MySql>>::type_info()
+    }
+
+    fn compatible(ty: &MySqlTypeInfo) -> bool {
+        <&[u8] as Type<MySql>>::compatible(ty)
+    }
+}
+
+impl<const N: usize> Encode<'_, MySql> for [u8; N] {
+    fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
+        buf.put_bytes_lenenc(self);
+
+        IsNull::No
+    }
+}
+
+impl<'r, const N: usize> Decode<'r, MySql> for [u8; N] {
+    fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
+        let bytes = <&[u8] as Decode<MySql>>::decode(value)?;
+
+        bytes.try_into().map_err(|_| {
+            format!(
+                "mismatched lengths: [u8; {}] from a BLOB of {} bytes",
+                N,
+                bytes.len()
+            )
+            .into()
+        })
+    }
+}
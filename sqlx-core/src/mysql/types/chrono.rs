@@ -272,6 +272,9 @@ fn decode_time(len: u8, mut buf: &[u8]) -> NaiveTime {
     let minute = buf.get_u8();
     let seconds = buf.get_u8();
 
+    // whether microseconds are present is determined by the length of the value, not
+    // the `decimals` count of the column: a `DATETIME(6)` column still sends the short
+    // form when the fractional part is zero
     let micros = if len > 3 {
         // microseconds : int<EOF>
         buf.get_uint_le(buf.len())
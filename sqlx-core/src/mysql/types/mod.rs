@@ -82,6 +82,7 @@ mod bool;
 mod bytes;
 mod float;
 mod int;
+mod ipaddr;
 mod str;
 mod uint;
 
@@ -13,6 +13,9 @@ use crate::mysql::type_info::MySqlTypeInfo;
 use crate::mysql::{MySql, MySqlValueFormat, MySqlValueRef};
 use crate::types::Type;
 
+/// The MySQL `TIMESTAMP` type is stored in UTC and converted to/from the session
+/// `time_zone` on the wire, while `DATETIME` is naive and stored as-is; use
+/// [`PrimitiveDateTime`] for the latter.
 impl Type<MySql> for OffsetDateTime {
     fn type_info() -> MySqlTypeInfo {
         MySqlTypeInfo::binary(ColumnType::Timestamp)
@@ -23,6 +26,9 @@ impl Type<MySql> for OffsetDateTime {
     }
 }
 
+/// Note: assumes the connection's `time_zone` is set to `+00:00` (UTC); with any
+/// other session time zone the server will interpret the encoded wall-clock time in
+/// that zone instead.
 impl Encode<'_, MySql> for OffsetDateTime {
     fn encode_by_ref(&self, buf: &mut Vec<u8>) -> IsNull {
         let utc_dt = self.to_offset(UtcOffset::UTC);
@@ -32,6 +38,8 @@ impl Encode<'_, MySql> for OffsetDateTime {
     }
 }
 
+/// Note: assumes the connection's `time_zone` is set to `+00:00` (UTC). Values
+/// round-trip in any fixed session time zone, but the decoded offset is always UTC.
 impl<'r> Decode<'r, MySql> for OffsetDateTime {
     fn decode(value: MySqlValueRef<'r>) -> Result<Self, BoxDynError> {
         let primitive: PrimitiveDateTime = Decode::<MySql>::decode(value)?;
@@ -154,6 +162,8 @@ impl<'r> Decode<'r, MySql> for Date {
     }
 }
 
+/// `DATETIME` is naive: it stores the wall-clock value unchanged, independent of
+/// the session `time_zone`.
 impl Type<MySql> for PrimitiveDateTime {
     fn type_info() -> MySqlTypeInfo {
         MySqlTypeInfo::binary(ColumnType::Datetime)
@@ -271,6 +281,9 @@ fn decode_time(len: u8, mut buf: &[u8]) -> Result<Time, BoxDynError> {
     let minute = buf.get_u8();
     let seconds = buf.get_u8();
 
+    // whether microseconds are present is determined by the length of the value, not
+    // the `decimals` count of the column: a `DATETIME(6)` column still sends the short
+    // form when the fractional part is zero
     let micros = if len > 3 {
         // microseconds : int<EOF>
         buf.get_uint_le(buf.len())
@@ -1,7 +1,7 @@
 use std::fmt::{self, Debug, Formatter};
 use std::ops::{Deref, DerefMut};
 use std::sync::Arc;
-use std::time::Instant;
+use std::time::{Duration, Instant};
 
 use futures_intrusive::sync::SemaphoreReleaser;
 
@@ -23,6 +23,9 @@ pub struct PoolConnection<DB: Database> {
 pub(super) struct Live<DB: Database> {
     pub(super) raw: DB::Connection,
     pub(super) created: Instant,
+    /// Subtracted from the pool's `max_lifetime` for this connection so that
+    /// connections created together are not all recycled at the same instant.
+    pub(super) lifetime_jitter: Duration,
 }
 
 pub(super) struct Idle<DB: Database> {
@@ -176,11 +179,16 @@ impl<DB: Database> DerefMut for Idle<DB> {
 }
 
 impl<'s, DB: Database> Floating<'s, Live<DB>> {
-    pub fn new_live(conn: DB::Connection, guard: DecrementSizeGuard<'s>) -> Self {
+    pub fn new_live(
+        conn: DB::Connection,
+        guard: DecrementSizeGuard<'s>,
+        max_lifetime_jitter: Option<Duration>,
+    ) -> Self {
         Self {
             inner: Live {
                 raw: conn,
                 created: Instant::now(),
+                lifetime_jitter: max_lifetime_jitter.map_or(Duration::from_secs(0), random_jitter),
             },
             guard,
         }
@@ -263,3 +271,33 @@ impl<C> DerefMut for Floating<'_, C> {
         &mut self.inner
     }
 }
+
+/// A uniformly-random duration in `[0, max]`, without requiring a `rand` dependency.
+fn random_jitter(max: Duration) -> Duration {
+    use std::collections::hash_map::RandomState;
+    use std::hash::{BuildHasher, Hasher};
+
+    // `RandomState` is seeded from OS entropy and perturbs each instance,
+    // which is plenty for load spreading
+    let entropy = RandomState::new().build_hasher().finish();
+
+    max.mul_f64(entropy as f64 / u64::MAX as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::random_jitter;
+    use std::time::Duration;
+
+    #[test]
+    fn jitter_spreads_connection_lifetimes() {
+        let max = Duration::from_secs(30 * 60);
+
+        let jitters: Vec<_> = (0..16).map(|_| random_jitter(max)).collect();
+
+        assert!(jitters.iter().all(|jitter| *jitter <= max));
+
+        // connections created together should not all expire at the same instant
+        assert!(jitters.iter().any(|jitter| *jitter != jitters[0]));
+    }
+}
@@ -3,7 +3,7 @@ use crate::connection::ConnectOptions;
 use crate::connection::Connection;
 use crate::database::Database;
 use crate::error::Error;
-use crate::pool::{deadline_as_timeout, PoolOptions};
+use crate::pool::{deadline_as_timeout, AcquireOrder, AcquireOutcome, PoolOptions};
 use crossbeam_queue::ArrayQueue;
 
 use futures_intrusive::sync::{Semaphore, SemaphoreReleaser};
@@ -12,7 +12,7 @@ use std::cmp;
 use std::mem;
 use std::ptr;
 use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 
 use std::time::{Duration, Instant};
 
@@ -22,12 +22,72 @@ use std::time::{Duration, Instant};
 /// potentially overflowing the permits count in the semaphore itself.
 const WAKE_ALL_PERMITS: usize = usize::MAX / 2;
 
+/// Storage for the idle connections of a pool; the variant decides which connection
+/// `acquire()` sees next.
+pub(super) enum IdleQueue<DB: Database> {
+    /// Hand out connections in the order they were returned.
+    Fifo(ArrayQueue<Idle<DB>>),
+
+    /// Prefer the most recently returned connection.
+    Lifo {
+        stack: Mutex<Vec<Idle<DB>>>,
+        capacity: usize,
+    },
+}
+
+impl<DB: Database> IdleQueue<DB> {
+    fn new(order: AcquireOrder, capacity: usize) -> Self {
+        match order {
+            AcquireOrder::Fifo => IdleQueue::Fifo(ArrayQueue::new(capacity)),
+            AcquireOrder::Lifo => IdleQueue::Lifo {
+                stack: Mutex::new(Vec::with_capacity(capacity)),
+                capacity,
+            },
+        }
+    }
+
+    pub(super) fn len(&self) -> usize {
+        match self {
+            IdleQueue::Fifo(queue) => queue.len(),
+            IdleQueue::Lifo { stack, .. } => stack.lock().expect("idle queue poisoned").len(),
+        }
+    }
+
+    pub(super) fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    fn pop(&self) -> Option<Idle<DB>> {
+        match self {
+            IdleQueue::Fifo(queue) => queue.pop(),
+            IdleQueue::Lifo { stack, .. } => stack.lock().expect("idle queue poisoned").pop(),
+        }
+    }
+
+    fn push(&self, idle: Idle<DB>) -> Result<(), Idle<DB>> {
+        match self {
+            IdleQueue::Fifo(queue) => queue.push(idle),
+            IdleQueue::Lifo { stack, capacity } => {
+                let mut stack = stack.lock().expect("idle queue poisoned");
+
+                if stack.len() < *capacity {
+                    stack.push(idle);
+                    Ok(())
+                } else {
+                    Err(idle)
+                }
+            }
+        }
+    }
+}
+
 pub(crate) struct SharedPool<DB: Database> {
     pub(super) connect_options: <DB::Connection as Connection>::Options,
-    pub(super) idle_conns: ArrayQueue<Idle<DB>>,
+    pub(super) idle_conns: IdleQueue<DB>,
     pub(super) semaphore: Semaphore,
     pub(super) size: AtomicU32,
     is_closed: AtomicBool,
+    is_draining: AtomicBool,
     pub(super) options: PoolOptions<DB>,
 }
 
@@ -46,10 +106,11 @@ impl<DB: Database> SharedPool<DB> {
 
         let pool = Self {
             connect_options,
-            idle_conns: ArrayQueue::new(capacity),
+            idle_conns: IdleQueue::new(options.acquire_order, capacity),
             semaphore: Semaphore::new(options.fair, capacity),
             size: AtomicU32::new(0),
             is_closed: AtomicBool::new(false),
+            is_draining: AtomicBool::new(false),
             options,
         };
 
@@ -73,6 +134,14 @@ impl<DB: Database> SharedPool<DB> {
         self.is_closed.load(Ordering::Acquire)
     }
 
+    pub(super) fn is_draining(&self) -> bool {
+        self.is_draining.load(Ordering::Acquire)
+    }
+
+    pub(super) fn begin_drain(&self) {
+        self.is_draining.store(true, Ordering::Release);
+    }
+
     pub(super) async fn close(&self) {
         let already_closed = self.is_closed.swap(true, Ordering::AcqRel);
 
@@ -96,7 +165,7 @@ impl<DB: Database> SharedPool<DB> {
 
     #[inline]
     pub(super) fn try_acquire(&self) -> Option<Floating<'_, Idle<DB>>> {
-        if self.is_closed() {
+        if self.is_closed() || self.is_draining() {
             return None;
         }
 
@@ -123,6 +192,10 @@ impl<DB: Database> SharedPool<DB> {
             }
         }
 
+        if self.options.shrink_buffers_on_release {
+            floating.raw.shrink_buffers();
+        }
+
         let Floating { inner: idle, guard } = floating.into_idle();
 
         if !self.idle_conns.push(idle).is_ok() {
@@ -156,10 +229,36 @@ impl<DB: Database> SharedPool<DB> {
 
     #[allow(clippy::needless_lifetimes)]
     pub(super) async fn acquire<'s>(&'s self) -> Result<Floating<'s, Live<DB>>, Error> {
+        let started = Instant::now();
+        let result = self.acquire_inner().await;
+
+        // the callback runs on the acquiring task, outside of any internal locks
+        if let Some(callback) = &self.options.on_acquire {
+            let outcome = match &result {
+                Ok((_, true)) => AcquireOutcome::Opened,
+                Ok((_, false)) => AcquireOutcome::Reused,
+                Err(Error::PoolTimedOut) => AcquireOutcome::TimedOut,
+                Err(_) => AcquireOutcome::Error,
+            };
+
+            callback(started.elapsed(), outcome);
+        }
+
+        result.map(|(live, _)| live)
+    }
+
+    /// On success, the `bool` is `true` if a new connection was opened rather than
+    /// an idle one reused.
+    #[allow(clippy::needless_lifetimes)]
+    async fn acquire_inner<'s>(&'s self) -> Result<(Floating<'s, Live<DB>>, bool), Error> {
         if self.is_closed() {
             return Err(Error::PoolClosed);
         }
 
+        if self.is_draining() {
+            return Err(Error::PoolDraining);
+        }
+
         let deadline = Instant::now() + self.options.connect_timeout;
 
         sqlx_rt::timeout(
@@ -172,6 +271,10 @@ impl<DB: Database> SharedPool<DB> {
                         return Err(Error::PoolClosed);
                     }
 
+                    if self.is_draining() {
+                        return Err(Error::PoolDraining);
+                    }
+
                     // First attempt to pop a connection from the idle queue.
                     let guard = match self.pop_idle(permit) {
 
@@ -179,7 +282,7 @@ impl<DB: Database> SharedPool<DB> {
                         Ok(conn) => match check_conn(conn, &self.options).await {
 
                             // All good!
-                            Ok(live) => return Ok(live),
+                            Ok(live) => return Ok((live, false)),
 
                             // if the connection isn't usable for one reason or another,
                             // we get the `DecrementSizeGuard` back to open a new one
@@ -195,7 +298,7 @@ impl<DB: Database> SharedPool<DB> {
                     };
 
                     // Attempt to connect...
-                    return self.connection(deadline, guard).await;
+                    return self.connection(deadline, guard).await.map(|live| (live, true));
                 }
             }
         )
@@ -227,7 +330,7 @@ impl<DB: Database> SharedPool<DB> {
                         callback(&mut raw).await?;
                     }
 
-                    return Ok(Floating::new_live(raw, guard));
+                    return Ok(Floating::new_live(raw, guard, self.options.max_lifetime_jitter));
                 }
 
                 // an IO error while connecting is assumed to be the system starting up
@@ -260,9 +363,9 @@ impl<DB: Database> SharedPool<DB> {
 
 fn is_beyond_lifetime<DB: Database>(live: &Live<DB>, options: &PoolOptions<DB>) -> bool {
     // check if connection was within max lifetime (or not set)
-    options
-        .max_lifetime
-        .map_or(false, |max| live.created.elapsed() > max)
+    options.max_lifetime.map_or(false, |max| {
+        live.created.elapsed() > max.saturating_sub(live.lifetime_jitter)
+    })
 }
 
 fn is_beyond_idle<DB: Database>(idle: &Idle<DB>, options: &PoolOptions<DB>) -> bool {
@@ -312,14 +415,27 @@ async fn check_conn<'s: 'p, 'p, DB: Database>(
     Ok(conn.into_live())
 }
 
-/// if `max_lifetime` or `idle_timeout` is set, spawn a task that reaps senescent connections
-fn spawn_reaper<DB: Database>(pool: &Arc<SharedPool<DB>>) {
-    let period = match (pool.options.max_lifetime, pool.options.idle_timeout) {
-        (Some(it), None) | (None, Some(it)) => it,
-
-        (Some(a), Some(b)) => cmp::min(a, b),
+fn needs_keepalive<DB: Database>(idle: &Idle<DB>, options: &PoolOptions<DB>) -> bool {
+    // check if the connection has been idle long enough to warrant a keepalive ping
+    options
+        .keepalive_interval
+        .map_or(false, |interval| idle.since.elapsed() > interval)
+}
 
-        (None, None) => return,
+/// if `max_lifetime`, `idle_timeout` or `keepalive_interval` is set, spawn a task that
+/// reaps senescent connections and keeps the remaining idle connections alive
+fn spawn_reaper<DB: Database>(pool: &Arc<SharedPool<DB>>) {
+    let period = match [
+        pool.options.max_lifetime,
+        pool.options.idle_timeout,
+        pool.options.keepalive_interval,
+    ]
+    .iter()
+    .flatten()
+    .min()
+    {
+        Some(period) => *period,
+        None => return,
     };
 
     let pool = Arc::clone(&pool);
@@ -346,7 +462,19 @@ async fn do_reap<DB: Database>(pool: &SharedPool<DB>) {
             is_beyond_idle(conn, &pool.options) || is_beyond_lifetime(conn, &pool.options)
         });
 
-    for conn in keep {
+    for mut conn in keep {
+        if needs_keepalive(&conn, &pool.options) {
+            // ping connections that have been idle past the keepalive interval
+            // so the server (or anything in between) doesn't hang up on them
+            if let Err(e) = conn.ping().await {
+                // the connection is already dead; discard it instead of
+                // returning it to the pool
+                log::info!("keepalive ping on idle connection returned error: {}", e);
+                let _ = conn.close().await;
+                continue;
+            }
+        }
+
         // return valid connections to the pool first
         pool.release(conn.into_live());
     }
@@ -61,6 +61,7 @@ use crate::connection::Connection;
 use crate::database::Database;
 use crate::error::Error;
 use crate::transaction::Transaction;
+use futures_core::future::BoxFuture;
 use std::fmt;
 use std::future::Future;
 use std::sync::Arc;
@@ -78,7 +79,54 @@ mod options;
 
 pub use self::connection::PoolConnection;
 pub(crate) use self::maybe::MaybePoolConnection;
-pub use self::options::PoolOptions;
+pub use self::options::{AcquireOrder, AcquireOutcome, PoolOptions};
+
+/// Controls how many times [`Pool::transaction_with_retry`] re-runs its closure after a
+/// retryable failure such as a serialization error or a deadlock.
+#[derive(Debug, Clone, Copy)]
+pub struct RetryPolicy {
+    max_retries: u32,
+}
+
+impl RetryPolicy {
+    /// Retry up to `max_retries` times after the initial attempt.
+    pub fn new(max_retries: u32) -> Self {
+        Self { max_retries }
+    }
+
+    /// The maximum number of retries after the initial attempt.
+    pub fn max_retries(&self) -> u32 {
+        self.max_retries
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self { max_retries: 3 }
+    }
+}
+
+/// Returns `true` for errors that indicate the transaction should be re-run from the top:
+/// serialization failures, deadlocks, and (for SQLite) a locked database.
+fn is_retryable_error(error: &Error) -> bool {
+    let code = match error {
+        Error::Database(error) => match error.code() {
+            Some(code) => code.into_owned(),
+            None => return false,
+        },
+
+        _ => return false,
+    };
+
+    match code.as_str() {
+        // [postgres, mysql] serialization_failure; [postgres] deadlock_detected
+        "40001" | "40P01" => true,
+
+        // [sqlite] SQLITE_BUSY and SQLITE_LOCKED, including their extended variants
+        // which carry the primary code in the least significant byte
+        _ => matches!(code.parse::<i32>(), Ok(code) if matches!(code & 0xFF, 5 | 6)),
+    }
+}
 
 /// An asynchronous pool of SQLx database connections.
 ///
@@ -292,6 +340,53 @@ impl<DB: Database> Pool<DB> {
         }
     }
 
+    /// Execute the function inside a transaction, retrying on serialization failures.
+    ///
+    /// If the function returns an error, the transaction is rolled back; when the error (or a
+    /// failed commit) is a retryable serialization/deadlock failure, the whole function is
+    /// re-run in a fresh transaction, up to the limit set by the [`RetryPolicy`]. Any other
+    /// error aborts immediately.
+    ///
+    /// Note that the function may be executed multiple times; side effects outside of the
+    /// transaction itself should be idempotent.
+    pub async fn transaction_with_retry<F, R>(
+        &self,
+        policy: RetryPolicy,
+        callback: F,
+    ) -> Result<R, Error>
+    where
+        for<'c> F: Fn(&'c mut Transaction<'static, DB>) -> BoxFuture<'c, Result<R, Error>>
+            + Send
+            + Sync,
+        R: Send,
+    {
+        let mut retries = 0;
+
+        loop {
+            let mut transaction = self.begin().await?;
+
+            let error = match callback(&mut transaction).await {
+                Ok(ret) => match transaction.commit().await {
+                    Ok(()) => return Ok(ret),
+
+                    Err(error) => error,
+                },
+
+                Err(error) => {
+                    transaction.rollback().await?;
+
+                    error
+                }
+            };
+
+            if retries >= policy.max_retries || !is_retryable_error(&error) {
+                return Err(error);
+            }
+
+            retries += 1;
+        }
+    }
+
     /// Shut down the connection pool, waiting for all connections to be gracefully closed.
     ///
     /// Upon `.await`ing this call, any currently waiting or subsequent calls to [Pool::acquire] and
@@ -319,6 +414,39 @@ impl<DB: Database> Pool<DB> {
         self.0.close().await;
     }
 
+    /// Stop accepting new acquisitions and close the pool once every checked-out
+    /// connection has been returned.
+    ///
+    /// The draining state takes effect immediately when this method is called: any
+    /// subsequent call to [`acquire()`][Pool::acquire] fails with [`Error::PoolDraining`],
+    /// while connections already checked out remain fully usable (e.g. to commit an
+    /// in-flight transaction) until they are returned.
+    ///
+    /// The returned future resolves once the pool has closed gracefully. If `timeout`
+    /// elapses first, the pool is marked closed without waiting for the remaining
+    /// connections; they are discarded as they are returned.
+    pub fn begin_drain(
+        &self,
+        timeout: impl Into<Option<Duration>>,
+    ) -> impl Future<Output = ()> + 'static {
+        // reject new acquisitions eagerly, before the returned future is first polled
+        self.0.begin_drain();
+
+        let pool = self.0.clone();
+        let timeout = timeout.into();
+
+        async move {
+            match timeout {
+                Some(timeout) => {
+                    // `close()` marks the pool closed on its first poll; on timeout we
+                    // simply stop waiting for stragglers
+                    let _ = sqlx_rt::timeout(timeout, pool.close()).await;
+                }
+                None => pool.close().await,
+            }
+        }
+    }
+
     /// Returns `true` if [`.close()`][Pool::close] has been called on the pool, `false` otherwise.
     pub fn is_closed(&self) -> bool {
         self.0.is_closed()
@@ -348,6 +476,13 @@ impl Pool<Any> {
     pub fn any_kind(&self) -> AnyKind {
         self.0.connect_options.kind()
     }
+
+    /// Returns the name of the database backend in use, e.g. "SQLite".
+    ///
+    /// Determined by the connection URI.
+    pub fn backend_name(&self) -> &'static str {
+        self.any_kind().backend_name()
+    }
 }
 
 /// Returns a new [Pool] tied to the same shared connection pool.
@@ -10,8 +10,39 @@ use std::fmt::{self, Debug, Formatter};
 use std::sync::Arc;
 use std::time::{Duration, Instant};
 
+/// The order in which idle connections are handed out by [`Pool::acquire`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AcquireOrder {
+    /// First-in, first-out; connections are handed out in the order they were
+    /// returned to the pool, spreading load evenly over the whole pool.
+    Fifo,
+
+    /// Last-in, first-out; the most recently returned connection is preferred,
+    /// keeping a small hot set of connections busy while the rest sit idle
+    /// (and eventually age out through [`idle_timeout`][PoolOptions::idle_timeout]).
+    Lifo,
+}
+
+/// The result of a [`Pool::acquire`] attempt, as reported to the callback
+/// registered with [`PoolOptions::on_acquire`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AcquireOutcome {
+    /// An idle connection was handed out.
+    Reused,
+
+    /// A new connection was opened to satisfy the acquisition.
+    Opened,
+
+    /// The acquire timed out waiting for a connection.
+    TimedOut,
+
+    /// The acquire failed with some other error.
+    Error,
+}
+
 pub struct PoolOptions<DB: Database> {
     pub(crate) test_before_acquire: bool,
+    pub(crate) shrink_buffers_on_release: bool,
     pub(crate) after_connect: Option<
         Box<
             dyn Fn(&mut DB::Connection) -> BoxFuture<'_, Result<(), Error>> + 'static + Send + Sync,
@@ -27,11 +58,15 @@ pub struct PoolOptions<DB: Database> {
     >,
     pub(crate) after_release:
         Option<Box<dyn Fn(&mut DB::Connection) -> bool + 'static + Send + Sync>>,
+    pub(crate) on_acquire: Option<Box<dyn Fn(Duration, AcquireOutcome) + 'static + Send + Sync>>,
     pub(crate) max_connections: u32,
     pub(crate) connect_timeout: Duration,
     pub(crate) min_connections: u32,
     pub(crate) max_lifetime: Option<Duration>,
+    pub(crate) max_lifetime_jitter: Option<Duration>,
     pub(crate) idle_timeout: Option<Duration>,
+    pub(crate) keepalive_interval: Option<Duration>,
+    pub(crate) acquire_order: AcquireOrder,
     pub(crate) fair: bool,
 }
 
@@ -46,13 +81,18 @@ impl<DB: Database> PoolOptions<DB> {
         Self {
             after_connect: None,
             test_before_acquire: true,
+            shrink_buffers_on_release: false,
             before_acquire: None,
             after_release: None,
+            on_acquire: None,
             max_connections: 10,
             min_connections: 0,
             connect_timeout: Duration::from_secs(30),
             idle_timeout: Some(Duration::from_secs(10 * 60)),
             max_lifetime: Some(Duration::from_secs(30 * 60)),
+            max_lifetime_jitter: None,
+            keepalive_interval: None,
+            acquire_order: AcquireOrder::Fifo,
             fair: true,
         }
     }
@@ -104,6 +144,22 @@ impl<DB: Database> PoolOptions<DB> {
         self
     }
 
+    /// Randomize each connection's [`max_lifetime`] by subtracting up to this duration from it.
+    ///
+    /// Without jitter, a pool whose connections were all created together (e.g. at startup via
+    /// [`min_connections`]) will try to recycle all of them at once when the lifetime elapses,
+    /// causing a reconnect storm. With jitter, each connection's effective maximum lifetime is
+    /// chosen uniformly from `[max_lifetime - jitter, max_lifetime]` so recycling is spread out.
+    ///
+    /// Has no effect unless [`max_lifetime`] is also set.
+    ///
+    /// [`max_lifetime`]: Self::max_lifetime
+    /// [`min_connections`]: Self::min_connections
+    pub fn max_lifetime_jitter(mut self, jitter: impl Into<Option<Duration>>) -> Self {
+        self.max_lifetime_jitter = jitter.into();
+        self
+    }
+
     /// Set a maximum idle duration for individual connections.
     ///
     /// Any connection with an idle duration longer than this will be closed.
@@ -114,6 +170,32 @@ impl<DB: Database> PoolOptions<DB> {
         self
     }
 
+    /// Set the order in which idle connections are handed out by [`Pool::acquire`].
+    ///
+    /// Defaults to [`AcquireOrder::Fifo`], which rotates through every connection in the
+    /// pool. [`AcquireOrder::Lifo`] instead prefers the most recently returned connection,
+    /// which improves cache locality on the server side and lets the rest of the pool go
+    /// idle and be reaped when concurrency is low.
+    pub fn acquire_order(mut self, order: AcquireOrder) -> Self {
+        self.acquire_order = order;
+        self
+    }
+
+    /// Set an interval at which idle connections are pinged in the background.
+    ///
+    /// Any connection that has been sitting idle in the pool for longer than this will be
+    /// checked with a call to [`Connection::ping`]; connections that fail the ping are
+    /// discarded. This keeps otherwise-idle connections from being silently dropped by the
+    /// server or an aggressive NAT/firewall between it and the application.
+    ///
+    /// Connections that are checked out are never touched.
+    ///
+    /// When set to `None` (the default), idle connections are not pinged.
+    pub fn keepalive_interval(mut self, interval: impl Into<Option<Duration>>) -> Self {
+        self.keepalive_interval = interval.into();
+        self
+    }
+
     /// If true, the health of a connection will be verified by a call to [`Connection::ping`]
     /// before returning the connection.
     ///
@@ -123,6 +205,19 @@ impl<DB: Database> PoolOptions<DB> {
         self
     }
 
+    /// If set to `true`, a connection's internal buffers are shrunk with
+    /// [`Connection::shrink_buffers`] as it is returned to the pool.
+    ///
+    /// This keeps a connection that served one large query from holding onto an
+    /// oversized buffer while it sits idle, at the cost of reallocating the buffer
+    /// the next time a large query is run on it.
+    ///
+    /// Defaults to `false`.
+    pub fn shrink_buffers_on_release(mut self, shrink: bool) -> Self {
+        self.shrink_buffers_on_release = shrink;
+        self
+    }
+
     /// If set to `true`, calls to `acquire()` are fair and connections  are issued
     /// in first-come-first-serve order. If `false`, "drive-by" tasks may steal idle connections
     /// ahead of tasks that have been waiting.
@@ -189,6 +284,19 @@ impl<DB: Database> PoolOptions<DB> {
         self
     }
 
+    /// Register a callback invoked after every [`Pool::acquire`] attempt with the time
+    /// spent waiting and the [`AcquireOutcome`], for latency tracing of pool saturation.
+    ///
+    /// The callback is invoked outside of the pool's internal locks, but it does run on
+    /// the acquiring task, so it should return promptly.
+    pub fn on_acquire<F>(mut self, callback: F) -> Self
+    where
+        F: Fn(Duration, AcquireOutcome) + 'static + Send + Sync,
+    {
+        self.on_acquire = Some(Box::new(callback));
+        self
+    }
+
     /// Creates a new pool from this configuration and immediately establishes one connection.
     pub async fn connect(self, uri: &str) -> Result<Pool<DB>, Error> {
         self.connect_with(uri.parse()?).await
@@ -251,8 +359,12 @@ impl<DB: Database> Debug for PoolOptions<DB> {
             .field("min_connections", &self.min_connections)
             .field("connect_timeout", &self.connect_timeout)
             .field("max_lifetime", &self.max_lifetime)
+            .field("max_lifetime_jitter", &self.max_lifetime_jitter)
             .field("idle_timeout", &self.idle_timeout)
+            .field("keepalive_interval", &self.keepalive_interval)
+            .field("acquire_order", &self.acquire_order)
             .field("test_before_acquire", &self.test_before_acquire)
+            .field("shrink_buffers_on_release", &self.shrink_buffers_on_release)
             .finish()
     }
 }
@@ -3,6 +3,7 @@ use crate::HashMap;
 use crate::common::StatementCache;
 use crate::error::Error;
 use crate::io::Decode;
+use crate::metrics::ConnectionMetrics;
 use crate::postgres::connection::{sasl, stream::PgStream, tls};
 use crate::postgres::message::{
     Authentication, BackendKeyData, MessageFormat, Password, ReadyForQuery, Startup,
@@ -144,9 +145,13 @@ impl PgConnection {
             pending_ready_for_query_count: 0,
             next_statement_id: 1,
             cache_statement: StatementCache::new(options.statement_cache_capacity),
+            cache_describe: StatementCache::new(options.describe_cache_capacity),
             cache_type_oid: HashMap::new(),
             cache_type_info: HashMap::new(),
             log_settings: options.log_settings.clone(),
+            metrics: ConnectionMetrics::default(),
+            pending_deferred_sync: false,
+            fetch_buffer_size: 0,
         })
     }
 }
@@ -64,7 +64,7 @@ async fn prepare(
     // indicates that the SQL query string is now successfully parsed and has semantic validity
     let _ = conn
         .stream
-        .recv_expect(MessageFormat::ParseComplete)
+        .recv_expect::<()>(MessageFormat::ParseComplete)
         .await?;
 
     let metadata = if let Some(metadata) = metadata {
@@ -158,6 +158,9 @@ impl PgConnection {
 
         // all SYNC messages will return a ReadyForQuery
         self.pending_ready_for_query_count += 1;
+
+        // a deferred [Sync] is no longer pending once one is written
+        self.pending_deferred_sync = false;
     }
 
     async fn get_or_prepare<'a>(
@@ -199,7 +202,7 @@ impl PgConnection {
         persistent: bool,
         metadata_opt: Option<Arc<PgStatementMetadata>>,
     ) -> Result<impl Stream<Item = Result<Either<PgQueryResult, PgRow>, Error>> + 'e, Error> {
-        let mut logger = QueryLogger::new(query, self.log_settings.clone());
+        let mut logger = QueryLogger::new(query, self.log_settings.clone(), self.metrics.clone());
 
         // before we continue, wait until we are "ready" to accept more queries
         self.wait_until_ready().await?;
@@ -232,19 +235,35 @@ impl PgConnection {
                 result_formats: &[PgValueFormat::Binary],
             });
 
+            // an unlimited fetch respects the connection's buffered-fetch setting;
+            // an explicit limit (fetch_optional) takes precedence
+            let fetch_buffer = if limit == 0 { self.fetch_buffer_size } else { 0 };
+
             // executes the portal up to the passed limit
             // the protocol-level limit acts nearly identically to the `LIMIT` in SQL
             self.stream.write(message::Execute {
                 portal: None,
-                limit: limit.into(),
+                limit: if fetch_buffer > 0 {
+                    fetch_buffer
+                } else {
+                    limit.into()
+                },
             });
 
-            // finally, [Sync] asks postgres to process the messages that we sent and respond with
-            // a [ReadyForQuery] message when it's completely done. Theoretically, we could send
-            // dozens of queries before a [Sync] and postgres can handle that. Execution on the server
-            // is still serial but it would reduce round-trips. Some kind of builder pattern that is
-            // termed batching might suit this.
-            self.write_sync();
+            if fetch_buffer > 0 {
+                // a [Sync] would end the implicit transaction and destroy the suspended
+                // portal; defer it until the portal completes. `wait_until_ready` sends
+                // it on our behalf if the stream is dropped before that.
+                self.pending_deferred_sync = true;
+            } else {
+                // finally, [Sync] asks postgres to process the messages that we sent and respond with
+                // a [ReadyForQuery] message when it's completely done. Theoretically, we could send
+                // dozens of queries before a [Sync] and postgres can handle that. Execution on the server
+                // is still serial but it would reduce round-trips. Some kind of builder pattern that is
+                // termed batching might suit this.
+                self.write_sync();
+            }
+
 
             // prepared statements are binary
             PgValueFormat::Binary
@@ -274,7 +293,25 @@ impl PgConnection {
                         // harmless messages to ignore
                     }
 
+                    MessageFormat::PortalSuspended if self.pending_deferred_sync => {
+                        // the server sent `fetch_buffer_size` rows and paused the
+                        // portal; ask for the next batch
+                        self.stream.write(message::Execute {
+                            portal: None,
+                            limit: self.fetch_buffer_size,
+                        });
+
+                        self.stream.flush().await?;
+                    }
+
                     MessageFormat::CommandComplete => {
+                        // the portal is complete; send the deferred [Sync] so the
+                        // server finishes up and reports ReadyForQuery
+                        if self.pending_deferred_sync {
+                            self.write_sync();
+                            self.stream.flush().await?;
+                        }
+
                         // a SQL command completed normally
                         let cc: CommandComplete = message.decode()?;
 
@@ -419,15 +456,26 @@ impl<'c> Executor<'c> for &'c mut PgConnection {
         Box::pin(async move {
             self.wait_until_ready().await?;
 
+            if let Some(describe) = self.cache_describe.get_mut(sql) {
+                return Ok(describe.clone());
+            }
+
             let (stmt_id, metadata) = self.get_or_prepare(sql, &[], true, None).await?;
 
             let nullable = self.get_nullable_for_columns(stmt_id, &metadata).await?;
 
-            Ok(Describe {
+            let describe = Describe {
                 columns: metadata.columns.clone(),
                 nullable,
                 parameters: Some(Either::Left(metadata.parameters.clone())),
-            })
+                read_only: None,
+            };
+
+            if self.cache_describe.is_enabled() {
+                self.cache_describe.insert(sql, describe.clone());
+            }
+
+            Ok(describe)
         })
     }
 }
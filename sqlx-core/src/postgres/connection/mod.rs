@@ -7,10 +7,12 @@ use futures_util::{FutureExt, TryFutureExt};
 
 use crate::common::StatementCache;
 use crate::connection::{Connection, LogSettings};
+use crate::describe::Describe;
 use crate::error::Error;
 use crate::executor::Executor;
 use crate::ext::ustr::UStr;
 use crate::io::Decode;
+use crate::metrics::ConnectionMetrics;
 use crate::postgres::message::{
     Close, Message, MessageFormat, ReadyForQuery, Terminate, TransactionStatus,
 };
@@ -51,6 +53,9 @@ pub struct PgConnection {
     // cache statement by query string to the id and columns
     cache_statement: StatementCache<(u32, Arc<PgStatementMetadata>)>,
 
+    // cache describe results by query string; disabled unless opted in
+    cache_describe: StatementCache<Describe<Postgres>>,
+
     // cache user-defined types by id <-> info
     cache_type_info: HashMap<u32, PgTypeInfo>,
     cache_type_oid: HashMap<UStr, u32>,
@@ -58,16 +63,31 @@ pub struct PgConnection {
     // number of ReadyForQuery messages that we are currently expecting
     pub(crate) pending_ready_for_query_count: usize,
 
+    // a buffered fetch deferred its [Sync] until the portal completes; if the stream
+    // is dropped early the [Sync] still needs to be sent for the server to recover
+    pub(crate) pending_deferred_sync: bool,
+
+    // protocol-level row limit per Execute for buffered fetches; zero is unbounded
+    pub(crate) fetch_buffer_size: u32,
+
     // current transaction status
     transaction_status: TransactionStatus,
     pub(crate) transaction_depth: usize,
 
     log_settings: LogSettings,
+
+    metrics: ConnectionMetrics,
 }
 
 impl PgConnection {
     // will return when the connection is ready for another query
     pub(in crate::postgres) async fn wait_until_ready(&mut self) -> Result<(), Error> {
+        if self.pending_deferred_sync {
+            // a buffered fetch ended (or was dropped) before its portal completed;
+            // the server is still waiting on the [Sync]
+            self.write_sync();
+        }
+
         if !self.stream.wbuf.is_empty() {
             self.stream.flush().await?;
         }
@@ -101,6 +121,49 @@ impl PgConnection {
 
         Ok(())
     }
+
+    /// Set the maximum number of rows the server sends per round-trip for
+    /// subsequent prepared-statement fetches on this connection.
+    ///
+    /// By default the server streams every row of a result as fast as the network
+    /// allows, which can buffer an unbounded amount of data ahead of a slow
+    /// consumer. With a limit set, each protocol-level `Execute` asks for at most
+    /// `size` rows and a follow-up `Execute` is issued as the stream is consumed,
+    /// bounding how far the server runs ahead.
+    ///
+    /// Smaller sizes trade throughput (one round-trip per batch) for a lower
+    /// memory ceiling. A size of `0` restores the default, unbounded behavior.
+    /// Simple (unprepared) queries and [`fetch_optional`][crate::executor::Executor::fetch_optional]
+    /// are unaffected.
+    pub fn set_fetch_buffer_size(&mut self, size: u32) {
+        self.fetch_buffer_size = size;
+    }
+
+    /// Estimate the number of rows `sql` would return, without executing it.
+    ///
+    /// This runs `EXPLAIN (FORMAT JSON)` and extracts the planner's row estimate from
+    /// the top plan node. It is exactly that — an *estimate*; its accuracy depends on
+    /// the table statistics available to the planner and it carries no guarantee.
+    #[cfg(feature = "json")]
+    pub async fn estimate_rows(
+        &mut self,
+        sql: &str,
+        arguments: super::PgArguments,
+    ) -> Result<u64, Error> {
+        use crate::row::Row;
+
+        let row = crate::query::query_with(&*format!("EXPLAIN (FORMAT JSON) {}", sql), arguments)
+            .fetch_one(&mut *self)
+            .await?;
+
+        let plan: serde_json::Value = row.try_get(0)?;
+
+        plan.get(0)
+            .and_then(|node| node.get("Plan"))
+            .and_then(|plan| plan.get("Plan Rows"))
+            .and_then(serde_json::Value::as_u64)
+            .ok_or_else(|| err_protocol!("unexpected EXPLAIN (FORMAT JSON) output: {}", plan))
+    }
 }
 
 impl Debug for PgConnection {
@@ -151,6 +214,8 @@ impl Connection for PgConnection {
 
             self.wait_until_ready().await?;
 
+            self.cache_describe.clear();
+
             while let Some((id, _)) = self.cache_statement.remove_lru() {
                 self.stream.write(Close::Statement(id));
                 cleared += 1;
@@ -173,6 +238,14 @@ impl Connection for PgConnection {
         self.wait_until_ready().boxed()
     }
 
+    fn metrics(&self) -> ConnectionMetrics {
+        self.metrics.clone()
+    }
+
+    fn shrink_buffers(&mut self) {
+        self.stream.shrink_buffers();
+    }
+
     #[doc(hidden)]
     fn should_flush(&self) -> bool {
         !self.stream.wbuf.is_empty()
@@ -31,6 +31,16 @@ impl PgConnection {
         PgCopyIn::begin(self, statement).await
     }
 
+    /// Like [`copy_in_raw`][Self::copy_in_raw], but appends the `WITH (...)` clause
+    /// described by `options` to the statement.
+    pub async fn copy_in_raw_with(
+        &mut self,
+        statement: &str,
+        options: PgCopyOptions,
+    ) -> Result<PgCopyIn<&mut Self>> {
+        PgCopyIn::begin(self, &options.apply(statement)?).await
+    }
+
     /// Issue a `COPY TO STDOUT` statement and transition the connection to streaming data
     /// from Postgres. This is a more efficient way to export data from Postgres but
     /// arrives in chunks of one of a few data formats (text/CSV/binary).
@@ -57,6 +67,17 @@ impl PgConnection {
     ) -> Result<BoxStream<'c, Result<Bytes>>> {
         pg_begin_copy_out(self, statement).await
     }
+
+    /// Like [`copy_out_raw`][Self::copy_out_raw], but appends the `WITH (...)` clause
+    /// described by `options` to the statement.
+    #[allow(clippy::needless_lifetimes)]
+    pub async fn copy_out_raw_with<'c>(
+        &'c mut self,
+        statement: &str,
+        options: PgCopyOptions,
+    ) -> Result<BoxStream<'c, Result<Bytes>>> {
+        pg_begin_copy_out(self, &options.apply(statement)?).await
+    }
 }
 
 impl Pool<Postgres> {
@@ -103,6 +124,75 @@ impl Pool<Postgres> {
     }
 }
 
+
+/// Options for text-format `COPY`, emitted as a `WITH (...)` clause.
+///
+/// Created with [`PgCopyOptions::new`] and passed to
+/// [`PgConnection::copy_in_raw_with`] or [`PgConnection::copy_out_raw_with`].
+#[derive(Debug, Clone, Default)]
+pub struct PgCopyOptions {
+    delimiter: Option<char>,
+    null: Option<String>,
+}
+
+impl PgCopyOptions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Set the character that separates columns within each row.
+    ///
+    /// Postgres requires this to be a single one-byte character; anything wider is
+    /// rejected when the `COPY` begins.
+    pub fn delimiter(mut self, delimiter: char) -> Self {
+        self.delimiter = Some(delimiter);
+        self
+    }
+
+    /// Set the string that represents a `NULL` value, e.g. `\N` or the empty string.
+    pub fn null(mut self, null: impl Into<String>) -> Self {
+        self.null = Some(null.into());
+        self
+    }
+
+    /// Append the `WITH (...)` clause for these options to `statement`.
+    fn apply(&self, statement: &str) -> Result<String> {
+        let mut with = Vec::new();
+
+        if let Some(delimiter) = self.delimiter {
+            if delimiter.len_utf8() > 1 {
+                return Err(Error::Configuration(
+                    format!(
+                        "COPY delimiter must be a single one-byte character, got {:?}",
+                        delimiter
+                    )
+                    .into(),
+                ));
+            }
+
+            with.push(format!("DELIMITER '{}'", escape_literal(delimiter)));
+        }
+
+        if let Some(null) = &self.null {
+            with.push(format!("NULL '{}'", null.replace('\'', "''")));
+        }
+
+        if with.is_empty() {
+            Ok(statement.to_owned())
+        } else {
+            Ok(format!("{} WITH ({})", statement.trim_end(), with.join(", ")))
+        }
+    }
+}
+
+fn escape_literal(c: char) -> String {
+    if c == '\'' {
+        "''".to_owned()
+    } else {
+        c.to_string()
+    }
+}
+
 /// A connection in streaming `COPY FROM STDIN` mode.
 ///
 /// Created by [PgConnection::copy_in_raw] or [Pool::copy_out_raw].
@@ -258,7 +348,7 @@ impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
                     Some(Cow::Borrowed("57014")) => {
                         // postgres abort received error code
                         conn.stream
-                            .recv_expect(MessageFormat::ReadyForQuery)
+                            .recv_expect::<()>(MessageFormat::ReadyForQuery)
                             .await?;
                         Ok(())
                     }
@@ -285,7 +375,7 @@ impl<C: DerefMut<Target = PgConnection>> PgCopyIn<C> {
             .await?;
 
         conn.stream
-            .recv_expect(MessageFormat::ReadyForQuery)
+            .recv_expect::<()>(MessageFormat::ReadyForQuery)
             .await?;
 
         Ok(cc.rows_affected())
@@ -321,8 +411,8 @@ async fn pg_begin_copy_out<'c, C: DerefMut<Target = PgConnection> + Send + 'c>(
                 MessageFormat::CopyData => r#yield!(msg.decode::<CopyData<Bytes>>()?.0),
                 MessageFormat::CopyDone => {
                     let _ = msg.decode::<CopyDone>()?;
-                    conn.stream.recv_expect(MessageFormat::CommandComplete).await?;
-                    conn.stream.recv_expect(MessageFormat::ReadyForQuery).await?;
+                    conn.stream.recv_expect::<CommandComplete>(MessageFormat::CommandComplete).await?;
+                    conn.stream.recv_expect::<()>(MessageFormat::ReadyForQuery).await?;
                     return Ok(())
                 },
                 _ => return Err(err_protocol!("unexpected message format during copy out: {:?}", msg.format))
@@ -332,3 +422,40 @@ async fn pg_begin_copy_out<'c, C: DerefMut<Target = PgConnection> + Send + 'c>(
 
     Ok(Box::pin(stream))
 }
+
+#[test]
+fn test_copy_options_build_with_clause() {
+    let statement = PgCopyOptions::new()
+        .delimiter('|')
+        .null("")
+        .apply("COPY users (id, name) FROM STDIN")
+        .unwrap();
+
+    assert_eq!(
+        statement,
+        "COPY users (id, name) FROM STDIN WITH (DELIMITER '|', NULL '')"
+    );
+}
+
+#[test]
+fn test_copy_options_escape_quotes() {
+    let statement = PgCopyOptions::new()
+        .null("it's null")
+        .apply("COPY users FROM STDIN")
+        .unwrap();
+
+    assert_eq!(
+        statement,
+        "COPY users FROM STDIN WITH (NULL 'it''s null')"
+    );
+}
+
+#[test]
+fn test_copy_options_reject_wide_delimiter() {
+    let err = PgCopyOptions::new()
+        .delimiter('é')
+        .apply("COPY users FROM STDIN")
+        .unwrap_err();
+
+    assert!(matches!(err, Error::Configuration(_)));
+}
@@ -24,6 +24,12 @@ impl Database for Postgres {
     type TypeInfo = PgTypeInfo;
 
     type Value = PgValue;
+
+    fn fmt_placeholder(sql: &mut String, index: usize) {
+        use std::fmt::Write;
+
+        let _ = write!(sql, "${}", index);
+    }
 }
 
 impl<'r> HasValueRef<'r> for Postgres {
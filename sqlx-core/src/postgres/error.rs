@@ -188,4 +188,20 @@ impl DatabaseError for PgDatabaseError {
     fn constraint(&self) -> Option<&str> {
         self.constraint()
     }
+
+    fn is_unique_violation(&self) -> bool {
+        self.code() == "23505"
+    }
+
+    fn is_foreign_key_violation(&self) -> bool {
+        self.code() == "23503"
+    }
+
+    fn is_check_violation(&self) -> bool {
+        self.code() == "23514"
+    }
+
+    fn is_not_null_violation(&self) -> bool {
+        self.code() == "23502"
+    }
 }
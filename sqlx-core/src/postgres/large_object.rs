@@ -0,0 +1,134 @@
+use crate::connection::Connection;
+use crate::error::Result;
+use crate::ext::async_stream::TryAsyncStream;
+use crate::postgres::connection::PgConnection;
+use crate::query_scalar::query_scalar;
+use bytes::Bytes;
+use futures_core::stream::BoxStream;
+use sqlx_rt::{AsyncRead, AsyncReadExt};
+
+// `INV_READ`/`INV_WRITE` from libpq's `libpq-fs.h`; passed to `lo_open`.
+const INV_READ: i32 = 0x40000;
+const INV_WRITE: i32 = 0x20000;
+
+// how many bytes each `loread` call asks the server for
+const READ_CHUNK_SIZE: i32 = 64 * 1024;
+
+// how many bytes are sent per `lowrite` call
+const WRITE_CHUNK_SIZE: usize = 64 * 1024;
+
+impl PgConnection {
+    /// Stream the contents of a [large object] out of the database in chunks,
+    /// without materializing the whole value in memory.
+    ///
+    /// Large object descriptors are only valid for the duration of a transaction,
+    /// so this must be called on a connection with an open transaction; the stream
+    /// must also be consumed before that transaction ends.
+    ///
+    /// `oid` is the object's OID, e.g. as stored in an `oid` column or returned
+    /// by `lo_creat`/`lo_from_bytea`.
+    ///
+    /// [large object]: https://www.postgresql.org/docs/current/largeobjects.html
+    pub async fn read_large_object(&mut self, oid: u32) -> Result<BoxStream<'_, Result<Bytes>>> {
+        let fd: i32 = query_scalar("SELECT lo_open($1, $2)")
+            .bind(oid)
+            .bind(INV_READ)
+            .fetch_one(&mut *self)
+            .await?;
+
+        let stream: TryAsyncStream<'_, Bytes> = try_stream! {
+            loop {
+                let chunk: Vec<u8> = query_scalar("SELECT loread($1, $2)")
+                    .bind(fd)
+                    .bind(READ_CHUNK_SIZE)
+                    .fetch_one(&mut *self)
+                    .await?;
+
+                let done = chunk.len() < READ_CHUNK_SIZE as usize;
+
+                if !chunk.is_empty() {
+                    r#yield!(Bytes::from(chunk));
+                }
+
+                if done {
+                    let _: i32 = query_scalar("SELECT lo_close($1)")
+                        .bind(fd)
+                        .fetch_one(&mut *self)
+                        .await?;
+
+                    return Ok(());
+                }
+            }
+        };
+
+        Ok(Box::pin(stream))
+    }
+
+    /// Create a [large object] and stream the contents of `source` into it in chunks,
+    /// returning the OID of the new object.
+    ///
+    /// The object is created and written within a transaction (large object
+    /// descriptors require one). If reading from `source` or writing to the server
+    /// fails, the transaction is rolled back, which also discards the partially
+    /// written object.
+    ///
+    /// [large object]: https://www.postgresql.org/docs/current/largeobjects.html
+    pub async fn write_large_object(
+        &mut self,
+        mut source: impl AsyncRead + Unpin,
+    ) -> Result<u32> {
+        let mut tx = Connection::begin(self).await?;
+
+        let oid: u32 = query_scalar("SELECT lo_creat(-1)").fetch_one(&mut tx).await?;
+
+        match write_from(&mut *tx, oid, &mut source).await {
+            Ok(()) => {
+                tx.commit().await?;
+
+                Ok(oid)
+            }
+
+            Err(error) => {
+                // rolling back also undoes the `lo_creat` above
+                tx.rollback().await?;
+
+                Err(error)
+            }
+        }
+    }
+}
+
+async fn write_from(
+    conn: &mut PgConnection,
+    oid: u32,
+    source: &mut (impl AsyncRead + Unpin),
+) -> Result<()> {
+    let fd: i32 = query_scalar("SELECT lo_open($1, $2)")
+        .bind(oid)
+        .bind(INV_WRITE)
+        .fetch_one(&mut *conn)
+        .await?;
+
+    let mut buf = vec![0_u8; WRITE_CHUNK_SIZE];
+
+    loop {
+        let read = source.read(&mut buf).await?;
+
+        if read == 0 {
+            break;
+        }
+
+        let _: i32 = query_scalar("SELECT lowrite($1, $2)")
+            .bind(fd)
+            .bind(&buf[..read])
+            .fetch_one(&mut *conn)
+            .await?;
+    }
+
+    let _: i32 = query_scalar("SELECT lo_close($1)")
+        .bind(fd)
+        .fetch_one(&mut *conn)
+        .await?;
+
+    Ok(())
+}
@@ -255,6 +255,33 @@ impl PgListener {
             }
         })
     }
+
+    /// Consume this listener, returning a `Stream` of only the notifications for which
+    /// `predicate` returns `true`.
+    ///
+    /// The predicate receives the channel name and the payload. Notifications that do not
+    /// match are discarded, but are still read off the connection, so a busy channel that
+    /// the consumer is not interested in will not stall the stream.
+    ///
+    /// This has the same potential drawbacks as [`recv`](PgListener::recv).
+    ///
+    pub fn into_stream_filtered<F>(
+        mut self,
+        mut predicate: F,
+    ) -> impl Stream<Item = Result<PgNotification, Error>> + Unpin
+    where
+        F: FnMut(&str, &str) -> bool + Send + 'static,
+    {
+        Box::pin(try_stream! {
+            loop {
+                let notification = self.recv().await?;
+
+                if predicate(notification.channel(), notification.payload()) {
+                    r#yield!(notification);
+                }
+            }
+        })
+    }
 }
 
 impl Drop for PgListener {
@@ -2,7 +2,7 @@ use crate::connection::{ConnectOptions, Connection};
 use crate::error::Error;
 use crate::executor::Executor;
 use crate::migrate::MigrateError;
-use crate::migrate::{AppliedMigration, Migration};
+use crate::migrate::{AppliedMigration, AppliedMigrationDetail, Migration};
 use crate::migrate::{Migrate, MigrateDatabase};
 use crate::postgres::{PgConnectOptions, PgConnection, Postgres};
 use crate::query::query;
@@ -85,12 +85,15 @@ impl MigrateDatabase for Postgres {
 }
 
 impl Migrate for PgConnection {
-    fn ensure_migrations_table(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
+    fn ensure_migrations_table<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
         Box::pin(async move {
             // language=SQL
-            self.execute(
+            let create_table = format!(
                 r#"
-CREATE TABLE IF NOT EXISTS _sqlx_migrations (
+CREATE TABLE IF NOT EXISTS {} (
     version BIGINT PRIMARY KEY,
     description TEXT NOT NULL,
     installed_on TIMESTAMPTZ NOT NULL DEFAULT now(),
@@ -99,8 +102,10 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
     execution_time BIGINT NOT NULL
 );
                 "#,
-            )
-            .await?;
+                table_name
+            );
+
+            self.execute(&*create_table).await?;
 
             Ok(())
         })
@@ -119,12 +124,16 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
-    fn dirty_version(&mut self) -> BoxFuture<'_, Result<Option<i64>, MigrateError>> {
+    fn dirty_version<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+    ) -> BoxFuture<'m, Result<Option<i64>, MigrateError>> {
         Box::pin(async move {
             // language=SQL
-            let row: Option<(i64,)> = query_as(
-                "SELECT version FROM _sqlx_migrations WHERE success = false ORDER BY version LIMIT 1",
-            )
+            let row: Option<(i64,)> = query_as(&format!(
+                "SELECT version FROM {} WHERE success = false ORDER BY version LIMIT 1",
+                table_name
+            ))
             .fetch_optional(self)
             .await?;
 
@@ -132,15 +141,18 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
-    fn list_applied_migrations(
-        &mut self,
-    ) -> BoxFuture<'_, Result<Vec<AppliedMigration>, MigrateError>> {
+    fn list_applied_migrations<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+    ) -> BoxFuture<'m, Result<Vec<AppliedMigration>, MigrateError>> {
         Box::pin(async move {
             // language=SQL
-            let rows: Vec<(i64, Vec<u8>)> =
-                query_as("SELECT version, checksum FROM _sqlx_migrations ORDER BY version")
-                    .fetch_all(self)
-                    .await?;
+            let rows: Vec<(i64, Vec<u8>)> = query_as(&format!(
+                "SELECT version, checksum FROM {} ORDER BY version",
+                table_name
+            ))
+            .fetch_all(self)
+            .await?;
 
             let migrations = rows
                 .into_iter()
@@ -154,6 +166,35 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn list_applied_migrations_detailed<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+    ) -> BoxFuture<'m, Result<Vec<AppliedMigrationDetail>, MigrateError>> {
+        Box::pin(async move {
+            // language=SQL
+            let rows: Vec<(i64, String, String, i64)> = query_as(&format!(
+                "SELECT version, description, installed_on::text, execution_time FROM {} ORDER BY version",
+                table_name
+            ))
+            .fetch_all(self)
+            .await?;
+
+            let migrations = rows
+                .into_iter()
+                .map(
+                    |(version, description, applied_at, execution_time)| AppliedMigrationDetail {
+                        version,
+                        description,
+                        applied_at,
+                        execution_time: Duration::from_nanos(std::cmp::max(execution_time, 0) as u64),
+                    },
+                )
+                .collect();
+
+            Ok(migrations)
+        })
+    }
+
     fn lock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move {
             let database_name = current_database(self).await?;
@@ -175,6 +216,45 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn lock_with_timeout(
+        &mut self,
+        timeout: Option<Duration>,
+    ) -> BoxFuture<'_, Result<(), MigrateError>> {
+        Box::pin(async move {
+            let timeout = match timeout {
+                Some(timeout) => timeout,
+                None => return self.lock().await,
+            };
+
+            let database_name = current_database(self).await?;
+            let lock_id = generate_lock_id(&database_name);
+
+            // bound the advisory lock wait with `lock_timeout`; a timeout surfaces as
+            // SQLSTATE 55P03 (lock_not_available)
+            let _ = self
+                .execute(&*format!("SET lock_timeout = '{}ms'", timeout.as_millis()))
+                .await?;
+
+            // language=SQL
+            let res = query("SELECT pg_advisory_lock($1)")
+                .bind(lock_id)
+                .execute(&mut *self)
+                .await;
+
+            let _ = self.execute("SET lock_timeout = DEFAULT").await?;
+
+            match res {
+                Ok(_) => Ok(()),
+                Err(crate::error::Error::Database(error))
+                    if error.code().as_deref() == Some("55P03") =>
+                {
+                    Err(MigrateError::LockTimeout)
+                }
+                Err(error) => Err(error.into()),
+            }
+        })
+    }
+
     fn unlock(&mut self) -> BoxFuture<'_, Result<(), MigrateError>> {
         Box::pin(async move {
             let database_name = current_database(self).await?;
@@ -216,28 +296,103 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
 
     fn apply<'e: 'm, 'm>(
         &'e mut self,
+        table_name: &'m str,
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
         Box::pin(async move {
-            let mut tx = self.begin().await?;
-            let start = Instant::now();
+            let elapsed = if migration.no_tx {
+                // the migration has opted out of the transaction wrapper; this allows
+                // statements such as `CREATE INDEX CONCURRENTLY`, but a failure
+                // part-way through leaves any completed statements in place
+                let start = Instant::now();
+
+                let _ = self.execute(&*migration.sql).await?;
+
+                start.elapsed()
+            } else {
+                let mut tx = self.begin().await?;
+                let start = Instant::now();
 
-            let _ = tx.execute(&*migration.sql).await?;
+                let _ = tx.execute(&*migration.sql).await?;
 
-            tx.commit().await?;
+                tx.commit().await?;
 
-            let elapsed = start.elapsed();
+                start.elapsed()
+            };
 
             // language=SQL
-            let _ = query(
+            let _ = query(&format!(
                 r#"
-    INSERT INTO _sqlx_migrations ( version, description, success, checksum, execution_time )
+    INSERT INTO {} ( version, description, success, checksum, execution_time )
     VALUES ( $1, $2, TRUE, $3, $4 )
                 "#,
-            )
+                table_name
+            ))
+            .bind(migration.version)
+            .bind(&*migration.description)
+            .bind(&*migration.checksum)
+            .bind(elapsed.as_nanos() as i64)
+            .execute(self)
+            .await?;
+
+            Ok(elapsed)
+        })
+    }
+
+    fn mark_applied<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
+        Box::pin(async move {
+            // language=SQL
+            let _ = query(&format!(
+                r#"
+    INSERT INTO {} ( version, description, success, checksum, execution_time )
+    VALUES ( $1, $2, TRUE, $3, 0 )
+                "#,
+                table_name
+            ))
             .bind(migration.version)
             .bind(&*migration.description)
             .bind(&*migration.checksum)
+            .execute(self)
+            .await?;
+
+            Ok(())
+        })
+    }
+
+    fn reapply<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
+        Box::pin(async move {
+            let elapsed = if migration.no_tx {
+                let start = Instant::now();
+
+                let _ = self.execute(&*migration.sql).await?;
+
+                start.elapsed()
+            } else {
+                let mut tx = self.begin().await?;
+                let start = Instant::now();
+
+                let _ = tx.execute(&*migration.sql).await?;
+
+                tx.commit().await?;
+
+                start.elapsed()
+            };
+
+            // language=SQL
+            let _ = query(&format!(
+                r#"UPDATE {} SET checksum = $2, execution_time = $3, installed_on = now() WHERE version = $1"#,
+                table_name
+            ))
+            .bind(migration.version)
+            .bind(&*migration.checksum)
             .bind(elapsed.as_nanos() as i64)
             .execute(self)
             .await?;
@@ -246,22 +401,54 @@ CREATE TABLE IF NOT EXISTS _sqlx_migrations (
         })
     }
 
+    fn update_checksum<'e: 'm, 'm>(
+        &'e mut self,
+        table_name: &'m str,
+        migration: &'m Migration,
+    ) -> BoxFuture<'m, Result<(), MigrateError>> {
+        Box::pin(async move {
+            // language=SQL
+            let _ = query(&format!(
+                r#"UPDATE {} SET checksum = $2 WHERE version = $1"#,
+                table_name
+            ))
+            .bind(migration.version)
+            .bind(&*migration.checksum)
+            .execute(self)
+            .await?;
+
+            Ok(())
+        })
+    }
+
     fn revert<'e: 'm, 'm>(
         &'e mut self,
+        table_name: &'m str,
         migration: &'m Migration,
     ) -> BoxFuture<'m, Result<Duration, MigrateError>> {
         Box::pin(async move {
-            let mut tx = self.begin().await?;
-            let start = Instant::now();
+            let elapsed = if migration.no_tx {
+                let start = Instant::now();
+
+                let _ = self.execute(&*migration.sql).await?;
+
+                start.elapsed()
+            } else {
+                let mut tx = self.begin().await?;
+                let start = Instant::now();
 
-            let _ = tx.execute(&*migration.sql).await?;
+                let _ = tx.execute(&*migration.sql).await?;
 
-            tx.commit().await?;
+                tx.commit().await?;
 
-            let elapsed = start.elapsed();
+                start.elapsed()
+            };
 
             // language=SQL
-            let _ = query(r#"DELETE FROM _sqlx_migrations WHERE version = $1"#)
+            let _ = query(&format!(
+                r#"DELETE FROM {} WHERE version = $1"#,
+                table_name
+            ))
                 .bind(migration.version)
                 .execute(self)
                 .await?;
@@ -9,6 +9,7 @@ mod copy;
 mod database;
 mod error;
 mod io;
+mod large_object;
 mod listener;
 mod message;
 mod options;
@@ -26,7 +27,7 @@ mod migrate;
 pub use arguments::{PgArgumentBuffer, PgArguments};
 pub use column::PgColumn;
 pub use connection::{PgConnection, PgConnectionInfo};
-pub use copy::PgCopyIn;
+pub use copy::{PgCopyIn, PgCopyOptions};
 pub use database::Postgres;
 pub use error::{PgDatabaseError, PgErrorPosition};
 pub use listener::{PgListener, PgNotification};
@@ -85,6 +85,7 @@ pub struct PgConnectOptions {
     pub(crate) ssl_mode: PgSslMode,
     pub(crate) ssl_root_cert: Option<CertificateInput>,
     pub(crate) statement_cache_capacity: usize,
+    pub(crate) describe_cache_capacity: usize,
     pub(crate) application_name: Option<String>,
     pub(crate) log_settings: LogSettings,
     pub(crate) options: Option<String>,
@@ -146,6 +147,7 @@ impl PgConnectOptions {
                 .and_then(|v| v.parse().ok())
                 .unwrap_or_default(),
             statement_cache_capacity: 100,
+            describe_cache_capacity: 0,
             application_name: var("PGAPPNAME").ok(),
             log_settings: Default::default(),
             options: var("PGOPTIONS").ok(),
@@ -322,6 +324,21 @@ impl PgConnectOptions {
         self
     }
 
+    /// Sets the capacity of the connection's describe cache in a number of
+    /// distinct SQL strings. When enabled, [`Executor::describe`] results are
+    /// cached per connection and reused instead of re-querying the server.
+    ///
+    /// The cache is cleared by [`Connection::clear_cached_statements`].
+    ///
+    /// The default capacity is 0 (caching disabled).
+    ///
+    /// [`Executor::describe`]: crate::executor::Executor::describe
+    /// [`Connection::clear_cached_statements`]: crate::connection::Connection::clear_cached_statements
+    pub fn describe_cache_capacity(mut self, capacity: usize) -> Self {
+        self.describe_cache_capacity = capacity;
+        self
+    }
+
     /// Sets the application name. Defaults to None
     ///
     /// # Example
@@ -28,6 +28,46 @@ where
     }
 }
 
+// postgres uses the same type for an array of any dimensionality; `int[][]`
+// and `int[]` are both `_int4`, so a nested vector shares the array type of
+// its innermost element
+//
+// NOTE: nested vectors currently only *decode*; there is no binary encoding
+//       of a nested vector as a multidimensional array
+impl<T> PgHasArrayType for Vec<Vec<T>>
+where
+    T: PgHasArrayType,
+{
+    fn array_type_info() -> PgTypeInfo {
+        T::array_type_info()
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        T::array_compatible(ty)
+    }
+}
+
+// a blanket implementation over `Vec<T>` would conflict with the dedicated
+// `Vec<u8>` (`BYTEA`) implementation, so the scalar element types are
+// enumerated instead
+macro_rules! impl_array_type_for_vec_of {
+    ($($t:ty),* $(,)?) => {
+        $(
+            impl PgHasArrayType for Vec<$t> {
+                fn array_type_info() -> PgTypeInfo {
+                    <$t as PgHasArrayType>::array_type_info()
+                }
+
+                fn array_compatible(ty: &PgTypeInfo) -> bool {
+                    <$t as PgHasArrayType>::array_compatible(ty)
+                }
+            }
+        )*
+    };
+}
+
+impl_array_type_for_vec_of!(bool, i16, i32, i64, f32, f64, String);
+
 impl<T> Type<Postgres> for [T]
 where
     T: PgHasArrayType,
@@ -120,10 +160,6 @@ where
                     return Ok(Vec::new());
                 }
 
-                if ndim != 1 {
-                    return Err(format!("encountered an array of {} dimensions; only one-dimensional arrays are supported", ndim).into());
-                }
-
                 // appears to have been used in the past to communicate potential NULLS
                 // but reading source code back through our supported postgres versions (9.5+)
                 // this is never used for anything
@@ -135,24 +171,77 @@ where
                     .or_else(|| value.type_info.try_array_element().map(Cow::into_owned))
                     .unwrap_or_else(|| PgTypeInfo(PgType::DeclareWithOid(element_type_oid)));
 
-                // length of the array axis
-                let len = buf.get_i32();
+                // the length and lower bound of each dimension; a lower bound
+                // other than one only changes how the array is subscripted in
+                // SQL, not which elements it holds, so it is read and ignored
+                let mut dims = Vec::with_capacity(ndim as usize);
+
+                for _ in 0..ndim {
+                    let dim = buf.get_i32();
+                    let _lower_bound = buf.get_i32();
+
+                    dims.push(dim);
+                }
+
+                if ndim == 1 {
+                    let mut elements = Vec::with_capacity(dims[0] as usize);
 
-                // the lower bound, we only support arrays starting from "1"
-                let lower = buf.get_i32();
+                    for _ in 0..dims[0] {
+                        elements.push(T::decode(PgValueRef::get(
+                            &mut buf,
+                            format,
+                            element_type_info.clone(),
+                        ))?)
+                    }
+
+                    return Ok(elements);
+                }
 
-                if lower != 1 {
-                    return Err(format!("encountered an array with a lower bound of {} in the first dimension; only arrays starting at one are supported", lower).into());
+                // more than one dimension; each item of the outer dimension is
+                // itself an array, so `T` must be an array type for the decode
+                // to be meaningful
+                if T::type_info().try_array_element().is_none() {
+                    return Err(format!("encountered an array of {} dimensions; decode a multidimensional array into nested vectors, e.g. `Vec<Vec<T>>`", ndim).into());
                 }
 
-                let mut elements = Vec::with_capacity(len as usize);
+                // the elements are sent in row-major order with no
+                // per-dimension framing; rebuild an array value of one fewer
+                // dimension for each slice of the outer dimension and let `T`
+                // decode that
+                let inner_elements: i32 = dims[1..].iter().product();
+                let mut elements = Vec::with_capacity(dims[0] as usize);
+
+                for _ in 0..dims[0] {
+                    let mut inner = Vec::new();
+
+                    inner.extend_from_slice(&(ndim - 1).to_be_bytes());
+                    inner.extend_from_slice(&0_i32.to_be_bytes()); // flags
+                    inner.extend_from_slice(&element_type_oid.to_be_bytes());
 
-                for _ in 0..len {
-                    elements.push(T::decode(PgValueRef::get(
-                        &mut buf,
+                    for dim in &dims[1..] {
+                        inner.extend_from_slice(&dim.to_be_bytes());
+                        inner.extend_from_slice(&1_i32.to_be_bytes()); // lower bound
+                    }
+
+                    for _ in 0..inner_elements {
+                        let element_len = buf.get_i32();
+                        inner.extend_from_slice(&element_len.to_be_bytes());
+
+                        if element_len >= 0 {
+                            inner.extend_from_slice(&buf[..element_len as usize]);
+                            buf.advance(element_len as usize);
+                        }
+                    }
+
+                    elements.push(T::decode(PgValueRef {
+                        value: Some(&inner),
+                        row: None,
+                        // postgres uses the element's one-dimensional array
+                        // type for an array of any dimensionality, so the
+                        // inner slices share the outer type
+                        type_info: value.type_info.clone(),
                         format,
-                        element_type_info.clone(),
-                    ))?)
+                    })?);
                 }
 
                 Ok(elements)
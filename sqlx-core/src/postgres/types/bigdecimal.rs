@@ -430,4 +430,36 @@ mod bigdecimal_to_pgnumeric {
             }
         );
     }
+
+    #[test]
+    fn fifty_digit_round_trip() {
+        // 50 significant digits, beyond what `rust_decimal` can represent
+        let value: BigDecimal = "12345678901234567890123456789012345678901234567890.98765"
+            .parse()
+            .unwrap();
+
+        let numeric = PgNumeric::try_from(&value).unwrap();
+
+        assert_eq!(BigDecimal::try_from(numeric).unwrap(), value);
+    }
+
+    #[test]
+    fn large_weight_round_trip() {
+        // a large positive weight (digits far left of the decimal point)
+        let value: BigDecimal = "9e100".parse().unwrap();
+
+        let numeric = PgNumeric::try_from(&value).unwrap();
+
+        assert_eq!(BigDecimal::try_from(numeric).unwrap(), value.normalized());
+    }
+
+    #[test]
+    fn trailing_zero_group_round_trip() {
+        // ends exactly on a zero base-10000 digit group
+        let value: BigDecimal = "123450000".parse().unwrap();
+
+        let numeric = PgNumeric::try_from(&value).unwrap();
+
+        assert_eq!(BigDecimal::try_from(numeric).unwrap(), value);
+    }
 }
@@ -5,6 +5,7 @@ use crate::postgres::{
     PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
 };
 use crate::types::Type;
+use std::convert::TryInto;
 
 impl PgHasArrayType for u8 {
     fn array_type_info() -> PgTypeInfo {
@@ -60,3 +61,34 @@ impl Decode<'_, Postgres> for Vec<u8> {
         })
     }
 }
+
+impl<const N: usize> Type<Postgres> for [u8; N] {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::BYTEA
+    }
+}
+
+impl<const N: usize> PgHasArrayType for [u8; N] {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::BYTEA_ARRAY
+    }
+}
+
+impl<const N: usize> Encode<'_, Postgres> for [u8; N] {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.extend_from_slice(self);
+
+        IsNull::No
+    }
+}
+
+impl<'r, const N: usize> Decode<'r, Postgres> for [u8; N] {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        let bytes: Vec<u8> = Decode::<Postgres>::decode(value)?;
+        let len = bytes.len();
+
+        bytes.try_into().map_err(|_| {
+            format!("mismatched lengths: [u8; {}] from a BYTEA of {} bytes", N, len).into()
+        })
+    }
+}
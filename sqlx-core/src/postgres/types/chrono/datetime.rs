@@ -1,6 +1,7 @@
 use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
+use crate::postgres::types::timestamp::{TIMESTAMP_INFINITY, TIMESTAMP_NEG_INFINITY};
 use crate::postgres::{
     PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
 };
@@ -56,8 +57,18 @@ impl<'r> Decode<'r, Postgres> for NaiveDateTime {
         Ok(match value.format() {
             PgValueFormat::Binary => {
                 // TIMESTAMP is encoded as the microseconds since the epoch
+                let us: i64 = Decode::<Postgres>::decode(value)?;
+
+                if us == TIMESTAMP_INFINITY || us == TIMESTAMP_NEG_INFINITY {
+                    return Err(format!(
+                        "timestamp is `{}infinity` and has no finite representation; decode as \
+                         `sqlx::postgres::types::PgTimestamp` to handle infinite timestamps",
+                        if us < 0 { "-" } else { "" },
+                    )
+                    .into());
+                }
+
                 let epoch = NaiveDate::from_ymd(2000, 1, 1).and_hms(0, 0, 0);
-                let us = Decode::<Postgres>::decode(value)?;
                 epoch + Duration::microseconds(us)
             }
 
@@ -79,6 +90,7 @@ impl<'r> Decode<'r, Postgres> for NaiveDateTime {
     }
 }
 
+/// Converts to UTC for the wire format; `timestamptz` does not store the offset.
 impl<Tz: TimeZone> Encode<'_, Postgres> for DateTime<Tz> {
     fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
         Encode::<Postgres>::encode(self.naive_utc(), buf)
@@ -89,6 +101,7 @@ impl<Tz: TimeZone> Encode<'_, Postgres> for DateTime<Tz> {
     }
 }
 
+/// The decoded instant is the stored UTC instant converted to the local offset.
 impl<'r> Decode<'r, Postgres> for DateTime<Local> {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
         let naive = <NaiveDateTime as Decode<Postgres>>::decode(value)?;
@@ -103,6 +116,10 @@ impl<'r> Decode<'r, Postgres> for DateTime<Utc> {
     }
 }
 
+/// `timestamptz` stores a UTC instant without the original offset, so the decoded
+/// value always carries an offset of `+00:00`; convert with
+/// [`DateTime::with_timezone()`] if another offset is wanted. Comparisons against a
+/// `DateTime` in any offset still agree, as they compare the instant.
 impl<'r> Decode<'r, Postgres> for DateTime<FixedOffset> {
     fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
         let naive = <NaiveDateTime as Decode<Postgres>>::decode(value)?;
@@ -0,0 +1,69 @@
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef, Postgres};
+use crate::types::Type;
+use std::fmt::{self, Display, Formatter};
+
+/// The PostgreSQL [`CITEXT`][citext] type, text that compares case-insensitively.
+///
+/// `citext` is an extension type whose OID is assigned per-database, so it is
+/// referenced by name and resolved against the connected database on first use.
+///
+/// `String` and `&str` also accept `citext` columns on decode, and a bound
+/// `text` parameter compares case-insensitively against a `citext` column
+/// through the extension's implicit casts; use this wrapper when the parameter
+/// itself must have the `citext` type, e.g. for `citext[]` arrays or functions
+/// that only accept `citext`.
+///
+/// Equality of two `PgCiText` values on the Rust side is still case-sensitive.
+///
+/// [citext]: https://www.postgresql.org/docs/current/citext.html
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PgCiText(pub String);
+
+impl Type<Postgres> for PgCiText {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("citext")
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <&str as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl PgHasArrayType for PgCiText {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::with_name("_citext")
+    }
+}
+
+impl Encode<'_, Postgres> for PgCiText {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        <&str as Encode<Postgres>>::encode(self.0.as_str(), buf)
+    }
+}
+
+impl Decode<'_, Postgres> for PgCiText {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(PgCiText(value.as_str()?.to_owned()))
+    }
+}
+
+impl From<String> for PgCiText {
+    fn from(value: String) -> Self {
+        Self(value)
+    }
+}
+
+impl From<PgCiText> for String {
+    fn from(value: PgCiText) -> Self {
+        value.0
+    }
+}
+
+impl Display for PgCiText {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.0)
+    }
+}
@@ -0,0 +1,288 @@
+use std::borrow::Cow;
+use std::convert::TryInto;
+
+use geo_types::{Coord, LineString, Point, Polygon};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::type_info::TypeInfo;
+use crate::types::Type;
+
+// PostGIS geometries are transferred as EWKB: a byte-order marker, a type code
+// carrying flag bits, an optional SRID and then the WKB coordinate data:
+// https://github.com/postgis/postgis/blob/master/doc/ZMSgeoms.txt
+//
+// `geometry` is an extension type whose OID is assigned per-database, so it is
+// referenced by name here. Geometries with Z or M coordinates are rejected on
+// decode rather than silently truncated.
+
+const EWKB_POINT: u32 = 1;
+const EWKB_LINE_STRING: u32 = 2;
+const EWKB_POLYGON: u32 = 3;
+
+const EWKB_FLAG_Z: u32 = 0x8000_0000;
+const EWKB_FLAG_M: u32 = 0x4000_0000;
+const EWKB_FLAG_SRID: u32 = 0x2000_0000;
+
+/// A [PostGIS](https://postgis.net/) geometry together with its SRID.
+///
+/// The bare `geo_types` geometries also implement [`Encode`]/[`Decode`]; they encode
+/// with SRID 0 (unknown) and ignore the SRID on decode.
+#[derive(Debug, Clone, PartialEq)]
+pub struct PgGeometry<T> {
+    pub geometry: T,
+    pub srid: u32,
+}
+
+macro_rules! impl_geometry_type {
+    ($ty:ty) => {
+        impl Type<Postgres> for PgGeometry<$ty> {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::with_name("geometry")
+            }
+
+            fn compatible(ty: &PgTypeInfo) -> bool {
+                ty.name() == "geometry" || ty.name() == "geography"
+            }
+        }
+
+        impl Type<Postgres> for $ty {
+            fn type_info() -> PgTypeInfo {
+                <PgGeometry<$ty> as Type<Postgres>>::type_info()
+            }
+
+            fn compatible(ty: &PgTypeInfo) -> bool {
+                <PgGeometry<$ty> as Type<Postgres>>::compatible(ty)
+            }
+        }
+
+        impl Encode<'_, Postgres> for $ty {
+            fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+                PgGeometry {
+                    geometry: self.clone(),
+                    srid: 0,
+                }
+                .encode_by_ref(buf)
+            }
+        }
+
+        impl Decode<'_, Postgres> for $ty {
+            fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+                Ok(<PgGeometry<$ty> as Decode<Postgres>>::decode(value)?.geometry)
+            }
+        }
+    };
+}
+
+impl_geometry_type!(Point<f64>);
+impl_geometry_type!(LineString<f64>);
+impl_geometry_type!(Polygon<f64>);
+
+impl Encode<'_, Postgres> for PgGeometry<Point<f64>> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        put_header(buf, EWKB_POINT, self.srid);
+        put_coordinate(buf, self.geometry.0);
+
+        IsNull::No
+    }
+}
+
+impl Encode<'_, Postgres> for PgGeometry<LineString<f64>> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        put_header(buf, EWKB_LINE_STRING, self.srid);
+        put_ring(buf, &self.geometry);
+
+        IsNull::No
+    }
+}
+
+impl Encode<'_, Postgres> for PgGeometry<Polygon<f64>> {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        put_header(buf, EWKB_POLYGON, self.srid);
+
+        buf.extend(&(1 + self.geometry.interiors().len() as u32).to_le_bytes());
+
+        put_ring(buf, self.geometry.exterior());
+
+        for interior in self.geometry.interiors() {
+            put_ring(buf, interior);
+        }
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Postgres> for PgGeometry<Point<f64>> {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let bytes = get_bytes(value)?;
+        let (mut reader, srid) = EwkbReader::new(&bytes, EWKB_POINT)?;
+
+        Ok(PgGeometry {
+            geometry: Point(reader.coordinate()?),
+            srid,
+        })
+    }
+}
+
+impl Decode<'_, Postgres> for PgGeometry<LineString<f64>> {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let bytes = get_bytes(value)?;
+        let (mut reader, srid) = EwkbReader::new(&bytes, EWKB_LINE_STRING)?;
+
+        Ok(PgGeometry {
+            geometry: reader.ring()?,
+            srid,
+        })
+    }
+}
+
+impl Decode<'_, Postgres> for PgGeometry<Polygon<f64>> {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let bytes = get_bytes(value)?;
+        let (mut reader, srid) = EwkbReader::new(&bytes, EWKB_POLYGON)?;
+
+        let rings = reader.u32()?;
+
+        if rings == 0 {
+            return Err("POLYGON with no rings".into());
+        }
+
+        let exterior = reader.ring()?;
+
+        let interiors = (1..rings)
+            .map(|_| reader.ring())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(PgGeometry {
+            geometry: Polygon::new(exterior, interiors),
+            srid,
+        })
+    }
+}
+
+fn put_header(buf: &mut PgArgumentBuffer, type_: u32, srid: u32) {
+    // little-endian
+    buf.push(1);
+
+    if srid != 0 {
+        buf.extend(&(type_ | EWKB_FLAG_SRID).to_le_bytes());
+        buf.extend(&srid.to_le_bytes());
+    } else {
+        buf.extend(&type_.to_le_bytes());
+    }
+}
+
+fn put_coordinate(buf: &mut PgArgumentBuffer, coord: Coord<f64>) {
+    buf.extend(&coord.x.to_le_bytes());
+    buf.extend(&coord.y.to_le_bytes());
+}
+
+fn put_ring(buf: &mut PgArgumentBuffer, ring: &LineString<f64>) {
+    buf.extend(&(ring.0.len() as u32).to_le_bytes());
+
+    for coord in &ring.0 {
+        buf.extend(&coord.x.to_le_bytes());
+        buf.extend(&coord.y.to_le_bytes());
+    }
+}
+
+// in text format PostGIS emits the EWKB as a hex string
+fn get_bytes(value: PgValueRef<'_>) -> Result<Cow<'_, [u8]>, BoxDynError> {
+    match value.format() {
+        PgValueFormat::Binary => Ok(Cow::Borrowed(value.as_bytes()?)),
+        PgValueFormat::Text => Ok(Cow::Owned(hex::decode(value.as_str()?)?)),
+    }
+}
+
+struct EwkbReader<'a> {
+    buf: &'a [u8],
+    little_endian: bool,
+}
+
+impl<'a> EwkbReader<'a> {
+    /// Read the EWKB header, checking the geometry type and returning the SRID.
+    fn new(buf: &'a [u8], expected: u32) -> Result<(Self, u32), BoxDynError> {
+        let mut reader = EwkbReader {
+            buf,
+            little_endian: true,
+        };
+
+        reader.little_endian = match reader.take(1)?[0] {
+            0 => false,
+            1 => true,
+            other => return Err(format!("invalid EWKB byte order marker {}", other).into()),
+        };
+
+        let type_ = reader.u32()?;
+
+        if type_ & (EWKB_FLAG_Z | EWKB_FLAG_M) != 0 {
+            return Err("geometries with Z or M coordinates are not supported".into());
+        }
+
+        let srid = if type_ & EWKB_FLAG_SRID != 0 {
+            reader.u32()?
+        } else {
+            0
+        };
+
+        let type_ = type_ & !EWKB_FLAG_SRID;
+
+        if type_ != expected {
+            return Err(
+                format!("expected geometry type {}, got {}", expected, type_).into(),
+            );
+        }
+
+        Ok((reader, srid))
+    }
+
+    fn take(&mut self, n: usize) -> Result<&'a [u8], BoxDynError> {
+        if self.buf.len() < n {
+            return Err("unexpected end of EWKB data".into());
+        }
+
+        let (bytes, rest) = self.buf.split_at(n);
+        self.buf = rest;
+
+        Ok(bytes)
+    }
+
+    fn u32(&mut self) -> Result<u32, BoxDynError> {
+        let bytes = self.take(4)?.try_into().unwrap();
+
+        Ok(if self.little_endian {
+            u32::from_le_bytes(bytes)
+        } else {
+            u32::from_be_bytes(bytes)
+        })
+    }
+
+    fn f64(&mut self) -> Result<f64, BoxDynError> {
+        let bytes = self.take(8)?.try_into().unwrap();
+
+        Ok(if self.little_endian {
+            f64::from_le_bytes(bytes)
+        } else {
+            f64::from_be_bytes(bytes)
+        })
+    }
+
+    fn coordinate(&mut self) -> Result<Coord<f64>, BoxDynError> {
+        Ok(Coord {
+            x: self.f64()?,
+            y: self.f64()?,
+        })
+    }
+
+    fn ring(&mut self) -> Result<LineString<f64>, BoxDynError> {
+        let len = self.u32()?;
+
+        let coords = (0..len)
+            .map(|_| self.coordinate())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(LineString(coords))
+    }
+}
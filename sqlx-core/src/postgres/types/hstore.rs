@@ -0,0 +1,95 @@
+use std::collections::{BTreeMap, HashMap};
+use std::str;
+
+use bytes::Buf;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{PgArgumentBuffer, PgTypeInfo, PgValueFormat, PgValueRef, Postgres};
+use crate::types::Type;
+
+// The binary representation of `hstore` is a pair count followed by, per pair,
+// a length-prefixed key and a length-prefixed value where a length of -1 marks
+// a `NULL` value:
+// https://github.com/postgres/postgres/blob/master/contrib/hstore/hstore_io.c
+//
+// `hstore` is an extension type whose OID is assigned per-database, so it is
+// referenced by name here; reading values in text format is not supported and
+// will cause an error.
+
+macro_rules! impl_hstore_for_map {
+    ($ty:ident) => {
+        impl Type<Postgres> for $ty<String, Option<String>> {
+            fn type_info() -> PgTypeInfo {
+                PgTypeInfo::with_name("hstore")
+            }
+        }
+
+        impl Encode<'_, Postgres> for $ty<String, Option<String>> {
+            fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+                buf.extend(&(self.len() as i32).to_be_bytes());
+
+                for (key, value) in self {
+                    buf.extend(&(key.len() as i32).to_be_bytes());
+                    buf.extend(key.as_bytes());
+
+                    match value {
+                        Some(value) => {
+                            buf.extend(&(value.len() as i32).to_be_bytes());
+                            buf.extend(value.as_bytes());
+                        }
+
+                        None => buf.extend(&(-1_i32).to_be_bytes()),
+                    }
+                }
+
+                IsNull::No
+            }
+        }
+
+        impl Decode<'_, Postgres> for $ty<String, Option<String>> {
+            fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+                decode_hstore(value)?.collect()
+            }
+        }
+    };
+}
+
+impl_hstore_for_map!(HashMap);
+impl_hstore_for_map!(BTreeMap);
+
+fn decode_hstore(
+    value: PgValueRef<'_>,
+) -> Result<impl Iterator<Item = Result<(String, Option<String>), BoxDynError>> + '_, BoxDynError> {
+    match value.format() {
+        PgValueFormat::Binary => {
+            let mut buf = value.as_bytes()?;
+            let count = buf.get_i32();
+
+            Ok((0..count).map(move |_| {
+                let key = get_str(&mut buf)?.ok_or("unexpected NULL `hstore` key")?;
+                let value = get_str(&mut buf)?;
+
+                Ok((key, value))
+            }))
+        }
+
+        PgValueFormat::Text => {
+            Err("reading `hstore` in text format is not supported".into())
+        }
+    }
+}
+
+fn get_str(buf: &mut &[u8]) -> Result<Option<String>, BoxDynError> {
+    let len = buf.get_i32();
+
+    if len < 0 {
+        return Ok(None);
+    }
+
+    let (bytes, rest) = buf.split_at(len as usize);
+    *buf = rest;
+
+    Ok(Some(str::from_utf8(bytes)?.to_owned()))
+}
@@ -1,5 +1,7 @@
 use std::convert::{TryFrom, TryInto};
+use std::fmt::{self, Display, Formatter};
 use std::mem;
+use std::str::FromStr;
 
 use byteorder::{NetworkEndian, ReadBytesExt};
 
@@ -70,6 +72,230 @@ impl Encode<'_, Postgres> for PgInterval {
     }
 }
 
+/// An `INTERVAL` viewed as an [ISO-8601 duration](https://en.wikipedia.org/wiki/ISO_8601#Durations)
+/// string such as `P1Y2M10DT2H30M`.
+///
+/// Decoding goes through [`PgInterval`] and then formats the months/days/microseconds
+/// components; the wrapper can also be parsed from an ISO-8601 string with [`FromStr`]
+/// and encoded back as an `INTERVAL`. This is intended for interoperability with
+/// systems that exchange durations in their ISO-8601 textual form.
+///
+/// ISO-8601 does not specify per-component signs; like PostgreSQL itself, each
+/// component carries its own sign, so an interval of `-14 months` formats as `P-1Y-2M`.
+/// A zero interval formats as `PT0S`.
+#[derive(Debug, Eq, PartialEq, Clone)]
+pub struct PgIntervalIso8601(pub PgInterval);
+
+impl From<PgInterval> for PgIntervalIso8601 {
+    fn from(interval: PgInterval) -> Self {
+        Self(interval)
+    }
+}
+
+impl Type<Postgres> for PgIntervalIso8601 {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INTERVAL
+    }
+}
+
+impl PgHasArrayType for PgIntervalIso8601 {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::INTERVAL_ARRAY
+    }
+}
+
+impl<'de> Decode<'de, Postgres> for PgIntervalIso8601 {
+    fn decode(value: PgValueRef<'de>) -> Result<Self, BoxDynError> {
+        Ok(Self(PgInterval::decode(value)?))
+    }
+}
+
+impl Encode<'_, Postgres> for PgIntervalIso8601 {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        self.0.encode_by_ref(buf)
+    }
+
+    fn size_hint(&self) -> usize {
+        self.0.size_hint()
+    }
+}
+
+impl Display for PgIntervalIso8601 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let PgInterval {
+            months,
+            days,
+            microseconds,
+        } = self.0;
+
+        if months == 0 && days == 0 && microseconds == 0 {
+            return f.write_str("PT0S");
+        }
+
+        f.write_str("P")?;
+
+        if months / 12 != 0 {
+            write!(f, "{}Y", months / 12)?;
+        }
+
+        if months % 12 != 0 {
+            write!(f, "{}M", months % 12)?;
+        }
+
+        if days != 0 {
+            write!(f, "{}D", days)?;
+        }
+
+        if microseconds != 0 {
+            f.write_str("T")?;
+
+            let hours = microseconds / 3_600_000_000;
+            let minutes = (microseconds % 3_600_000_000) / 60_000_000;
+            let sec_micros = microseconds % 60_000_000;
+
+            if hours != 0 {
+                write!(f, "{}H", hours)?;
+            }
+
+            if minutes != 0 {
+                write!(f, "{}M", minutes)?;
+            }
+
+            if sec_micros % 1_000_000 != 0 {
+                // keep the sign with the integral part even when it is zero (`-0.5S`)
+                if sec_micros < 0 && sec_micros / 1_000_000 == 0 {
+                    f.write_str("-")?;
+                }
+
+                let frac = format!("{:06}", (sec_micros % 1_000_000).abs());
+
+                write!(
+                    f,
+                    "{}.{}S",
+                    sec_micros / 1_000_000,
+                    frac.trim_end_matches('0')
+                )?;
+            } else if sec_micros != 0 {
+                write!(f, "{}S", sec_micros / 1_000_000)?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl FromStr for PgIntervalIso8601 {
+    type Err = BoxDynError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut rest = s
+            .strip_prefix('P')
+            .ok_or("ISO-8601 duration must start with 'P'")?;
+
+        if rest.is_empty() {
+            return Err("ISO-8601 duration must contain at least one component".into());
+        }
+
+        let mut months = 0_i64;
+        let mut days = 0_i64;
+        let mut microseconds = 0_i64;
+        let mut in_time = false;
+
+        while !rest.is_empty() {
+            if !in_time && rest.starts_with('T') {
+                in_time = true;
+                rest = &rest[1..];
+
+                if rest.is_empty() {
+                    return Err("ISO-8601 time designator 'T' must be followed by a component".into());
+                }
+
+                continue;
+            }
+
+            let len = rest
+                .find(|ch: char| !ch.is_ascii_digit() && !matches!(ch, '-' | '+' | '.'))
+                .ok_or("ISO-8601 duration component is missing its designator")?;
+
+            let (number, designator) = (&rest[..len], rest.as_bytes()[len]);
+            rest = &rest[len + 1..];
+
+            match (in_time, designator) {
+                (false, b'Y') => months = add(months, mul(parse_component(number)?, 12)?)?,
+                (false, b'M') => months = add(months, parse_component(number)?)?,
+                (false, b'W') => days = add(days, mul(parse_component(number)?, 7)?)?,
+                (false, b'D') => days = add(days, parse_component(number)?)?,
+                (true, b'H') => {
+                    microseconds =
+                        add(microseconds, mul(parse_component(number)?, 3_600_000_000)?)?
+                }
+                (true, b'M') => {
+                    microseconds = add(microseconds, mul(parse_component(number)?, 60_000_000)?)?
+                }
+                (true, b'S') => microseconds = add(microseconds, parse_seconds(number)?)?,
+
+                _ => {
+                    return Err(format!(
+                        "unexpected designator {:?} in ISO-8601 duration",
+                        designator as char
+                    )
+                    .into())
+                }
+            }
+        }
+
+        Ok(Self(PgInterval {
+            months: months.try_into()?,
+            days: days.try_into()?,
+            microseconds,
+        }))
+    }
+}
+
+fn parse_component(s: &str) -> Result<i64, BoxDynError> {
+    if s.contains('.') {
+        return Err("only the seconds component of an ISO-8601 duration may be fractional".into());
+    }
+
+    Ok(s.parse()?)
+}
+
+fn parse_seconds(s: &str) -> Result<i64, BoxDynError> {
+    let (sign, s) = match s.strip_prefix('-') {
+        Some(s) => (-1, s),
+        None => (1, s.strip_prefix('+').unwrap_or(s)),
+    };
+
+    let (whole, frac) = match s.find('.') {
+        Some(i) => (&s[..i], &s[i + 1..]),
+        None => (s, ""),
+    };
+
+    if frac.len() > 6 && frac[6..].bytes().any(|b| b != b'0') {
+        return Err("PostgreSQL `INTERVAL` does not support sub-microsecond precision".into());
+    }
+
+    let whole: i64 = whole.parse()?;
+
+    let micros: i64 = if frac.is_empty() {
+        0
+    } else {
+        format!("{:0<6}", &frac[..std::cmp::min(frac.len(), 6)]).parse()?
+    };
+
+    mul(add(mul(whole, 1_000_000)?, micros)?, sign)
+}
+
+fn add(a: i64, b: i64) -> Result<i64, BoxDynError> {
+    a.checked_add(b)
+        .ok_or_else(|| "overflow parsing ISO-8601 duration".into())
+}
+
+fn mul(a: i64, b: i64) -> Result<i64, BoxDynError> {
+    a.checked_mul(b)
+        .ok_or_else(|| "overflow parsing ISO-8601 duration".into())
+}
+
 // We then implement Encode + Type for std Duration, chrono Duration, and time Duration
 // This is to enable ease-of-use for encoding when its simple
 
@@ -97,6 +323,26 @@ impl Encode<'_, Postgres> for std::time::Duration {
     }
 }
 
+impl Decode<'_, Postgres> for std::time::Duration {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let interval = PgInterval::decode(value)?;
+
+        // a month or day component has no fixed length in microseconds and
+        // so cannot be represented by a `std::time::Duration`
+        if interval.months != 0 || interval.days != 0 {
+            return Err(
+                "INTERVAL with a month or day component cannot be decoded into `std::time::Duration`"
+                    .into(),
+            );
+        }
+
+        // this also rejects negative intervals, which a `Duration` cannot hold
+        let microseconds: u64 = interval.microseconds.try_into()?;
+
+        Ok(std::time::Duration::from_micros(microseconds))
+    }
+}
+
 impl TryFrom<std::time::Duration> for PgInterval {
     type Error = BoxDynError;
 
@@ -393,3 +639,85 @@ fn test_pginterval_time() {
     assert!(PgInterval::try_from(time::Duration::seconds(10_000_000_000_000)).is_err());
     assert!(PgInterval::try_from(time::Duration::seconds(-10_000_000_000_000)).is_err());
 }
+
+#[test]
+fn test_interval_iso8601_format() {
+    let iso = |months, days, microseconds| {
+        PgIntervalIso8601(PgInterval {
+            months,
+            days,
+            microseconds,
+        })
+        .to_string()
+    };
+
+    assert_eq!(iso(0, 0, 0), "PT0S");
+    assert_eq!(iso(14, 10, (2 * 3_600 + 30 * 60) * 1_000_000), "P1Y2M10DT2H30M");
+    assert_eq!(iso(3, 0, 0), "P3M");
+    assert_eq!(iso(0, 0, 90_000_000), "PT1M30S");
+    assert_eq!(iso(0, 0, 500_000), "PT0.5S");
+    assert_eq!(iso(0, 0, -500_000), "PT-0.5S");
+    assert_eq!(iso(0, 0, 1_000_001), "PT1.000001S");
+    assert_eq!(iso(-14, -1, 0), "P-1Y-2M-1D");
+    assert_eq!(iso(0, 0, -3_600_000_000), "PT-1H");
+}
+
+#[test]
+fn test_interval_iso8601_parse() {
+    let parse = |s: &str| s.parse::<PgIntervalIso8601>().unwrap().0;
+
+    assert_eq!(
+        parse("P1Y2M10DT2H30M"),
+        PgInterval {
+            months: 14,
+            days: 10,
+            microseconds: (2 * 3_600 + 30 * 60) * 1_000_000
+        }
+    );
+    assert_eq!(
+        parse("PT0S"),
+        PgInterval {
+            months: 0,
+            days: 0,
+            microseconds: 0
+        }
+    );
+    assert_eq!(
+        parse("P2W"),
+        PgInterval {
+            months: 0,
+            days: 14,
+            microseconds: 0
+        }
+    );
+    assert_eq!(
+        parse("PT-0.5S"),
+        PgInterval {
+            months: 0,
+            days: 0,
+            microseconds: -500_000
+        }
+    );
+    assert_eq!(
+        parse("P-1Y-2M"),
+        PgInterval {
+            months: -14,
+            days: 0,
+            microseconds: 0
+        }
+    );
+
+    // round trip through `Display`
+    for s in ["P1Y2M10DT2H30M", "PT0.5S", "P-1Y-2M-1D", "PT1M30S"] {
+        assert_eq!(parse(s), parse(&PgIntervalIso8601(parse(s)).to_string()));
+    }
+
+    assert!("".parse::<PgIntervalIso8601>().is_err());
+    assert!("P".parse::<PgIntervalIso8601>().is_err());
+    assert!("PT".parse::<PgIntervalIso8601>().is_err());
+    assert!("P1S".parse::<PgIntervalIso8601>().is_err());
+    assert!("PT1D".parse::<PgIntervalIso8601>().is_err());
+    assert!("P1.5Y".parse::<PgIntervalIso8601>().is_err());
+    assert!("PT0.0000001S".parse::<PgIntervalIso8601>().is_err());
+    assert!("10 days".parse::<PgIntervalIso8601>().is_err());
+}
@@ -0,0 +1,217 @@
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{
+    PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
+use crate::types::Type;
+
+// https://github.com/rust-lang/rust/search?q=AF_INET&unscoped_q=AF_INET
+
+#[cfg(windows)]
+const AF_INET: u8 = 2;
+
+#[cfg(not(any(unix, windows)))]
+const AF_INET: u8 = 0;
+
+#[cfg(unix)]
+const AF_INET: u8 = libc::AF_INET as u8;
+
+// https://github.com/postgres/postgres/blob/574925bfd0a8175f6e161936ea11d9695677ba09/src/include/utils/inet.h#L39
+
+const PGSQL_AF_INET: u8 = AF_INET;
+const PGSQL_AF_INET6: u8 = AF_INET + 1;
+
+impl Type<Postgres> for IpAddr {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::INET
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::CIDR || *ty == PgTypeInfo::INET
+    }
+}
+
+impl PgHasArrayType for IpAddr {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::INET_ARRAY
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::CIDR_ARRAY || *ty == PgTypeInfo::INET_ARRAY
+    }
+}
+
+impl Type<Postgres> for Ipv4Addr {
+    fn type_info() -> PgTypeInfo {
+        <IpAddr as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <IpAddr as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl PgHasArrayType for Ipv4Addr {
+    fn array_type_info() -> PgTypeInfo {
+        <IpAddr as PgHasArrayType>::array_type_info()
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        <IpAddr as PgHasArrayType>::array_compatible(ty)
+    }
+}
+
+impl Type<Postgres> for Ipv6Addr {
+    fn type_info() -> PgTypeInfo {
+        <IpAddr as Type<Postgres>>::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        <IpAddr as Type<Postgres>>::compatible(ty)
+    }
+}
+
+impl PgHasArrayType for Ipv6Addr {
+    fn array_type_info() -> PgTypeInfo {
+        <IpAddr as PgHasArrayType>::array_type_info()
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        <IpAddr as PgHasArrayType>::array_compatible(ty)
+    }
+}
+
+impl Encode<'_, Postgres> for IpAddr {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        // a plain address is encoded as a network with a full-length prefix
+        match self {
+            IpAddr::V4(addr) => <Ipv4Addr as Encode<'_, Postgres>>::encode_by_ref(addr, buf),
+            IpAddr::V6(addr) => <Ipv6Addr as Encode<'_, Postgres>>::encode_by_ref(addr, buf),
+        }
+    }
+
+    fn size_hint(&self) -> usize {
+        match self {
+            IpAddr::V4(_) => 8,
+            IpAddr::V6(_) => 20,
+        }
+    }
+}
+
+impl Encode<'_, Postgres> for Ipv4Addr {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.push(PGSQL_AF_INET); // ip_family
+        buf.push(32); // ip_bits
+        buf.push(0); // is_cidr
+        buf.push(4); // nb (number of bytes)
+        buf.extend_from_slice(&self.octets()); // address
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        8
+    }
+}
+
+impl Encode<'_, Postgres> for Ipv6Addr {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.push(PGSQL_AF_INET6); // ip_family
+        buf.push(128); // ip_bits
+        buf.push(0); // is_cidr
+        buf.push(16); // nb (number of bytes)
+        buf.extend_from_slice(&self.octets()); // address
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        20
+    }
+}
+
+impl Decode<'_, Postgres> for IpAddr {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let bytes = match value.format() {
+            PgValueFormat::Binary => value.as_bytes()?,
+            PgValueFormat::Text => {
+                // text format may carry a prefix length; a bare address must be full-length
+                return parse_text(value.as_str()?);
+            }
+        };
+
+        if bytes.len() >= 8 {
+            let family = bytes[0];
+            let prefix = bytes[1];
+            let _is_cidr = bytes[2] != 0;
+            let len = bytes[3];
+
+            match family {
+                PGSQL_AF_INET => {
+                    if bytes.len() == 8 && len == 4 && prefix == 32 {
+                        let addr = Ipv4Addr::new(bytes[4], bytes[5], bytes[6], bytes[7]);
+
+                        return Ok(IpAddr::V4(addr));
+                    }
+                }
+
+                PGSQL_AF_INET6 => {
+                    if bytes.len() == 20 && len == 16 && prefix == 128 {
+                        let addr = Ipv6Addr::from([
+                            bytes[4], bytes[5], bytes[6], bytes[7], bytes[8], bytes[9], bytes[10],
+                            bytes[11], bytes[12], bytes[13], bytes[14], bytes[15], bytes[16],
+                            bytes[17], bytes[18], bytes[19],
+                        ]);
+
+                        return Ok(IpAddr::V6(addr));
+                    }
+                }
+
+                _ => {
+                    return Err(format!("unknown ip family {}", family).into());
+                }
+            }
+        }
+
+        Err("invalid data received when expecting an INET; \
+             networks with a partial prefix cannot decode to `IpAddr`"
+            .into())
+    }
+}
+
+impl Decode<'_, Postgres> for Ipv4Addr {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match <IpAddr as Decode<'_, Postgres>>::decode(value)? {
+            IpAddr::V4(addr) => Ok(addr),
+            IpAddr::V6(_) => Err("expected an IPv4 address, got an IPv6 address".into()),
+        }
+    }
+}
+
+impl Decode<'_, Postgres> for Ipv6Addr {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        match <IpAddr as Decode<'_, Postgres>>::decode(value)? {
+            IpAddr::V6(addr) => Ok(addr),
+            IpAddr::V4(_) => Err("expected an IPv6 address, got an IPv4 address".into()),
+        }
+    }
+}
+
+fn parse_text(text: &str) -> Result<IpAddr, BoxDynError> {
+    let (addr, prefix) = match text.find('/') {
+        Some(pos) => (&text[..pos], Some(text[pos + 1..].parse::<u8>()?)),
+        None => (text, None),
+    };
+
+    let addr: IpAddr = addr.parse()?;
+
+    let full = if addr.is_ipv4() { 32 } else { 128 };
+    if matches!(prefix, Some(prefix) if prefix != full) {
+        return Err("networks with a partial prefix cannot decode to `IpAddr`".into());
+    }
+
+    Ok(addr)
+}
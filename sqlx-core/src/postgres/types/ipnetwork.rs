@@ -80,6 +80,67 @@ impl Encode<'_, Postgres> for IpNetwork {
     }
 }
 
+/// An [`IpNetwork`] bound specifically as the `CIDR` type.
+///
+/// `IpNetwork` itself encodes as `INET`; while PostgreSQL can assign that to a `CIDR`
+/// column, the distinction is lost in the bind parameter, which matters for the
+/// statically-checked query macros and for operators that behave differently on the
+/// two types. This wrapper declares `CIDR` and sets the `is_cidr` flag in the binary
+/// format.
+///
+/// The server rejects a `CIDR` value with bits set to the right of the netmask
+/// (e.g. `192.168.0.1/24`); normalize with [`IpNetwork::network()`] first if needed.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct PgCidr(pub IpNetwork);
+
+impl From<IpNetwork> for PgCidr {
+    fn from(net: IpNetwork) -> Self {
+        Self(net)
+    }
+}
+
+impl Type<Postgres> for PgCidr {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::CIDR
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::CIDR
+    }
+}
+
+impl PgHasArrayType for PgCidr {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::CIDR_ARRAY
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo::CIDR_ARRAY
+    }
+}
+
+impl Encode<'_, Postgres> for PgCidr {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        let offset = buf.len();
+        let is_null = self.0.encode_by_ref(buf);
+
+        // the layout is shared with `INET`; flip the `is_cidr` flag
+        buf[offset + 2] = 1;
+
+        is_null
+    }
+
+    fn size_hint(&self) -> usize {
+        self.0.size_hint()
+    }
+}
+
+impl Decode<'_, Postgres> for PgCidr {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        Ok(Self(IpNetwork::decode(value)?))
+    }
+}
+
 impl Decode<'_, Postgres> for IpNetwork {
     fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
         let bytes = match value.format() {
@@ -0,0 +1,145 @@
+use std::convert::TryInto;
+use std::fmt::{self, Display, Formatter};
+use std::str::FromStr;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{
+    PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
+use crate::types::Type;
+
+/// The PostgreSQL [`MACADDR8`] type, an EUI-64 MAC address stored as 8 bytes.
+///
+/// Unlike `MACADDR`, which is covered by the [`mac_address`](https://crates.io/crates/mac_address)
+/// integration, there is no widely-used Rust crate for EUI-64 addresses, so the raw
+/// octets are exposed directly.
+///
+/// [`MACADDR8`]: https://www.postgresql.org/docs/current/datatype-net-types.html#DATATYPE-MACADDR8
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct PgMacAddr8(pub [u8; 8]);
+
+impl PgMacAddr8 {
+    /// Returns the address as its 8 raw octets.
+    pub fn bytes(self) -> [u8; 8] {
+        self.0
+    }
+
+    /// Convert a 48-bit (EUI-48 / `MACADDR`) address by inserting `FF:FE` between the
+    /// OUI and the NIC-specific octets, as specified for EUI-64 derivation.
+    pub fn from_eui48(bytes: [u8; 6]) -> Self {
+        Self([
+            bytes[0], bytes[1], bytes[2], 0xFF, 0xFE, bytes[3], bytes[4], bytes[5],
+        ])
+    }
+}
+
+impl From<[u8; 8]> for PgMacAddr8 {
+    fn from(bytes: [u8; 8]) -> Self {
+        Self(bytes)
+    }
+}
+
+impl Display for PgMacAddr8 {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let [a, b, c, d, e, g, h, i] = self.0;
+
+        write!(
+            f,
+            "{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}:{:02x}",
+            a, b, c, d, e, g, h, i
+        )
+    }
+}
+
+impl FromStr for PgMacAddr8 {
+    type Err = BoxDynError;
+
+    /// Parse an address of 8 hexadecimal octets separated by `:` or `-`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut bytes = [0_u8; 8];
+        let mut parts = s.split(|ch| ch == ':' || ch == '-');
+
+        for byte in &mut bytes {
+            let part = parts
+                .next()
+                .ok_or("MACADDR8 must consist of 8 octets separated by ':' or '-'")?;
+
+            *byte = u8::from_str_radix(part, 16)?;
+        }
+
+        if parts.next().is_some() {
+            return Err("MACADDR8 must consist of 8 octets separated by ':' or '-'".into());
+        }
+
+        Ok(Self(bytes))
+    }
+}
+
+impl Type<Postgres> for PgMacAddr8 {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo::MACADDR8
+    }
+}
+
+impl PgHasArrayType for PgMacAddr8 {
+    fn array_type_info() -> PgTypeInfo {
+        PgTypeInfo::MACADDR8_ARRAY
+    }
+}
+
+impl Encode<'_, Postgres> for PgMacAddr8 {
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        buf.extend_from_slice(&self.0);
+
+        IsNull::No
+    }
+
+    fn size_hint(&self) -> usize {
+        8
+    }
+}
+
+impl Decode<'_, Postgres> for PgMacAddr8 {
+    fn decode(value: PgValueRef<'_>) -> Result<Self, BoxDynError> {
+        let bytes = match value.format() {
+            PgValueFormat::Binary => value.as_bytes()?,
+            PgValueFormat::Text => {
+                return value.as_str()?.parse();
+            }
+        };
+
+        if bytes.len() == 8 {
+            return Ok(PgMacAddr8(bytes.try_into().unwrap()));
+        }
+
+        Err("invalid data received when expecting a MACADDR8".into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::PgMacAddr8;
+
+    #[test]
+    fn display_and_parse_round_trip() {
+        let addr = PgMacAddr8([0x08, 0x00, 0x2B, 0x01, 0x02, 0x03, 0x04, 0x05]);
+
+        assert_eq!(addr.to_string(), "08:00:2b:01:02:03:04:05");
+        assert_eq!("08:00:2b:01:02:03:04:05".parse::<PgMacAddr8>().unwrap(), addr);
+        assert_eq!("08-00-2B-01-02-03-04-05".parse::<PgMacAddr8>().unwrap(), addr);
+
+        assert!("08:00:2b:01:02:03:04".parse::<PgMacAddr8>().is_err());
+        assert!("08:00:2b:01:02:03:04:05:06".parse::<PgMacAddr8>().is_err());
+        assert!("08:00:2b:01:02:03:04:zz".parse::<PgMacAddr8>().is_err());
+    }
+
+    #[test]
+    fn from_eui48_inserts_fffe() {
+        assert_eq!(
+            PgMacAddr8::from_eui48([0x08, 0x00, 0x2B, 0x01, 0x02, 0x03]),
+            PgMacAddr8([0x08, 0x00, 0x2B, 0xFF, 0xFE, 0x01, 0x02, 0x03])
+        );
+    }
+}
@@ -14,8 +14,16 @@
 //! | `&str`, [`String`]                    | VARCHAR, CHAR(N), TEXT, NAME                         |
 //! | `&[u8]`, `Vec<u8>`                    | BYTEA                                                |
 //! | [`PgInterval`]                        | INTERVAL                                             |
+//! | [`PgIntervalIso8601`]                 | INTERVAL                                             |
+//! | `std::time::Duration`                 | INTERVAL (no month/day component)                    |
 //! | [`PgRange<T>`](PgRange)               | INT8RANGE, INT4RANGE, TSRANGE, TSTZTRANGE, DATERANGE, NUMRANGE |
 //! | [`PgMoney`]                           | MONEY                                                |
+//! | [`PgMacAddr8`]                        | MACADDR8                                             |
+//! | [`PgCiText`]                          | CITEXT                                               |
+//! | [`PgRecord`]                          | RECORD                                               |
+//! | [`PgTimestamp<T>`](PgTimestamp)       | TIMESTAMP, TIMESTAMPTZ (including `infinity`)        |
+//! | `HashMap<String, Option<String>>`     | HSTORE                                               |
+//! | `BTreeMap<String, Option<String>>`    | HSTORE                                               |
 //!
 //!
 //! ### [`bigdecimal`](https://crates.io/crates/bigdecimal)
@@ -57,6 +65,18 @@
 //! | `time::Time`                          | TIME                                                 |
 //! | [`PgTimeTz`]                          | TIMETZ                                               |
 //!
+//! ### [`geo-types`](https://crates.io/crates/geo-types)
+//!
+//! Requires the `geo-types` Cargo feature flag and the
+//! [PostGIS](https://postgis.net/) extension.
+//!
+//! | Rust type                             | Postgres type(s)                                     |
+//! |---------------------------------------|------------------------------------------------------|
+//! | [`PgGeometry<T>`](PgGeometry)         | GEOMETRY, GEOGRAPHY                                  |
+//! | `geo_types::Point<f64>`               | GEOMETRY, GEOGRAPHY                                  |
+//! | `geo_types::LineString<f64>`          | GEOMETRY, GEOGRAPHY                                  |
+//! | `geo_types::Polygon<f64>`             | GEOMETRY, GEOGRAPHY                                  |
+//!
 //! ### [`uuid`](https://crates.io/crates/uuid)
 //!
 //! Requires the `uuid` Cargo feature flag.
@@ -72,6 +92,7 @@
 //! | Rust type                             | Postgres type(s)                                     |
 //! |---------------------------------------|------------------------------------------------------|
 //! | `ipnetwork::IpNetwork`                | INET, CIDR                                           |
+//! | [`PgCidr`]                            | CIDR                                                 |
 //!
 //! ### [`mac_address`](https://crates.io/crates/mac_address)
 //!
@@ -165,13 +186,18 @@ use crate::types::Type;
 mod array;
 mod bool;
 mod bytes;
+mod citext;
 mod float;
+mod hstore;
 mod int;
 mod interval;
+mod ipaddr;
+mod macaddr8;
 mod money;
 mod range;
 mod record;
 mod str;
+mod timestamp;
 mod tuple;
 mod void;
 
@@ -208,10 +234,23 @@ mod mac_address;
 #[cfg(feature = "bit-vec")]
 mod bit_vec;
 
+#[cfg(feature = "geo-types")]
+mod geo_types;
+
 pub use array::PgHasArrayType;
-pub use interval::PgInterval;
+pub use citext::PgCiText;
+pub use interval::{PgInterval, PgIntervalIso8601};
+pub use macaddr8::PgMacAddr8;
 pub use money::PgMoney;
 pub use range::PgRange;
+pub use record::PgRecord;
+pub use timestamp::PgTimestamp;
+
+#[cfg(feature = "geo-types")]
+pub use geo_types::PgGeometry;
+
+#[cfg(feature = "ipnetwork")]
+pub use self::ipnetwork::PgCidr;
 
 #[cfg(any(feature = "chrono", feature = "time"))]
 pub use time_tz::PgTimeTz;
@@ -201,3 +201,81 @@ impl<'r> PgRecordDecoder<'r> {
         }
     }
 }
+
+/// An anonymous `record` value, as returned by `SELECT ROW(1, 'a', true)` or a
+/// function with an undeclared `record` return type.
+///
+/// Unlike decoding into a tuple, the number and types of the fields do not need to
+/// be known up front; each field is decoded on demand by index. Reading a record in
+/// text format (unprepared queries) is not supported and will cause an error.
+pub struct PgRecord<'r> {
+    fields: Vec<PgValueRef<'r>>,
+}
+
+impl<'r> PgRecord<'r> {
+    /// Returns the number of fields in the record.
+    pub fn len(&self) -> usize {
+        self.fields.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.fields.is_empty()
+    }
+
+    /// Decode the field at `index` (zero-based) as `T`.
+    pub fn try_decode<T>(&self, index: usize) -> Result<T, BoxDynError>
+    where
+        T: Decode<'r, Postgres> + Type<Postgres>,
+    {
+        let value = self
+            .fields
+            .get(index)
+            .ok_or_else(|| format!("no field `{}` found on record", index))?;
+
+        if !value.type_info.is_null() && !T::compatible(&value.type_info) {
+            return Err(mismatched_types::<Postgres, T>(&value.type_info));
+        }
+
+        T::decode(value.clone())
+    }
+}
+
+impl Type<Postgres> for PgRecord<'_> {
+    fn type_info() -> PgTypeInfo {
+        PgTypeInfo(PgType::Record)
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        *ty == PgTypeInfo(PgType::Record) || matches!(ty.kind(), PgTypeKind::Composite(_))
+    }
+}
+
+impl<'r> Decode<'r, Postgres> for PgRecord<'r> {
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => {
+                let format = value.format();
+                let mut buf = value.as_bytes()?;
+
+                let count = buf.get_u32();
+
+                let fields = (0..count)
+                    .map(|_| {
+                        let element_type_oid = buf.get_u32();
+
+                        let element_type = PgTypeInfo::try_from_oid(element_type_oid)
+                            .unwrap_or_else(|| PgTypeInfo::with_oid(element_type_oid));
+
+                        PgValueRef::get(&mut buf, format, element_type)
+                    })
+                    .collect();
+
+                Ok(PgRecord { fields })
+            }
+
+            PgValueFormat::Text => {
+                Err("reading a `record` in text format is not supported".into())
+            }
+        }
+    }
+}
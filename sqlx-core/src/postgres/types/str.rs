@@ -18,6 +18,8 @@ impl Type<Postgres> for str {
             PgTypeInfo::BPCHAR,
             PgTypeInfo::VARCHAR,
             PgTypeInfo::UNKNOWN,
+            // an extension type; matched by name
+            PgTypeInfo::with_name("citext"),
         ]
         .contains(ty)
     }
@@ -2,6 +2,7 @@ use crate::decode::Decode;
 use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
 use crate::postgres::types::time::PG_EPOCH;
+use crate::postgres::types::timestamp::{TIMESTAMP_INFINITY, TIMESTAMP_NEG_INFINITY};
 use crate::postgres::{
     PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
 };
@@ -51,7 +52,17 @@ impl<'r> Decode<'r, Postgres> for PrimitiveDateTime {
         Ok(match value.format() {
             PgValueFormat::Binary => {
                 // TIMESTAMP is encoded as the microseconds since the epoch
-                let us = Decode::<Postgres>::decode(value)?;
+                let us: i64 = Decode::<Postgres>::decode(value)?;
+
+                if us == TIMESTAMP_INFINITY || us == TIMESTAMP_NEG_INFINITY {
+                    return Err(format!(
+                        "timestamp is `{}infinity` and has no finite representation; decode as \
+                         `sqlx::postgres::types::PgTimestamp` to handle infinite timestamps",
+                        if us < 0 { "-" } else { "" },
+                    )
+                    .into());
+                }
+
                 PG_EPOCH.midnight() + Duration::microseconds(us)
             }
 
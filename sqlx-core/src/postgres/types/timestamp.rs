@@ -0,0 +1,99 @@
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::postgres::{
+    PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueFormat, PgValueRef, Postgres,
+};
+use crate::types::Type;
+use byteorder::{BigEndian, ByteOrder};
+
+// the microsecond sentinels PostgreSQL uses for `infinity` and `-infinity`
+// in the binary format of `TIMESTAMP`/`TIMESTAMPTZ`
+pub(crate) const TIMESTAMP_INFINITY: i64 = i64::MAX;
+pub(crate) const TIMESTAMP_NEG_INFINITY: i64 = i64::MIN;
+
+/// A `TIMESTAMP` or `TIMESTAMPTZ` value that may be PostgreSQL's `infinity` or
+/// `-infinity`.
+///
+/// The plain date-time mappings return an error when they encounter an infinite
+/// timestamp, as `chrono`/`time` have no representation for it; wrap the mapping
+/// type in this enum when a column can legitimately hold the sentinels, e.g.
+/// `PgTimestamp<DateTime<Utc>>`.
+///
+/// The derived ordering matches the server's: `-infinity` sorts before every
+/// finite timestamp and `infinity` after.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum PgTimestamp<T> {
+    /// The `-infinity` sentinel, earlier than every finite timestamp.
+    NegInfinity,
+
+    /// A finite timestamp.
+    Value(T),
+
+    /// The `infinity` sentinel, later than every finite timestamp.
+    Infinity,
+}
+
+impl<T> Type<Postgres> for PgTimestamp<T>
+where
+    T: Type<Postgres>,
+{
+    fn type_info() -> PgTypeInfo {
+        T::type_info()
+    }
+
+    fn compatible(ty: &PgTypeInfo) -> bool {
+        T::compatible(ty)
+    }
+}
+
+impl<T> PgHasArrayType for PgTimestamp<T>
+where
+    T: PgHasArrayType,
+{
+    fn array_type_info() -> PgTypeInfo {
+        T::array_type_info()
+    }
+
+    fn array_compatible(ty: &PgTypeInfo) -> bool {
+        T::array_compatible(ty)
+    }
+}
+
+impl<'q, T> Encode<'q, Postgres> for PgTimestamp<T>
+where
+    T: Encode<'q, Postgres>,
+{
+    fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+        match self {
+            PgTimestamp::NegInfinity => {
+                Encode::<Postgres>::encode(TIMESTAMP_NEG_INFINITY, buf)
+            }
+
+            PgTimestamp::Value(value) => value.encode_by_ref(buf),
+
+            PgTimestamp::Infinity => Encode::<Postgres>::encode(TIMESTAMP_INFINITY, buf),
+        }
+    }
+}
+
+impl<'r, T> Decode<'r, Postgres> for PgTimestamp<T>
+where
+    T: Decode<'r, Postgres>,
+{
+    fn decode(value: PgValueRef<'r>) -> Result<Self, BoxDynError> {
+        match value.format() {
+            PgValueFormat::Binary => match BigEndian::read_i64(value.as_bytes()?) {
+                TIMESTAMP_NEG_INFINITY => Ok(PgTimestamp::NegInfinity),
+                TIMESTAMP_INFINITY => Ok(PgTimestamp::Infinity),
+                _ => Ok(PgTimestamp::Value(T::decode(value)?)),
+            },
+
+            PgValueFormat::Text => match value.as_str()? {
+                "-infinity" => Ok(PgTimestamp::NegInfinity),
+                "infinity" => Ok(PgTimestamp::Infinity),
+                _ => Ok(PgTimestamp::Value(T::decode(value)?)),
+            },
+        }
+    }
+}
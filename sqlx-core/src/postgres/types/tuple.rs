@@ -1,7 +1,8 @@
 use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
 use crate::error::BoxDynError;
-use crate::postgres::types::PgRecordDecoder;
-use crate::postgres::{PgHasArrayType, PgTypeInfo, PgValueRef, Postgres};
+use crate::postgres::types::{PgRecordDecoder, PgRecordEncoder};
+use crate::postgres::{PgArgumentBuffer, PgHasArrayType, PgTypeInfo, PgValueRef, Postgres};
 use crate::types::Type;
 
 macro_rules! impl_type_for_tuple {
@@ -20,6 +21,23 @@ macro_rules! impl_type_for_tuple {
             }
         }
 
+        impl<$($T,)*> Encode<'_, Postgres> for ($($T,)*)
+        where
+            $($T: for<'q> Encode<'q, Postgres>,)*
+            $($T: Type<Postgres>,)*
+        {
+            fn encode_by_ref(&self, buf: &mut PgArgumentBuffer) -> IsNull {
+                let mut encoder = PgRecordEncoder::new(buf);
+
+                let ($($idx,)*) = self;
+                $(encoder.encode($idx);)*
+
+                encoder.finish();
+
+                IsNull::No
+            }
+        }
+
         impl<'r, $($T,)*> Decode<'r, Postgres> for ($($T,)*)
         where
             $($T: 'r,)*
@@ -83,6 +83,22 @@ impl<'q, DB: Database> Query<'q, DB, <DB as HasArguments<'q>>::Arguments> {
 
         self
     }
+
+    /// Bind each value of an iterator for use with this SQL query.
+    ///
+    /// This is intended to be paired with [`in_placeholders`] which produces
+    /// the matching list of placeholders for an `IN (...)` clause.
+    pub fn bind_in<T, I>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: 'q + Send + Encode<'q, DB> + Type<DB>,
+    {
+        for value in values {
+            self = self.bind(value);
+        }
+
+        self
+    }
 }
 
 impl<'q, DB, A> Query<'q, DB, A>
@@ -452,3 +468,64 @@ where
         persistent: true,
     }
 }
+
+/// Returns a comma-separated list of `count` bind placeholders for splicing
+/// into an `IN (...)` clause, in the placeholder dialect of `DB`.
+///
+/// `start` is the 1-based index of the first placeholder; it only matters for
+/// databases with positional placeholders (e.g., PostgreSQL) and should be one
+/// past the number of placeholders appearing earlier in the query.
+///
+/// An empty list expands to `NULL` so that the resulting `IN (NULL)` clause
+/// matches no rows instead of being a syntax error.
+///
+/// ```rust,ignore
+/// let ids = [1_i32, 2, 3];
+///
+/// let sql = format!(
+///     "SELECT * FROM users WHERE id IN ({})",
+///     sqlx::in_placeholders::<Postgres>(1, ids.len()),
+/// );
+///
+/// let users = sqlx::query(&sql).bind_in(ids).fetch_all(&mut conn).await?;
+/// ```
+pub fn in_placeholders<DB>(start: usize, count: usize) -> String
+where
+    DB: Database,
+{
+    if count == 0 {
+        return "NULL".to_owned();
+    }
+
+    let mut sql = String::new();
+
+    for index in start..start + count {
+        if index > start {
+            sql.push_str(", ");
+        }
+
+        DB::fmt_placeholder(&mut sql, index);
+    }
+
+    sql
+}
+
+#[cfg(all(test, feature = "postgres", feature = "mysql"))]
+mod tests {
+    use super::in_placeholders;
+    use crate::mysql::MySql;
+    use crate::postgres::Postgres;
+
+    #[test]
+    fn it_expands_in_placeholders() {
+        assert_eq!(in_placeholders::<MySql>(1, 5), "?, ?, ?, ?, ?");
+        assert_eq!(in_placeholders::<Postgres>(1, 3), "$1, $2, $3");
+        assert_eq!(in_placeholders::<Postgres>(4, 2), "$4, $5");
+    }
+
+    #[test]
+    fn it_expands_the_empty_in_list_to_null() {
+        assert_eq!(in_placeholders::<MySql>(1, 0), "NULL");
+        assert_eq!(in_placeholders::<Postgres>(1, 0), "NULL");
+    }
+}
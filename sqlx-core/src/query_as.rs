@@ -55,6 +55,18 @@ impl<'q, DB: Database, O> QueryAs<'q, DB, O, <DB as HasArguments<'q>>::Arguments
         self.inner = self.inner.bind(value);
         self
     }
+
+    /// Bind each value of an iterator for use with this SQL query.
+    ///
+    /// See [`Query::bind_in`][crate::query::Query::bind_in].
+    pub fn bind_in<T, I>(mut self, values: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: 'q + Send + Encode<'q, DB> + Type<DB>,
+    {
+        self.inner = self.inner.bind_in(values);
+        self
+    }
 }
 
 impl<'q, DB, O, A> QueryAs<'q, DB, O, A>
@@ -79,6 +79,13 @@ impl SqliteArguments<'_> {
                             name
                         )
                     })?
+                } else if name.starts_with(':') || name.starts_with('@') {
+                    // named parameters are numbered by SQLite in order of first appearance,
+                    // so they bind to the arguments in that order; a reused name keeps its
+                    // original index and thus consumes only one argument
+                    let n = offset + param_i;
+                    arg_i = std::cmp::max(arg_i, n);
+                    n
                 } else {
                     return Err(err_protocol!("unsupported SQL parameter format: {}", name));
                 }
@@ -0,0 +1,249 @@
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+
+use libsqlite3_sys::{
+    sqlite3_set_authorizer, SQLITE_ALTER_TABLE, SQLITE_ANALYZE, SQLITE_ATTACH, SQLITE_CREATE_INDEX,
+    SQLITE_CREATE_TABLE, SQLITE_CREATE_TEMP_INDEX, SQLITE_CREATE_TEMP_TABLE,
+    SQLITE_CREATE_TEMP_TRIGGER, SQLITE_CREATE_TEMP_VIEW, SQLITE_CREATE_TRIGGER,
+    SQLITE_CREATE_VIEW, SQLITE_CREATE_VTABLE, SQLITE_DELETE, SQLITE_DENY, SQLITE_DETACH,
+    SQLITE_DROP_INDEX, SQLITE_DROP_TABLE, SQLITE_DROP_TEMP_INDEX, SQLITE_DROP_TEMP_TABLE,
+    SQLITE_DROP_TEMP_TRIGGER, SQLITE_DROP_TEMP_VIEW, SQLITE_DROP_TRIGGER, SQLITE_DROP_VIEW,
+    SQLITE_DROP_VTABLE, SQLITE_FUNCTION, SQLITE_IGNORE, SQLITE_INSERT, SQLITE_OK, SQLITE_PRAGMA,
+    SQLITE_READ, SQLITE_RECURSIVE, SQLITE_REINDEX, SQLITE_SAVEPOINT, SQLITE_SELECT,
+    SQLITE_TRANSACTION, SQLITE_UPDATE,
+};
+
+use crate::error::Error;
+use crate::sqlite::connection::ConnectionState;
+use crate::sqlite::SqliteError;
+
+/// The kind of action being authorized, from the action codes of
+/// [`sqlite3_set_authorizer()`](https://www.sqlite.org/c3ref/c_alter_table.html).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqliteAuthActionCode {
+    CreateIndex,
+    CreateTable,
+    CreateTempIndex,
+    CreateTempTable,
+    CreateTempTrigger,
+    CreateTempView,
+    CreateTrigger,
+    CreateView,
+    Delete,
+    DropIndex,
+    DropTable,
+    DropTempIndex,
+    DropTempTable,
+    DropTempTrigger,
+    DropTempView,
+    DropTrigger,
+    DropView,
+    Insert,
+    Pragma,
+    Read,
+    Select,
+    Transaction,
+    Update,
+    Attach,
+    Detach,
+    AlterTable,
+    Reindex,
+    Analyze,
+    CreateVtable,
+    DropVtable,
+    Function,
+    Savepoint,
+    Recursive,
+
+    /// An action code added in a later version of SQLite than this enum covers.
+    Other(i32),
+}
+
+impl SqliteAuthActionCode {
+    fn from_code(code: c_int) -> Self {
+        match code {
+            SQLITE_CREATE_INDEX => Self::CreateIndex,
+            SQLITE_CREATE_TABLE => Self::CreateTable,
+            SQLITE_CREATE_TEMP_INDEX => Self::CreateTempIndex,
+            SQLITE_CREATE_TEMP_TABLE => Self::CreateTempTable,
+            SQLITE_CREATE_TEMP_TRIGGER => Self::CreateTempTrigger,
+            SQLITE_CREATE_TEMP_VIEW => Self::CreateTempView,
+            SQLITE_CREATE_TRIGGER => Self::CreateTrigger,
+            SQLITE_CREATE_VIEW => Self::CreateView,
+            SQLITE_DELETE => Self::Delete,
+            SQLITE_DROP_INDEX => Self::DropIndex,
+            SQLITE_DROP_TABLE => Self::DropTable,
+            SQLITE_DROP_TEMP_INDEX => Self::DropTempIndex,
+            SQLITE_DROP_TEMP_TABLE => Self::DropTempTable,
+            SQLITE_DROP_TEMP_TRIGGER => Self::DropTempTrigger,
+            SQLITE_DROP_TEMP_VIEW => Self::DropTempView,
+            SQLITE_DROP_TRIGGER => Self::DropTrigger,
+            SQLITE_DROP_VIEW => Self::DropView,
+            SQLITE_INSERT => Self::Insert,
+            SQLITE_PRAGMA => Self::Pragma,
+            SQLITE_READ => Self::Read,
+            SQLITE_SELECT => Self::Select,
+            SQLITE_TRANSACTION => Self::Transaction,
+            SQLITE_UPDATE => Self::Update,
+            SQLITE_ATTACH => Self::Attach,
+            SQLITE_DETACH => Self::Detach,
+            SQLITE_ALTER_TABLE => Self::AlterTable,
+            SQLITE_REINDEX => Self::Reindex,
+            SQLITE_ANALYZE => Self::Analyze,
+            SQLITE_CREATE_VTABLE => Self::CreateVtable,
+            SQLITE_DROP_VTABLE => Self::DropVtable,
+            SQLITE_FUNCTION => Self::Function,
+            SQLITE_SAVEPOINT => Self::Savepoint,
+            SQLITE_RECURSIVE => Self::Recursive,
+            other => Self::Other(other),
+        }
+    }
+}
+
+/// An action about to be performed by a statement being prepared, passed to the
+/// callback registered by
+/// [`SqliteConnection::set_authorizer()`][crate::sqlite::SqliteConnection::set_authorizer].
+#[derive(Debug, Clone, Copy)]
+pub struct SqliteAuthAction<'a> {
+    code: SqliteAuthActionCode,
+    arg1: Option<&'a str>,
+    arg2: Option<&'a str>,
+    database: Option<&'a str>,
+    accessor: Option<&'a str>,
+}
+
+impl<'a> SqliteAuthAction<'a> {
+    /// The kind of action being authorized.
+    pub fn code(&self) -> SqliteAuthActionCode {
+        self.code
+    }
+
+    /// The first action-specific argument; for [`SqliteAuthActionCode::Read`] and
+    /// [`SqliteAuthActionCode::Update`] this is the table name.
+    pub fn arg1(&self) -> Option<&'a str> {
+        self.arg1
+    }
+
+    /// The second action-specific argument; for [`SqliteAuthActionCode::Read`] and
+    /// [`SqliteAuthActionCode::Update`] this is the column name.
+    pub fn arg2(&self) -> Option<&'a str> {
+        self.arg2
+    }
+
+    /// The name of the database the action applies to (`main`, `temp`, or the name
+    /// of an attached database), if applicable.
+    pub fn database(&self) -> Option<&'a str> {
+        self.database
+    }
+
+    /// The name of the innermost trigger or view responsible for the action, or
+    /// `None` if the action comes directly from input SQL.
+    pub fn accessor(&self) -> Option<&'a str> {
+        self.accessor
+    }
+}
+
+/// The verdict returned from an authorizer callback.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqliteAuthorization {
+    /// Allow the action (`SQLITE_OK`).
+    Allow,
+
+    /// Disallow the action but allow the statement to continue; for
+    /// [`SqliteAuthActionCode::Read`] the column value is replaced with `NULL`
+    /// (`SQLITE_IGNORE`).
+    Ignore,
+
+    /// Reject the whole statement with an authorization error (`SQLITE_DENY`).
+    Deny,
+}
+
+impl SqliteAuthorization {
+    fn into_code(self) -> c_int {
+        match self {
+            Self::Allow => SQLITE_OK,
+            Self::Ignore => SQLITE_IGNORE,
+            Self::Deny => SQLITE_DENY,
+        }
+    }
+}
+
+pub(crate) type AuthorizerFn =
+    Box<dyn FnMut(SqliteAuthAction<'_>) -> SqliteAuthorization + Send + 'static>;
+
+/// Register `authorizer` with `sqlite3_set_authorizer()`, or clear any registered
+/// authorizer if `None`.
+///
+/// As with the WAL hook, the closure is stored in the [`ConnectionState`] so that its
+/// context pointer stays valid for as long as it is registered.
+pub(crate) fn set_authorizer(
+    conn: &mut ConnectionState,
+    authorizer: Option<AuthorizerFn>,
+) -> Result<(), Error> {
+    let previous = conn.authorizer.take();
+
+    let status = match authorizer {
+        Some(authorizer) => {
+            let mut authorizer = Box::new(authorizer);
+
+            // SAFETY: we have exclusive access to the database handle, and the boxed
+            // closure outlives the registration; it is replaced or cleared through
+            // this function only.
+            let status = unsafe {
+                sqlite3_set_authorizer(
+                    conn.handle.as_ptr(),
+                    Some(authorizer_callback),
+                    &mut *authorizer as *mut AuthorizerFn as *mut c_void,
+                )
+            };
+
+            conn.authorizer = Some(authorizer);
+            status
+        }
+
+        // SAFETY: as above; a null callback unregisters the authorizer.
+        None => unsafe { sqlite3_set_authorizer(conn.handle.as_ptr(), None, ptr::null_mut()) },
+    };
+
+    drop(previous);
+
+    if status == SQLITE_OK {
+        Ok(())
+    } else {
+        Err(Error::Database(Box::new(SqliteError::new(
+            conn.handle.as_ptr(),
+        ))))
+    }
+}
+
+unsafe extern "C" fn authorizer_callback(
+    ctx: *mut c_void,
+    code: c_int,
+    arg1: *const c_char,
+    arg2: *const c_char,
+    database: *const c_char,
+    accessor: *const c_char,
+) -> c_int {
+    let authorizer: *mut AuthorizerFn = ctx as *mut AuthorizerFn;
+    debug_assert!(!authorizer.is_null());
+
+    let action = SqliteAuthAction {
+        code: SqliteAuthActionCode::from_code(code),
+        arg1: opt_str(arg1),
+        arg2: opt_str(arg2),
+        database: opt_str(database),
+        accessor: opt_str(accessor),
+    };
+
+    (*authorizer)(action).into_code()
+}
+
+unsafe fn opt_str<'a>(ptr: *const c_char) -> Option<&'a str> {
+    if ptr.is_null() {
+        None
+    } else {
+        // identifiers are valid UTF-8 in any practical usage
+        CStr::from_ptr(ptr).to_str().ok()
+    }
+}
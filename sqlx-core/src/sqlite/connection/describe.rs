@@ -17,11 +17,15 @@ pub(super) fn describe(conn: &mut ConnectionState, query: &str) -> Result<Descri
     let mut columns = Vec::new();
     let mut nullable = Vec::new();
     let mut num_params = 0;
+    let mut read_only = true;
 
     // we start by finding the first statement that *can* return results
     while let Some(stmt) = statement.prepare_next(&mut conn.handle)? {
         num_params += stmt.handle.bind_parameter_count();
 
+        // the query is read-only if every statement in it is
+        read_only &= stmt.handle.read_only();
+
         let mut stepped = false;
 
         let num = stmt.handle.column_count();
@@ -90,5 +94,6 @@ pub(super) fn describe(conn: &mut ConnectionState, query: &str) -> Result<Descri
         columns,
         parameters: Some(Either::Right(num_params)),
         nullable,
+        read_only: Some(read_only),
     })
 }
@@ -1,5 +1,6 @@
 use crate::connection::LogSettings;
 use crate::error::Error;
+use crate::metrics::ConnectionMetrics;
 use crate::sqlite::connection::handle::ConnectionHandle;
 use crate::sqlite::connection::{ConnectionState, Statements};
 use crate::sqlite::{SqliteConnectOptions, SqliteError};
@@ -8,7 +9,7 @@ use libsqlite3_sys::{
     SQLITE_OPEN_CREATE, SQLITE_OPEN_FULLMUTEX, SQLITE_OPEN_MEMORY, SQLITE_OPEN_NOMUTEX,
     SQLITE_OPEN_PRIVATECACHE, SQLITE_OPEN_READONLY, SQLITE_OPEN_READWRITE, SQLITE_OPEN_SHAREDCACHE,
 };
-use std::ffi::CString;
+use std::ffi::{CStr, CString};
 use std::io;
 use std::sync::atomic::{AtomicU64, Ordering};
 use std::time::Duration;
@@ -21,11 +22,15 @@ static THREAD_ID: AtomicU64 = AtomicU64::new(0);
 
 pub struct EstablishParams {
     filename: CString,
+    vfs: Option<CString>,
     open_flags: i32,
     busy_timeout: Duration,
+    begin_immediate_retry: Option<(u32, Duration)>,
     statement_cache_capacity: usize,
     log_settings: LogSettings,
+    pub(crate) metrics: ConnectionMetrics,
     pub(crate) thread_name: String,
+    pub(crate) worker_stack_size: Option<usize>,
     pub(crate) command_channel_size: usize,
 }
 
@@ -82,13 +87,29 @@ impl EstablishParams {
             )
         })?;
 
+        let vfs = options
+            .vfs
+            .as_deref()
+            .map(CString::new)
+            .transpose()
+            .map_err(|_| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "VFS name passed to SQLite must not contain nul bytes",
+                )
+            })?;
+
         Ok(Self {
             filename,
+            vfs,
             open_flags: flags,
             busy_timeout: options.busy_timeout,
+            begin_immediate_retry: options.begin_immediate_retry,
             statement_cache_capacity: options.statement_cache_capacity,
             log_settings: options.log_settings.clone(),
+            metrics: ConnectionMetrics::default(),
             thread_name: (options.thread_name)(THREAD_ID.fetch_add(1, Ordering::AcqRel)),
+            worker_stack_size: options.worker_stack_size,
             command_channel_size: options.command_channel_size,
         })
     }
@@ -98,7 +119,12 @@ impl EstablishParams {
 
         // <https://www.sqlite.org/c3ref/open.html>
         let mut status = unsafe {
-            sqlite3_open_v2(self.filename.as_ptr(), &mut handle, self.open_flags, null())
+            sqlite3_open_v2(
+                self.filename.as_ptr(),
+                &mut handle,
+                self.open_flags,
+                self.vfs.as_deref().map_or(null(), CStr::as_ptr),
+            )
         };
 
         if handle.is_null() {
@@ -142,7 +168,11 @@ impl EstablishParams {
             handle,
             statements: Statements::new(self.statement_cache_capacity),
             transaction_depth: 0,
+            begin_immediate_retry: self.begin_immediate_retry,
             log_settings: self.log_settings.clone(),
+            metrics: self.metrics.clone(),
+            wal_hook: None,
+            authorizer: None,
         })
     }
 }
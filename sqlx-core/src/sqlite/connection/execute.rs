@@ -27,7 +27,11 @@ pub(crate) fn iter<'a>(
     // fetch the cached statement or allocate a new one
     let statement = conn.statements.get(query, persistent)?;
 
-    let logger = QueryLogger::new(query, conn.log_settings.clone());
+    let mut logger = QueryLogger::new(query, conn.log_settings.clone(), conn.metrics.clone());
+
+    if let Some(args) = &args {
+        logger.set_parameters(|| format!("{:?}", args.values));
+    }
 
     Ok(ExecuteIter {
         handle: &mut conn.handle,
@@ -86,6 +86,18 @@ impl<'c> Executor<'c> for &'c mut SqliteConnection {
     where
         'c: 'e,
     {
-        Box::pin(self.worker.describe(sql))
+        Box::pin(async move {
+            if let Some(describe) = self.describe_cache.get_mut(sql) {
+                return Ok(describe.clone());
+            }
+
+            let describe = self.worker.describe(sql).await?;
+
+            if self.describe_cache.is_enabled() {
+                self.describe_cache.insert(sql, describe.clone());
+            }
+
+            Ok(describe)
+        })
     }
 }
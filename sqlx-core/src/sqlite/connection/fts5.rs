@@ -0,0 +1,269 @@
+use std::ffi::{CStr, CString};
+use std::ops::Range;
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+
+use libsqlite3_sys::{
+    fts5_api, fts5_tokenizer, sqlite3_bind_pointer, sqlite3_finalize, sqlite3_prepare_v2,
+    sqlite3_step, Fts5Tokenizer, SQLITE_ERROR, SQLITE_OK,
+};
+
+use crate::error::Error;
+use crate::sqlite::connection::handle::ConnectionHandle;
+use crate::sqlite::SqliteError;
+
+/// A custom FTS5 tokenizer, registered with
+/// [`SqliteConnection::register_fts5_tokenizer()`][crate::sqlite::SqliteConnection::register_fts5_tokenizer].
+///
+/// One instance is created per FTS5 table using the tokenizer; FTS5 calls
+/// [`tokenize()`][Self::tokenize] both while indexing documents and while parsing
+/// `MATCH` queries, so the same text must always produce the same tokens.
+pub trait SqliteFts5Tokenizer: Send + 'static {
+    /// Tokenize `text`, calling [`SqliteFts5Tokens::push()`] for each emitted token.
+    ///
+    /// `flags` is the `FTS5_TOKENIZE_*` bitmask describing why the text is being
+    /// tokenized (document insertion, query parsing, or an auxiliary function).
+    fn tokenize(
+        &mut self,
+        flags: i32,
+        text: &str,
+        tokens: &mut SqliteFts5Tokens<'_>,
+    ) -> Result<(), Error>;
+}
+
+/// Sink for the token spans emitted by an [`SqliteFts5Tokenizer`].
+pub struct SqliteFts5Tokens<'a> {
+    ctx: *mut c_void,
+    push: unsafe extern "C" fn(
+        ctx: *mut c_void,
+        flags: c_int,
+        token: *const c_char,
+        token_len: c_int,
+        start: c_int,
+        end: c_int,
+    ) -> c_int,
+    text_len: usize,
+    _marker: std::marker::PhantomData<&'a ()>,
+}
+
+impl SqliteFts5Tokens<'_> {
+    /// Emit `token` for the byte range `span` of the text being tokenized.
+    ///
+    /// The token itself does not have to be a verbatim slice of the text (it is
+    /// typically case-folded), but `span` must lie within the text so that
+    /// highlighting and snippets can map tokens back to it.
+    pub fn push(&mut self, token: &str, span: Range<usize>) -> Result<(), Error> {
+        if span.end > self.text_len || span.start > span.end {
+            return Err(err_protocol!(
+                "token span {}..{} out of bounds for text of {} bytes",
+                span.start,
+                span.end,
+                self.text_len
+            ));
+        }
+
+        let status = unsafe {
+            (self.push)(
+                self.ctx,
+                0,
+                token.as_ptr() as *const c_char,
+                token.len() as c_int,
+                span.start as c_int,
+                span.end as c_int,
+            )
+        };
+
+        if status == SQLITE_OK {
+            Ok(())
+        } else {
+            Err(err_protocol!(
+                "FTS5 token callback returned error code {}",
+                status
+            ))
+        }
+    }
+}
+
+pub(crate) type TokenizerFactoryFn = Box<
+    dyn Fn(&[&str]) -> Result<Box<dyn SqliteFts5Tokenizer>, Error> + Send + Sync + 'static,
+>;
+
+/// Register `factory` as the FTS5 tokenizer `name` on this connection, using the
+/// `fts5_api` pointer obtained from the `fts5()` SQL function.
+pub(crate) fn register_tokenizer(
+    handle: &mut ConnectionHandle,
+    name: &str,
+    factory: TokenizerFactoryFn,
+) -> Result<(), Error> {
+    let api = fts5_api_ptr(handle)?;
+
+    let c_name =
+        CString::new(name).map_err(|_| err_protocol!("invalid tokenizer name: {:?}", name))?;
+
+    let tokenizer = fts5_tokenizer {
+        xCreate: Some(tokenizer_create),
+        xDelete: Some(tokenizer_delete),
+        xTokenize: Some(tokenizer_tokenize),
+    };
+
+    let create_tokenizer = unsafe { (*api).xCreateTokenizer }
+        .ok_or_else(|| err_protocol!("fts5_api is missing xCreateTokenizer"))?;
+
+    let ctx = Box::into_raw(Box::new(factory));
+
+    // SAFETY: FTS5 copies the `fts5_tokenizer` struct and takes ownership of `ctx`,
+    // freeing it through `drop_factory` when the tokenizer is replaced or the
+    // database is closed.
+    let status = unsafe {
+        create_tokenizer(
+            api,
+            c_name.as_ptr(),
+            ctx as *mut c_void,
+            &tokenizer as *const fts5_tokenizer as *mut fts5_tokenizer,
+            Some(drop_factory),
+        )
+    };
+
+    if status == SQLITE_OK {
+        Ok(())
+    } else {
+        // xDestroy is not called if registration fails
+        drop(unsafe { Box::from_raw(ctx) });
+
+        Err(err_protocol!(
+            "failed to register FTS5 tokenizer {:?}: error code {}",
+            name,
+            status
+        ))
+    }
+}
+
+/// Fetch the `fts5_api` pointer by evaluating `SELECT fts5(?1)` with a pointer binding.
+///
+/// <https://www.sqlite.org/fts5.html#extending_fts5>
+fn fts5_api_ptr(handle: &mut ConnectionHandle) -> Result<*mut fts5_api, Error> {
+    let mut api: *mut fts5_api = ptr::null_mut();
+
+    // SAFETY: we have exclusive access to the database handle
+    unsafe {
+        let mut stmt = ptr::null_mut();
+
+        if sqlite3_prepare_v2(
+            handle.as_ptr(),
+            "SELECT fts5(?1)\0".as_ptr() as *const c_char,
+            -1,
+            &mut stmt,
+            ptr::null_mut(),
+        ) != SQLITE_OK
+        {
+            return Err(Error::Database(Box::new(SqliteError::new(handle.as_ptr()))));
+        }
+
+        sqlite3_bind_pointer(
+            stmt,
+            1,
+            &mut api as *mut *mut fts5_api as *mut c_void,
+            "fts5_api_ptr\0".as_ptr() as *const c_char,
+            None,
+        );
+
+        sqlite3_step(stmt);
+        sqlite3_finalize(stmt);
+    }
+
+    if api.is_null() {
+        Err(err_protocol!(
+            "FTS5 is not available in this build of SQLite"
+        ))
+    } else {
+        Ok(api)
+    }
+}
+
+unsafe extern "C" fn drop_factory(ctx: *mut c_void) {
+    drop(Box::from_raw(ctx as *mut TokenizerFactoryFn));
+}
+
+unsafe extern "C" fn tokenizer_create(
+    ctx: *mut c_void,
+    args: *mut *const c_char,
+    n_args: c_int,
+    out: *mut *mut Fts5Tokenizer,
+) -> c_int {
+    let factory = &*(ctx as *const TokenizerFactoryFn);
+
+    let mut parsed = Vec::with_capacity(n_args as usize);
+
+    for i in 0..n_args as isize {
+        match CStr::from_ptr(*args.offset(i)).to_str() {
+            Ok(arg) => parsed.push(arg),
+            Err(_) => return SQLITE_ERROR,
+        }
+    }
+
+    match factory(&parsed) {
+        Ok(tokenizer) => {
+            // double-boxed as `dyn SqliteFts5Tokenizer` is a wide pointer
+            *out = Box::into_raw(Box::new(tokenizer)) as *mut Fts5Tokenizer;
+
+            SQLITE_OK
+        }
+        Err(error) => {
+            log::error!("error creating FTS5 tokenizer: {}", error);
+
+            SQLITE_ERROR
+        }
+    }
+}
+
+unsafe extern "C" fn tokenizer_delete(tokenizer: *mut Fts5Tokenizer) {
+    drop(Box::from_raw(tokenizer as *mut Box<dyn SqliteFts5Tokenizer>));
+}
+
+unsafe extern "C" fn tokenizer_tokenize(
+    tokenizer: *mut Fts5Tokenizer,
+    ctx: *mut c_void,
+    flags: c_int,
+    text: *const c_char,
+    text_len: c_int,
+    push: Option<
+        unsafe extern "C" fn(
+            ctx: *mut c_void,
+            flags: c_int,
+            token: *const c_char,
+            token_len: c_int,
+            start: c_int,
+            end: c_int,
+        ) -> c_int,
+    >,
+) -> c_int {
+    let tokenizer = &mut **(tokenizer as *mut Box<dyn SqliteFts5Tokenizer>);
+
+    let push = match push {
+        Some(push) => push,
+        None => return SQLITE_ERROR,
+    };
+
+    let text = std::slice::from_raw_parts(text as *const u8, text_len as usize);
+
+    let text = match std::str::from_utf8(text) {
+        Ok(text) => text,
+        Err(_) => return SQLITE_ERROR,
+    };
+
+    let mut tokens = SqliteFts5Tokens {
+        ctx,
+        push,
+        text_len: text.len(),
+        _marker: std::marker::PhantomData,
+    };
+
+    match tokenizer.tokenize(flags, text, &mut tokens) {
+        Ok(()) => SQLITE_OK,
+        Err(error) => {
+            log::error!("error in FTS5 tokenizer: {}", error);
+
+            SQLITE_ERROR
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use std::cmp::Ordering;
 use std::fmt::{self, Debug, Formatter};
 use std::ptr::NonNull;
+use std::time::Duration;
 
 use futures_core::future::BoxFuture;
 use futures_intrusive::sync::MutexGuard;
@@ -11,11 +12,14 @@ pub(crate) use handle::{ConnectionHandle, ConnectionHandleRaw};
 
 use crate::common::StatementCache;
 use crate::connection::{Connection, LogSettings};
+use crate::describe::Describe;
 use crate::error::Error;
+use crate::executor::Executor;
+use crate::metrics::ConnectionMetrics;
 use crate::sqlite::connection::establish::EstablishParams;
 use crate::sqlite::connection::worker::ConnectionWorker;
 use crate::sqlite::statement::VirtualStatement;
-use crate::sqlite::{Sqlite, SqliteConnectOptions};
+use crate::sqlite::{Sqlite, SqliteConnectOptions, SqliteQueryResult};
 use crate::transaction::Transaction;
 
 pub(crate) mod collation;
@@ -23,11 +27,20 @@ mod describe;
 mod establish;
 mod execute;
 mod executor;
+pub(crate) mod authorizer;
 mod explain;
+pub(crate) mod fts5;
 mod handle;
+pub(crate) mod stats;
+pub(crate) mod wal_hook;
 
 mod worker;
 
+pub use authorizer::{SqliteAuthAction, SqliteAuthActionCode, SqliteAuthorization};
+pub use fts5::{SqliteFts5Tokenizer, SqliteFts5Tokens};
+pub use stats::SqliteDatabaseStats;
+pub use wal_hook::SqliteWalHookResult;
+
 /// A connection to an open [Sqlite] database.
 ///
 /// Because SQLite is an in-process database accessed by blocking API calls, SQLx uses a background
@@ -41,6 +54,14 @@ mod worker;
 pub struct SqliteConnection {
     pub(crate) worker: ConnectionWorker,
     pub(crate) row_channel_size: usize,
+
+    metrics: ConnectionMetrics,
+
+    // whether `PRAGMA query_only` was enabled through `set_query_only()`
+    query_only: bool,
+
+    // cache describe results by query string; disabled unless opted in
+    pub(crate) describe_cache: StatementCache<Describe<Sqlite>>,
 }
 
 pub struct LockedSqliteHandle<'a> {
@@ -53,9 +74,21 @@ pub(crate) struct ConnectionState {
     // transaction status
     pub(crate) transaction_depth: usize,
 
+    // `BEGIN IMMEDIATE` retry policy: (max retries, initial backoff)
+    pub(crate) begin_immediate_retry: Option<(u32, Duration)>,
+
     pub(crate) statements: Statements,
 
     log_settings: LogSettings,
+
+    metrics: ConnectionMetrics,
+
+    // the registered WAL hook, if any; boxed so that the context pointer handed to
+    // `sqlite3_wal_hook()` remains stable
+    pub(crate) wal_hook: Option<Box<wal_hook::WalHookFn>>,
+
+    // the registered authorizer, if any; boxed for the same reason
+    pub(crate) authorizer: Option<Box<authorizer::AuthorizerFn>>,
 }
 
 pub(crate) struct Statements {
@@ -68,10 +101,14 @@ pub(crate) struct Statements {
 impl SqliteConnection {
     pub(crate) async fn establish(options: &SqliteConnectOptions) -> Result<Self, Error> {
         let params = EstablishParams::from_options(options)?;
+        let metrics = params.metrics.clone();
         let worker = ConnectionWorker::establish(params).await?;
         Ok(Self {
             worker,
             row_channel_size: options.row_channel_size,
+            metrics,
+            query_only: false,
+            describe_cache: StatementCache::new(options.describe_cache_capacity),
         })
     }
 
@@ -119,6 +156,149 @@ impl SqliteConnection {
         self.worker.create_collation(name, compare)
     }
 
+    /// Register a callback to be invoked on the worker thread each time a transaction
+    /// is committed in [WAL mode][crate::sqlite::SqliteJournalMode::Wal].
+    ///
+    /// The callback receives the database name (`main`, or the name of an attached
+    /// database) and the current number of pages in the write-ahead log. Returning
+    /// [`SqliteWalHookResult::Checkpoint`] runs a passive checkpoint before the commit
+    /// returns, allowing applications to checkpoint on their own threshold.
+    ///
+    /// Registering a hook replaces any previously registered hook, including the
+    /// internal one installed by `PRAGMA wal_autocheckpoint`; automatic checkpointing
+    /// is therefore disabled while a hook is set. Use
+    /// [`.remove_wal_hook()`][Self::remove_wal_hook] to unregister it again.
+    ///
+    /// Note that like [`.create_collation()`][Self::create_collation], this completes
+    /// asynchronously on the worker thread; however, registration itself cannot fail.
+    /// [`Error::WorkerCrashed`] is returned if we could not communicate with the worker.
+    pub fn set_wal_hook(
+        &mut self,
+        hook: impl FnMut(&str, i32) -> SqliteWalHookResult + Send + 'static,
+    ) -> Result<(), Error> {
+        self.worker.set_wal_hook(Some(Box::new(hook)))
+    }
+
+    /// Register an authorizer callback, invoked on the worker thread for each action a
+    /// statement being prepared would perform.
+    ///
+    /// The callback is passed an [`SqliteAuthAction`] describing the action (reading a
+    /// column, attaching a database, running a pragma, etc.) and decides its fate with
+    /// an [`SqliteAuthorization`]: [`Deny`][SqliteAuthorization::Deny] rejects the whole
+    /// statement so that preparation fails with an authorization error,
+    /// [`Ignore`][SqliteAuthorization::Ignore] disallows just that action, and
+    /// [`Allow`][SqliteAuthorization::Allow] lets it proceed.
+    ///
+    /// This can be used to sandbox dynamically constructed or user-provided SQL.
+    /// Registering an authorizer replaces any previously registered one; use
+    /// [`.remove_authorizer()`][Self::remove_authorizer] to unregister it again.
+    ///
+    /// Note that like [`.create_collation()`][Self::create_collation], this completes
+    /// asynchronously on the worker thread. [`Error::WorkerCrashed`] is returned if we
+    /// could not communicate with the worker.
+    pub fn set_authorizer(
+        &mut self,
+        authorizer: impl FnMut(SqliteAuthAction<'_>) -> SqliteAuthorization + Send + 'static,
+    ) -> Result<(), Error> {
+        self.worker.set_authorizer(Some(Box::new(authorizer)))
+    }
+
+    /// Unregister the callback registered by [`.set_authorizer()`][Self::set_authorizer].
+    pub fn remove_authorizer(&mut self) -> Result<(), Error> {
+        self.worker.set_authorizer(None)
+    }
+
+    /// Unregister the callback registered by [`.set_wal_hook()`][Self::set_wal_hook].
+    ///
+    /// This does not restore automatic checkpointing; re-apply
+    /// `PRAGMA wal_autocheckpoint` if that behavior is wanted back.
+    pub fn remove_wal_hook(&mut self) -> Result<(), Error> {
+        self.worker.set_wal_hook(None)
+    }
+
+    /// Enable or disable [`PRAGMA query_only`](https://www.sqlite.org/pragma.html#pragma_query_only)
+    /// on this connection.
+    ///
+    /// While enabled, any statement that would change the database (including temporary
+    /// tables) fails with a readonly error, without the connection having to be reopened
+    /// with [`read_only`][crate::sqlite::SqliteConnectOptions::read_only]. This is
+    /// useful to temporarily protect a connection, e.g. for the duration of a
+    /// reporting job.
+    pub async fn set_query_only(&mut self, query_only: bool) -> Result<(), Error> {
+        self.execute(if query_only {
+            "PRAGMA query_only = ON"
+        } else {
+            "PRAGMA query_only = OFF"
+        })
+        .await?;
+
+        self.query_only = query_only;
+
+        Ok(())
+    }
+
+    /// Returns whether `PRAGMA query_only` was enabled with
+    /// [`set_query_only()`][Self::set_query_only].
+    ///
+    /// This does not reflect changes made by executing the pragma directly.
+    pub fn query_only(&self) -> bool {
+        self.query_only
+    }
+
+    /// Execute a script of `;`-separated statements, returning one [`SqliteQueryResult`]
+    /// per statement.
+    ///
+    /// Unlike [`execute_many`][crate::executor::Executor::execute_many], which streams
+    /// each statement's result as it completes, this runs the whole script on the worker
+    /// thread in one command and collects the results. Rows produced by intermediate
+    /// statements are stepped over and discarded.
+    ///
+    /// Execution stops at the first failing statement; its index in the script is the
+    /// number of results collected before it, and is also logged with the error.
+    pub async fn execute_script(&mut self, script: &str) -> Result<Vec<SqliteQueryResult>, Error> {
+        self.worker.execute_script(script).await
+    }
+
+    /// Register a custom [FTS5 tokenizer](https://www.sqlite.org/fts5.html#custom_tokenizers)
+    /// under `name` on this connection.
+    ///
+    /// `factory` is invoked with the arguments from the `tokenize = '<name> <args>'`
+    /// option whenever an FTS5 table using the tokenizer is created or opened, and
+    /// returns the [`SqliteFts5Tokenizer`] instance used to split that table's
+    /// documents and queries into tokens.
+    ///
+    /// Requires FTS5 to be available in the linked SQLite (it is enabled in the
+    /// bundled build). Registering a tokenizer replaces any previous one of the
+    /// same name for tables opened afterwards.
+    pub async fn register_fts5_tokenizer<T, F>(
+        &mut self,
+        name: &str,
+        factory: F,
+    ) -> Result<(), Error>
+    where
+        T: SqliteFts5Tokenizer,
+        F: Fn(&[&str]) -> Result<T, Error> + Send + Sync + 'static,
+    {
+        self.worker
+            .register_fts5_tokenizer(
+                name,
+                Box::new(move |args| Ok(Box::new(factory(args)?))),
+            )
+            .await
+    }
+
+    /// Read the page and freelist statistics of the database.
+    ///
+    /// `schema` may name an attached database; it defaults to `main`. The underlying
+    /// pragmas (`page_count`, `page_size`, `freelist_count`) are all read on the worker
+    /// thread in one go, so the returned figures are consistent with each other.
+    pub async fn database_stats(
+        &mut self,
+        schema: Option<&str>,
+    ) -> Result<SqliteDatabaseStats, Error> {
+        self.worker.database_stats(schema).await
+    }
+
     /// Lock the SQLite database handle out from the worker thread so direct SQLite API calls can
     /// be made safely.
     ///
@@ -176,12 +356,17 @@ impl Connection for SqliteConnection {
 
     fn clear_cached_statements(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         Box::pin(async move {
+            self.describe_cache.clear();
             self.worker.clear_cache().await?;
             Ok(())
         })
     }
 
     #[doc(hidden)]
+    fn metrics(&self) -> ConnectionMetrics {
+        self.metrics.clone()
+    }
+
     fn flush(&mut self) -> BoxFuture<'_, Result<(), Error>> {
         // For SQLite, FLUSH does effectively nothing...
         // Well, we could use this to ensure that the command channel has been cleared,
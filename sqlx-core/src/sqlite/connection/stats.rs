@@ -0,0 +1,77 @@
+use either::Either;
+
+use crate::error::Error;
+use crate::row::Row;
+use crate::sqlite::connection::{execute, ConnectionState};
+
+/// Page and freelist statistics for an open SQLite database.
+///
+/// Returned by
+/// [`SqliteConnection::database_stats()`][crate::sqlite::SqliteConnection::database_stats].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SqliteDatabaseStats {
+    page_count: u64,
+    page_size: u64,
+    freelist_count: u64,
+}
+
+impl SqliteDatabaseStats {
+    /// The total number of pages in the database file (`PRAGMA page_count`).
+    pub fn page_count(&self) -> u64 {
+        self.page_count
+    }
+
+    /// The page size of the database in bytes (`PRAGMA page_size`).
+    pub fn page_size(&self) -> u64 {
+        self.page_size
+    }
+
+    /// The number of unused pages on the freelist (`PRAGMA freelist_count`).
+    ///
+    /// A large freelist indicates fragmentation; `VACUUM` rebuilds the database
+    /// file and returns the space to the filesystem.
+    pub fn freelist_count(&self) -> u64 {
+        self.freelist_count
+    }
+
+    /// The total size of the database file in bytes.
+    pub fn total_bytes(&self) -> u64 {
+        self.page_count * self.page_size
+    }
+
+    /// The number of bytes held by unused freelist pages.
+    pub fn free_bytes(&self) -> u64 {
+        self.freelist_count * self.page_size
+    }
+}
+
+/// Read the page statistics for `schema` (or `main` if unspecified) on the worker
+/// thread, so that all three pragmas observe the same state of the database.
+pub(crate) fn database_stats(
+    conn: &mut ConnectionState,
+    schema: Option<&str>,
+) -> Result<SqliteDatabaseStats, Error> {
+    // quoted so that unusual attached database names pass through intact
+    let schema = quote_identifier(schema.unwrap_or("main"));
+
+    Ok(SqliteDatabaseStats {
+        page_count: read_pragma(conn, &format!("PRAGMA {}.page_count", schema))?,
+        page_size: read_pragma(conn, &format!("PRAGMA {}.page_size", schema))?,
+        freelist_count: read_pragma(conn, &format!("PRAGMA {}.freelist_count", schema))?,
+    })
+}
+
+fn read_pragma(conn: &mut ConnectionState, sql: &str) -> Result<u64, Error> {
+    for res in execute::iter(conn, sql, None, false)? {
+        if let Either::Right(row) = res? {
+            return row.try_get::<i64, _>(0).map(|value| value as u64);
+        }
+    }
+
+    // an unknown schema name produces no rows rather than an error
+    Err(err_protocol!("{} returned no rows", sql))
+}
+
+fn quote_identifier(ident: &str) -> String {
+    format!("\"{}\"", ident.replace('"', "\"\""))
+}
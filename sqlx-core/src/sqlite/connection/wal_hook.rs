@@ -0,0 +1,88 @@
+use std::ffi::CStr;
+use std::os::raw::{c_char, c_int, c_void};
+use std::ptr;
+
+use libsqlite3_sys::{
+    sqlite3, sqlite3_wal_checkpoint_v2, sqlite3_wal_hook, SQLITE_CHECKPOINT_PASSIVE, SQLITE_OK,
+};
+
+use crate::sqlite::connection::ConnectionState;
+
+/// The action to take after a transaction is committed in WAL mode.
+///
+/// Returned from the callback registered by
+/// [`SqliteConnection::set_wal_hook()`][crate::sqlite::SqliteConnection::set_wal_hook].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqliteWalHookResult {
+    /// Leave the write-ahead log as it is.
+    Continue,
+
+    /// Run a passive checkpoint (`SQLITE_CHECKPOINT_PASSIVE`) to transfer the contents
+    /// of the write-ahead log back into the database file.
+    Checkpoint,
+}
+
+pub(crate) type WalHookFn = Box<dyn FnMut(&str, i32) -> SqliteWalHookResult + Send + 'static>;
+
+/// Register `hook` with `sqlite3_wal_hook()`, or clear any registered hook if `None`.
+///
+/// The closure is stored in the [`ConnectionState`] so that its context pointer stays
+/// valid for as long as it is registered; the previous hook (if any) is only dropped
+/// after it has been replaced.
+pub(crate) fn set_wal_hook(conn: &mut ConnectionState, hook: Option<WalHookFn>) {
+    let previous = conn.wal_hook.take();
+
+    match hook {
+        Some(hook) => {
+            let mut hook = Box::new(hook);
+
+            // SAFETY: we have exclusive access to the database handle, and the boxed
+            // closure outlives the registration; it is replaced or cleared through
+            // this function only.
+            unsafe {
+                sqlite3_wal_hook(
+                    conn.handle.as_ptr(),
+                    Some(wal_hook),
+                    &mut *hook as *mut WalHookFn as *mut c_void,
+                );
+            }
+
+            conn.wal_hook = Some(hook);
+        }
+
+        // SAFETY: as above; a null callback unregisters the hook.
+        None => unsafe {
+            sqlite3_wal_hook(conn.handle.as_ptr(), None, ptr::null_mut());
+        },
+    }
+
+    drop(previous);
+}
+
+unsafe extern "C" fn wal_hook(
+    ctx: *mut c_void,
+    db: *mut sqlite3,
+    db_name: *const c_char,
+    pages: c_int,
+) -> c_int {
+    let hook: *mut WalHookFn = ctx as *mut WalHookFn;
+    debug_assert!(!hook.is_null());
+
+    // database names are valid UTF-8 in any practical usage
+    let name = CStr::from_ptr(db_name).to_str().unwrap_or_default();
+
+    if let SqliteWalHookResult::Checkpoint = (*hook)(name, pages) {
+        // mirrors `sqlite3_wal_autocheckpoint()`, whose built-in hook likewise runs a
+        // passive checkpoint from inside the callback; the return value is ignored as
+        // a busy checkpoint will simply be retried after a later commit
+        sqlite3_wal_checkpoint_v2(
+            db,
+            db_name,
+            SQLITE_CHECKPOINT_PASSIVE,
+            ptr::null_mut(),
+            ptr::null_mut(),
+        );
+    }
+
+    SQLITE_OK
+}
@@ -12,10 +12,17 @@ use crate::describe::Describe;
 use crate::error::Error;
 use crate::sqlite::connection::collation::create_collation;
 use crate::sqlite::connection::describe::describe;
+use crate::sqlite::connection::authorizer;
+use crate::sqlite::connection::fts5;
 use crate::sqlite::connection::establish::EstablishParams;
+use crate::sqlite::connection::stats::{self, SqliteDatabaseStats};
+use crate::sqlite::connection::wal_hook;
 use crate::sqlite::connection::ConnectionState;
 use crate::sqlite::connection::{execute, ConnectionHandleRaw};
-use crate::sqlite::{Sqlite, SqliteArguments, SqliteQueryResult, SqliteRow, SqliteStatement};
+use crate::sqlite::{
+    Sqlite, SqliteArguments, SqliteError, SqliteQueryResult, SqliteRow, SqliteStatement,
+};
+use libsqlite3_sys::SQLITE_BUSY;
 use crate::transaction::{
     begin_ansi_transaction_sql, commit_ansi_transaction_sql, rollback_ansi_transaction_sql,
 };
@@ -67,6 +74,25 @@ enum Command {
         create_collation:
             Box<dyn FnOnce(&mut ConnectionState) -> Result<(), Error> + Send + Sync + 'static>,
     },
+    SetWalHook {
+        hook: Option<wal_hook::WalHookFn>,
+    },
+    SetAuthorizer {
+        authorizer: Option<authorizer::AuthorizerFn>,
+    },
+    DatabaseStats {
+        schema: Option<Box<str>>,
+        tx: oneshot::Sender<Result<SqliteDatabaseStats, Error>>,
+    },
+    ExecuteScript {
+        script: Box<str>,
+        tx: oneshot::Sender<Result<Vec<SqliteQueryResult>, Error>>,
+    },
+    RegisterFts5Tokenizer {
+        name: Box<str>,
+        factory: fts5::TokenizerFactoryFn,
+        tx: oneshot::Sender<Result<(), Error>>,
+    },
     UnlockDb,
     ClearCache {
         tx: oneshot::Sender<()>,
@@ -83,8 +109,13 @@ impl ConnectionWorker {
     pub(crate) async fn establish(params: EstablishParams) -> Result<Self, Error> {
         let (establish_tx, establish_rx) = oneshot::channel();
 
-        thread::Builder::new()
-            .name(params.thread_name.clone())
+        let mut builder = thread::Builder::new().name(params.thread_name.clone());
+
+        if let Some(stack_size) = params.worker_stack_size {
+            builder = builder.stack_size(stack_size);
+        }
+
+        builder
             .spawn(move || {
                 let (command_tx, command_rx) = flume::bounded(params.command_channel_size);
 
@@ -155,15 +186,7 @@ impl ConnectionWorker {
                             update_cached_statements_size(&conn, &shared.cached_statements_size);
                         }
                         Command::Begin { tx } => {
-                            let depth = conn.transaction_depth;
-                            let res =
-                                conn.handle
-                                    .exec(begin_ansi_transaction_sql(depth))
-                                    .map(|_| {
-                                        conn.transaction_depth += 1;
-                                    });
-
-                            tx.send(res).ok();
+                            tx.send(begin(&mut conn)).ok();
                         }
                         Command::Commit { tx } => {
                             let depth = conn.transaction_depth;
@@ -202,6 +225,29 @@ impl ConnectionWorker {
                                 log::warn!("error applying collation in background worker: {}", e);
                             }
                         }
+                        Command::SetWalHook { hook } => {
+                            wal_hook::set_wal_hook(&mut conn, hook);
+                        }
+                        Command::SetAuthorizer { authorizer } => {
+                            if let Err(e) = authorizer::set_authorizer(&mut conn, authorizer) {
+                                log::warn!(
+                                    "error applying authorizer in background worker: {}",
+                                    e
+                                );
+                            }
+                        }
+                        Command::DatabaseStats { schema, tx } => {
+                            tx.send(stats::database_stats(&mut conn, schema.as_deref()))
+                                .ok();
+                        }
+                        Command::ExecuteScript { script, tx } => {
+                            tx.send(execute_script(&mut conn, &script)).ok();
+                            update_cached_statements_size(&conn, &shared.cached_statements_size);
+                        }
+                        Command::RegisterFts5Tokenizer { name, factory, tx } => {
+                            tx.send(fts5::register_tokenizer(&mut conn.handle, &name, factory))
+                                .ok();
+                        }
                         Command::ClearCache { tx } => {
                             conn.statements.clear();
                             update_cached_statements_size(&conn, &shared.cached_statements_size);
@@ -321,6 +367,55 @@ impl ConnectionWorker {
         Ok(())
     }
 
+    pub(crate) fn set_wal_hook(&mut self, hook: Option<wal_hook::WalHookFn>) -> Result<(), Error> {
+        self.command_tx
+            .send(Command::SetWalHook { hook })
+            .map_err(|_| Error::WorkerCrashed)
+    }
+
+    pub(crate) fn set_authorizer(
+        &mut self,
+        authorizer: Option<authorizer::AuthorizerFn>,
+    ) -> Result<(), Error> {
+        self.command_tx
+            .send(Command::SetAuthorizer { authorizer })
+            .map_err(|_| Error::WorkerCrashed)
+    }
+
+    pub(crate) async fn database_stats(
+        &mut self,
+        schema: Option<&str>,
+    ) -> Result<SqliteDatabaseStats, Error> {
+        let schema = schema.map(Box::from);
+
+        self.oneshot_cmd(|tx| Command::DatabaseStats { schema, tx })
+            .await?
+    }
+
+    pub(crate) async fn execute_script(
+        &mut self,
+        script: &str,
+    ) -> Result<Vec<SqliteQueryResult>, Error> {
+        self.oneshot_cmd(|tx| Command::ExecuteScript {
+            script: script.into(),
+            tx,
+        })
+        .await?
+    }
+
+    pub(crate) async fn register_fts5_tokenizer(
+        &mut self,
+        name: &str,
+        factory: fts5::TokenizerFactoryFn,
+    ) -> Result<(), Error> {
+        self.oneshot_cmd(|tx| Command::RegisterFts5Tokenizer {
+            name: name.into(),
+            factory,
+            tx,
+        })
+        .await?
+    }
+
     pub(crate) async fn clear_cache(&mut self) -> Result<(), Error> {
         self.oneshot_cmd(|tx| Command::ClearCache { tx }).await
     }
@@ -384,6 +479,75 @@ fn prepare(conn: &mut ConnectionState, query: &str) -> Result<SqliteStatement<'s
     })
 }
 
+fn execute_script(conn: &mut ConnectionState, script: &str) -> Result<Vec<SqliteQueryResult>, Error> {
+    let mut results = Vec::new();
+
+    for res in execute::iter(conn, script, None, false)? {
+        match res {
+            // one `Left` is produced per statement once it has run to completion
+            Ok(Either::Left(done)) => results.push(done),
+            // rows produced by intermediate statements are discarded
+            Ok(Either::Right(_)) => (),
+            Err(error) => {
+                log::error!(
+                    "error executing statement {} of script: {}",
+                    results.len(),
+                    error
+                );
+
+                return Err(error);
+            }
+        }
+    }
+
+    Ok(results)
+}
+
+fn begin(conn: &mut ConnectionState) -> Result<(), Error> {
+    let depth = conn.transaction_depth;
+
+    match (depth, conn.begin_immediate_retry) {
+        // only a top-level transaction takes a lock; savepoints are never busy
+        (0, Some((max_retries, backoff))) => {
+            let mut retries = 0;
+            let mut backoff = backoff;
+
+            loop {
+                match conn.handle.exec("BEGIN IMMEDIATE") {
+                    Ok(()) => break,
+
+                    Err(error) if retries < max_retries && is_busy(&error) => {
+                        retries += 1;
+
+                        // the worker thread only serves this connection, which cannot
+                        // make progress until the transaction has begun
+                        thread::sleep(backoff);
+                        backoff *= 2;
+                    }
+
+                    Err(error) => return Err(error),
+                }
+            }
+        }
+
+        _ => conn.handle.exec(begin_ansi_transaction_sql(depth))?,
+    }
+
+    conn.transaction_depth += 1;
+
+    Ok(())
+}
+
+fn is_busy(error: &Error) -> bool {
+    if let Error::Database(error) = error {
+        if let Some(error) = error.try_downcast_ref::<SqliteError>() {
+            return error.primary_code() == SQLITE_BUSY;
+        }
+    }
+
+    false
+}
+
 fn update_cached_statements_size(conn: &ConnectionState, size: &AtomicUsize) {
     size.store(conn.statements.len(), Ordering::Release);
 }
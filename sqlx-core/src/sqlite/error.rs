@@ -4,7 +4,11 @@ use std::fmt::{self, Display, Formatter};
 use std::os::raw::c_int;
 use std::{borrow::Cow, str::from_utf8_unchecked};
 
-use libsqlite3_sys::{sqlite3, sqlite3_errmsg, sqlite3_extended_errcode};
+use libsqlite3_sys::{
+    sqlite3, sqlite3_errmsg, sqlite3_extended_errcode, SQLITE_CONSTRAINT_CHECK,
+    SQLITE_CONSTRAINT_FOREIGNKEY, SQLITE_CONSTRAINT_NOTNULL, SQLITE_CONSTRAINT_PRIMARYKEY,
+    SQLITE_CONSTRAINT_UNIQUE,
+};
 
 use crate::error::DatabaseError;
 
@@ -35,6 +39,20 @@ impl SqliteError {
             message: message.to_owned(),
         }
     }
+
+    /// The [extended result code](https://www.sqlite.org/rescode.html#extrc) for this error,
+    /// e.g. `SQLITE_CONSTRAINT_UNIQUE` (2067).
+    pub fn extended_code(&self) -> c_int {
+        self.code
+    }
+
+    /// The [primary result code](https://www.sqlite.org/rescode.html#primary_result_code_list)
+    /// for this error, e.g. `SQLITE_CONSTRAINT` (19).
+    ///
+    /// The primary code is carried in the least significant byte of the extended code.
+    pub fn primary_code(&self) -> c_int {
+        self.code & 0xFF
+    }
 }
 
 impl Display for SqliteError {
@@ -71,4 +89,23 @@ impl DatabaseError for SqliteError {
     fn into_error(self: Box<Self>) -> Box<dyn StdError + Send + Sync + 'static> {
         self
     }
+
+    fn is_unique_violation(&self) -> bool {
+        matches!(
+            self.code,
+            SQLITE_CONSTRAINT_UNIQUE | SQLITE_CONSTRAINT_PRIMARYKEY
+        )
+    }
+
+    fn is_foreign_key_violation(&self) -> bool {
+        self.code == SQLITE_CONSTRAINT_FOREIGNKEY
+    }
+
+    fn is_check_violation(&self) -> bool {
+        self.code == SQLITE_CONSTRAINT_CHECK
+    }
+
+    fn is_not_null_violation(&self) -> bool {
+        self.code == SQLITE_CONSTRAINT_NOTNULL
+    }
 }
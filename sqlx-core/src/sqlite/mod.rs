@@ -7,11 +7,16 @@
 
 pub use arguments::{SqliteArgumentValue, SqliteArguments};
 pub use column::SqliteColumn;
-pub use connection::{LockedSqliteHandle, SqliteConnection};
+pub use connection::{
+    LockedSqliteHandle, SqliteAuthAction, SqliteAuthActionCode, SqliteAuthorization,
+    SqliteConnection, SqliteDatabaseStats, SqliteFts5Tokenizer, SqliteFts5Tokens,
+    SqliteWalHookResult,
+};
 pub use database::Sqlite;
 pub use error::SqliteError;
 pub use options::{
     SqliteAutoVacuum, SqliteConnectOptions, SqliteJournalMode, SqliteLockingMode, SqliteSynchronous,
+    SqliteThreadMode,
 };
 pub use query_result::SqliteQueryResult;
 pub use row::SqliteRow;
@@ -19,6 +24,7 @@ pub use statement::SqliteStatement;
 pub use transaction::SqliteTransactionManager;
 pub use type_info::SqliteTypeInfo;
 pub use value::{SqliteValue, SqliteValueRef};
+pub use vfs::{register_vfs, SqliteVfs, SqliteVfsFile};
 
 use crate::executor::Executor;
 
@@ -35,6 +41,7 @@ mod transaction;
 mod type_info;
 pub mod types;
 mod value;
+mod vfs;
 
 #[cfg(feature = "migrate")]
 mod migrate;
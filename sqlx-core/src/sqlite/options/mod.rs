@@ -4,6 +4,7 @@ mod auto_vacuum;
 mod connect;
 mod journal_mode;
 mod locking_mode;
+mod thread_mode;
 mod parse;
 mod synchronous;
 
@@ -11,6 +12,7 @@ use crate::connection::LogSettings;
 pub use auto_vacuum::SqliteAutoVacuum;
 pub use journal_mode::SqliteJournalMode;
 pub use locking_mode::SqliteLockingMode;
+pub use thread_mode::SqliteThreadMode;
 use std::cmp::Ordering;
 use std::sync::Arc;
 use std::{borrow::Cow, time::Duration};
@@ -61,6 +63,7 @@ pub struct SqliteConnectOptions {
     pub(crate) create_if_missing: bool,
     pub(crate) shared_cache: bool,
     pub(crate) statement_cache_capacity: usize,
+    pub(crate) describe_cache_capacity: usize,
     pub(crate) busy_timeout: Duration,
     pub(crate) log_settings: LogSettings,
     pub(crate) immutable: bool,
@@ -73,6 +76,11 @@ pub struct SqliteConnectOptions {
 
     pub(crate) serialized: bool,
     pub(crate) thread_name: Arc<DebugFn<dyn Fn(u64) -> String + Send + Sync + 'static>>,
+    pub(crate) worker_stack_size: Option<usize>,
+
+    pub(crate) vfs: Option<Cow<'static, str>>,
+
+    pub(crate) begin_immediate_retry: Option<(u32, Duration)>,
 }
 
 impl Default for SqliteConnectOptions {
@@ -120,6 +128,7 @@ impl SqliteConnectOptions {
             create_if_missing: false,
             shared_cache: false,
             statement_cache_capacity: 100,
+            describe_cache_capacity: 0,
             busy_timeout: Duration::from_secs(5),
             log_settings: Default::default(),
             immutable: false,
@@ -127,8 +136,11 @@ impl SqliteConnectOptions {
             collations: Default::default(),
             serialized: false,
             thread_name: Arc::new(DebugFn(|id| format!("sqlx-sqlite-worker-{}", id))),
+            worker_stack_size: None,
             command_channel_size: 50,
             row_channel_size: 50,
+            vfs: None,
+            begin_immediate_retry: None,
         }
     }
 
@@ -203,6 +215,21 @@ impl SqliteConnectOptions {
         self
     }
 
+    /// Sets the capacity of the connection's describe cache in a number of
+    /// distinct SQL strings. When enabled, [`Executor::describe`] results are
+    /// cached per connection and reused instead of re-preparing the statement.
+    ///
+    /// The cache is cleared by [`Connection::clear_cached_statements`].
+    ///
+    /// The default capacity is 0 (caching disabled).
+    ///
+    /// [`Executor::describe`]: crate::executor::Executor::describe
+    /// [`Connection::clear_cached_statements`]: crate::connection::Connection::clear_cached_statements
+    pub fn describe_cache_capacity(mut self, capacity: usize) -> Self {
+        self.describe_cache_capacity = capacity;
+        self
+    }
+
     /// Sets a timeout value to wait when the database is locked, before
     /// returning a busy timeout error.
     ///
@@ -293,6 +320,34 @@ impl SqliteConnectOptions {
         self
     }
 
+    /// Sets the name of the VFS the database is opened with.
+    ///
+    /// This may name one of the [built-in VFSes](https://www.sqlite.org/vfs.html) or a
+    /// custom one previously registered with
+    /// [`register_vfs()`][crate::sqlite::register_vfs]. If unset, the default VFS for
+    /// the platform is used.
+    pub fn vfs(mut self, vfs: impl Into<Cow<'static, str>>) -> Self {
+        self.vfs = Some(vfs.into());
+        self
+    }
+
+    /// Begin transactions with `BEGIN IMMEDIATE` and retry on `SQLITE_BUSY`.
+    ///
+    /// The default deferred `BEGIN` takes no lock until the first write statement,
+    /// which under concurrent writers frequently fails mid-transaction with
+    /// `SQLITE_BUSY` when it tries to upgrade to a write lock. With this option the
+    /// write lock is taken when the transaction begins, and a busy error at that
+    /// point is retried up to `max_retries` times, sleeping `backoff` (doubled after
+    /// each attempt) in between. Errors other than `SQLITE_BUSY` are never retried.
+    ///
+    /// The retries are in addition to SQLite's own [`busy_timeout`][Self::busy_timeout]
+    /// handling, which blocks inside the begin itself. Only top-level transactions are
+    /// affected; savepoints never take locks.
+    pub fn begin_immediate_retry(mut self, max_retries: u32, backoff: Duration) -> Self {
+        self.begin_immediate_retry = Some((max_retries, backoff));
+        self
+    }
+
     /// Sets the [threading mode](https://www.sqlite.org/threadsafe.html) for the database connection.
     ///
     /// The default setting is `false` corersponding to using `OPEN_NOMUTEX`, if `true` then `OPEN_FULLMUTEX`.
@@ -313,6 +368,18 @@ impl SqliteConnectOptions {
         self
     }
 
+    /// Sets the [threading mode](https://www.sqlite.org/threadsafe.html) for the database
+    /// connection by name instead of through [`.serialized()`][Self::serialized].
+    ///
+    /// The default is [`SqliteThreadMode::MultiThread`] (`SQLITE_OPEN_NOMUTEX`), which is safe
+    /// under sqlx's worker design and avoids the per-call mutex that
+    /// [`SqliteThreadMode::Serialized`] (`SQLITE_OPEN_FULLMUTEX`) takes. See the notes on
+    /// [`.serialized()`][Self::serialized] before opting into `Serialized`.
+    pub fn thread_mode(mut self, mode: SqliteThreadMode) -> Self {
+        self.serialized = matches!(mode, SqliteThreadMode::Serialized);
+        self
+    }
+
     /// Provide a callback to generate the name of the background worker thread.
     ///
     /// The value passed to the callback is an auto-incremented integer for use as the thread ID.
@@ -324,6 +391,27 @@ impl SqliteConnectOptions {
         self
     }
 
+    /// Set a fixed name for the background worker thread.
+    ///
+    /// This is a convenience over [`.thread_name()`][Self::thread_name] for naming a single
+    /// well-known connection; if several connections share the name, their worker threads
+    /// become indistinguishable in thread dumps.
+    pub fn worker_thread_name(self, name: impl Into<String>) -> Self {
+        let name = name.into();
+        self.thread_name(move |_| name.clone())
+    }
+
+    /// Set the stack size of the background worker thread, in bytes.
+    ///
+    /// The platform default is used when unset. All SQLite calls for this connection run on
+    /// the worker thread, so deeply recursive triggers or deeply nested expressions that
+    /// would overflow the default stack can be accommodated here without raising the stack
+    /// size of every thread in the process.
+    pub fn worker_stack_size(mut self, size: usize) -> Self {
+        self.worker_stack_size = Some(size);
+        self
+    }
+
     /// Set the maximum number of commands to buffer for the worker thread before backpressure is
     /// applied.
     ///
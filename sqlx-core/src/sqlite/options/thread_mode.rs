@@ -0,0 +1,26 @@
+/// Refer to [SQLite documentation] for the meaning of the connection threading modes.
+///
+/// `SingleThread` is not listed here: it can only be selected when SQLite is compiled
+/// or globally configured, not per-connection via the open flags.
+///
+/// Both of these modes are compatible with sqlx's worker design, which only ever uses a
+/// connection from its single background thread; `MultiThread` merely skips the
+/// per-connection mutex that `Serialized` takes around every call.
+///
+/// [SQLite documentation]: https://www.sqlite.org/threadsafe.html
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SqliteThreadMode {
+    /// Open the connection with `SQLITE_OPEN_NOMUTEX`: the connection may only be used
+    /// from one thread at a time, which sqlx's worker guarantees.
+    MultiThread,
+
+    /// Open the connection with `SQLITE_OPEN_FULLMUTEX`: SQLite itself serializes
+    /// concurrent calls with a mutex.
+    Serialized,
+}
+
+impl Default for SqliteThreadMode {
+    fn default() -> Self {
+        SqliteThreadMode::MultiThread
+    }
+}
@@ -7,6 +7,12 @@ pub struct SqliteQueryResult {
 }
 
 impl SqliteQueryResult {
+    /// The number of rows inserted, updated or deleted by the statement.
+    ///
+    /// For a statement with a `RETURNING` clause, the returned rows are streamed
+    /// separately as result rows and do not influence this count; the change counter
+    /// is only read once the statement has run to completion, so it reflects the
+    /// full DML portion (e.g. `INSERT ... RETURNING id` of three rows reports `3`).
     pub fn rows_affected(&self) -> u64 {
         self.changes
     }
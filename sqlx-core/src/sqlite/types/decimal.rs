@@ -0,0 +1,46 @@
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::sqlite::type_info::DataType;
+use crate::sqlite::{Sqlite, SqliteArgumentValue, SqliteTypeInfo, SqliteValueRef};
+use crate::types::Type;
+use crate::value::ValueRef;
+use std::borrow::Cow;
+
+impl Type<Sqlite> for Decimal {
+    fn type_info() -> SqliteTypeInfo {
+        SqliteTypeInfo(DataType::Text)
+    }
+
+    fn compatible(ty: &SqliteTypeInfo) -> bool {
+        matches!(
+            ty.0,
+            DataType::Text | DataType::Numeric | DataType::Float | DataType::Int | DataType::Int64
+        )
+    }
+}
+
+impl<'q> Encode<'q, Sqlite> for Decimal {
+    fn encode_by_ref(&self, args: &mut Vec<SqliteArgumentValue<'q>>) -> IsNull {
+        args.push(SqliteArgumentValue::Text(Cow::Owned(self.to_string())));
+
+        IsNull::No
+    }
+}
+
+impl Decode<'_, Sqlite> for Decimal {
+    fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
+        match value.type_info().0 {
+            DataType::Int | DataType::Int64 => Ok(Decimal::from(value.int64())),
+
+            // REAL storage is lossy; this parse is best-effort
+            DataType::Float => Decimal::from_f64(value.double())
+                .ok_or_else(|| format!("REAL value {} out of range for DECIMAL", value.double()).into()),
+
+            _ => Ok(value.text()?.parse::<Decimal>()?),
+        }
+    }
+}
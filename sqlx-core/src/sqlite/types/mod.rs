@@ -28,6 +28,14 @@
 //! | `chrono::DateTime<Utc>`               | DATETIME                                             |
 //! | `chrono::DateTime<Local>`             | DATETIME                                             |
 //!
+//! ### [`rust_decimal`](https://crates.io/crates/rust_decimal)
+//!
+//! Requires the `decimal` Cargo feature flag.
+//!
+//! | Rust type                             | Sqlite type(s)                                       |
+//! |---------------------------------------|------------------------------------------------------|
+//! | `rust_decimal::Decimal`               | TEXT, REAL (lossy), INTEGER                          |
+//!
 //! ### [`uuid`](https://crates.io/crates/uuid)
 //!
 //! Requires the `uuid` Cargo feature flag.
@@ -47,8 +55,11 @@ mod bool;
 mod bytes;
 #[cfg(feature = "chrono")]
 mod chrono;
+#[cfg(feature = "decimal")]
+mod decimal;
 mod float;
 mod int;
+mod ipaddr;
 #[cfg(feature = "json")]
 mod json;
 mod str;
@@ -29,8 +29,15 @@ impl<'q> Encode<'q, Sqlite> for Uuid {
 
 impl Decode<'_, Sqlite> for Uuid {
     fn decode(value: SqliteValueRef<'_>) -> Result<Self, BoxDynError> {
-        // construct a Uuid from the returned bytes
-        Uuid::from_slice(value.blob()).map_err(Into::into)
+        // a UUID may be stored either as a compact 16-byte BLOB or as hyphenated TEXT;
+        // detect which representation we were handed and accept both
+        let bytes = value.blob();
+
+        if bytes.len() == 16 {
+            Uuid::from_slice(bytes).map_err(Into::into)
+        } else {
+            Uuid::parse_str(value.text()?).map_err(Into::into)
+        }
     }
 }
 
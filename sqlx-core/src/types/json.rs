@@ -129,3 +129,29 @@ where
         <Json<Self> as Decode<DB>>::decode(value).map(|item| item.0)
     }
 }
+
+impl<DB> Type<DB> for Box<JsonRawValue>
+where
+    JsonRawValue: Type<DB>,
+    DB: Database,
+{
+    fn type_info() -> DB::TypeInfo {
+        <JsonRawValue as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <JsonRawValue as Type<DB>>::compatible(ty)
+    }
+}
+
+// unlike `&JsonRawValue` this owns its text and so can outlive the row,
+// but it still doesn't parse the JSON into a DOM
+impl<'r, DB> Decode<'r, DB> for Box<JsonRawValue>
+where
+    Json<Self>: Decode<'r, DB>,
+    DB: Database,
+{
+    fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+        <Json<Self> as Decode<DB>>::decode(value).map(|item| item.0)
+    }
+}
@@ -32,6 +32,9 @@ pub mod git2;
 #[cfg_attr(docsrs, doc(cfg(feature = "json")))]
 mod json;
 
+mod path;
+mod str;
+
 #[cfg(feature = "uuid")]
 #[cfg_attr(docsrs, doc(cfg(feature = "uuid")))]
 #[doc(no_inline)]
@@ -82,6 +85,13 @@ pub mod mac_address {
     pub use mac_address::MacAddress;
 }
 
+#[cfg(feature = "geo-types")]
+#[cfg_attr(docsrs, doc(cfg(feature = "geo-types")))]
+pub mod geo_types {
+    #[doc(no_inline)]
+    pub use geo_types::{LineString, Point, Polygon};
+}
+
 #[cfg(feature = "json")]
 pub use json::Json;
 
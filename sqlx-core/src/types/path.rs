@@ -0,0 +1,69 @@
+use std::path::{Path, PathBuf};
+
+use crate::database::{Database, HasArguments, HasValueRef};
+use crate::decode::Decode;
+use crate::encode::{Encode, IsNull};
+use crate::error::BoxDynError;
+use crate::types::Type;
+
+// filesystem paths bind to text columns; encoding uses the platform's lossy
+// UTF-8 conversion, so a path containing non-UTF-8 sequences will have them
+// replaced with U+FFFD and will not round-trip bit-for-bit
+
+impl<DB> Type<DB> for Path
+where
+    str: Type<DB>,
+    DB: Database,
+{
+    fn type_info() -> DB::TypeInfo {
+        <str as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <str as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<DB> Type<DB> for PathBuf
+where
+    str: Type<DB>,
+    DB: Database,
+{
+    fn type_info() -> DB::TypeInfo {
+        <str as Type<DB>>::type_info()
+    }
+
+    fn compatible(ty: &DB::TypeInfo) -> bool {
+        <str as Type<DB>>::compatible(ty)
+    }
+}
+
+impl<'q, DB> Encode<'q, DB> for &Path
+where
+    String: Encode<'q, DB>,
+    DB: Database,
+{
+    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+        self.to_string_lossy().into_owned().encode(buf)
+    }
+}
+
+impl<'q, DB> Encode<'q, DB> for PathBuf
+where
+    String: Encode<'q, DB>,
+    DB: Database,
+{
+    fn encode_by_ref(&self, buf: &mut <DB as HasArguments<'q>>::ArgumentBuffer) -> IsNull {
+        self.as_path().encode(buf)
+    }
+}
+
+impl<'r, DB> Decode<'r, DB> for PathBuf
+where
+    &'r str: Decode<'r, DB>,
+    DB: Database,
+{
+    fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+        Ok(PathBuf::from(<&str as Decode<DB>>::decode(value)?))
+    }
+}
@@ -0,0 +1,43 @@
+use std::rc::Rc;
+use std::sync::Arc;
+
+use crate::database::{Database, HasValueRef};
+use crate::decode::Decode;
+use crate::error::BoxDynError;
+use crate::types::Type;
+
+// shared-ownership (and boxed) strings decode from text columns by copying the
+// column bytes once, without an intermediate `String`; they are decode-only as
+// encoding can borrow the inner `str` instead
+
+macro_rules! impl_decode_for_smart_str {
+    ($ty:ty) => {
+        impl<DB> Type<DB> for $ty
+        where
+            String: Type<DB>,
+            DB: Database,
+        {
+            fn type_info() -> DB::TypeInfo {
+                <String as Type<DB>>::type_info()
+            }
+
+            fn compatible(ty: &DB::TypeInfo) -> bool {
+                <String as Type<DB>>::compatible(ty)
+            }
+        }
+
+        impl<'r, DB> Decode<'r, DB> for $ty
+        where
+            &'r str: Decode<'r, DB>,
+            DB: Database,
+        {
+            fn decode(value: <DB as HasValueRef<'r>>::ValueRef) -> Result<Self, BoxDynError> {
+                Ok(<&str as Decode<DB>>::decode(value)?.into())
+            }
+        }
+    };
+}
+
+impl_decode_for_smart_str!(Box<str>);
+impl_decode_for_smart_str!(Arc<str>);
+impl_decode_for_smart_str!(Rc<str>);
@@ -0,0 +1,99 @@
+use std::collections::BTreeMap;
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use once_cell::sync::Lazy;
+
+/// Macro configuration loaded from an optional `sqlx.toml` next to `Cargo.toml`:
+///
+/// ```toml
+/// [macros.type-overrides.columns]
+/// user_id = "crate::UserId"
+///
+/// [macros.type-overrides.sql-types]
+/// DATETIME = "crate::Timestamp"
+/// ```
+///
+/// The query macros consult these when choosing the Rust type for an output column;
+/// a per-query override (`as "name: Type"`) takes precedence, then the column-name
+/// mapping, then the SQL-type mapping, then the built-in mapping for the database.
+pub struct Config {
+    pub type_overrides: TypeOverrides,
+}
+
+#[derive(Default)]
+pub struct TypeOverrides {
+    /// column name -> fully-qualified Rust type
+    columns: BTreeMap<String, String>,
+    /// SQL type name, as reported by the database -> fully-qualified Rust type
+    sql_types: BTreeMap<String, String>,
+}
+
+impl TypeOverrides {
+    pub fn for_column(&self, name: &str, sql_type: &str) -> Option<&str> {
+        self.columns
+            .get(name)
+            .or_else(|| self.sql_types.get(sql_type))
+            .map(|ty| &**ty)
+    }
+}
+
+pub static CONFIG: Lazy<Config> = Lazy::new(|| {
+    let path = match env::var("CARGO_MANIFEST_DIR") {
+        Ok(dir) => Path::new(&dir).join("sqlx.toml"),
+        Err(_) => {
+            return Config {
+                type_overrides: TypeOverrides::default(),
+            }
+        }
+    };
+
+    let text = match fs::read_to_string(&path) {
+        Ok(text) => text,
+        // no config file; use the defaults
+        Err(_) => {
+            return Config {
+                type_overrides: TypeOverrides::default(),
+            }
+        }
+    };
+
+    // a present-but-invalid config should fail the build loudly, not be ignored
+    let value: toml::Value = text
+        .parse()
+        .unwrap_or_else(|e| panic!("failed to parse {}: {}", path.display(), e));
+
+    let overrides = value.get("macros").and_then(|v| v.get("type-overrides"));
+
+    Config {
+        type_overrides: TypeOverrides {
+            columns: string_table(&path, overrides.and_then(|v| v.get("columns"))),
+            sql_types: string_table(&path, overrides.and_then(|v| v.get("sql-types"))),
+        },
+    }
+});
+
+fn string_table(path: &Path, value: Option<&toml::Value>) -> BTreeMap<String, String> {
+    let table = match value {
+        Some(value) => value
+            .as_table()
+            .unwrap_or_else(|| panic!("expected a table, got {} in {}", value, path.display())),
+        None => return BTreeMap::new(),
+    };
+
+    table
+        .iter()
+        .map(|(key, value)| {
+            let value = value.as_str().unwrap_or_else(|| {
+                panic!(
+                    "expected a Rust type path (string) for {:?} in {}",
+                    key,
+                    path.display()
+                )
+            });
+
+            (key.clone(), value.to_owned())
+        })
+        .collect()
+}
@@ -129,6 +129,19 @@ pub fn parse_container_attributes(input: &[Attribute]) -> syn::Result<SqlxContai
                                 )
                             }
 
+                            // an alternative to `#[repr(..)]` for enums whose discriminant
+                            // should be stored without also changing the Rust layout
+                            Meta::NameValue(MetaNameValue {
+                                path,
+                                lit: Lit::Str(val),
+                                ..
+                            }) if path.is_ident("repr") => {
+                                let ident = syn::parse_str::<Ident>(&val.value())
+                                    .map_err(|_| syn::Error::new_spanned(val, "expected an integer type"))?;
+
+                                try_set!(repr, ident, value)
+                            }
+
                             Meta::NameValue(MetaNameValue {
                                 path,
                                 lit: Lit::Str(val),
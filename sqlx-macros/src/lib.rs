@@ -17,6 +17,7 @@ type Error = Box<dyn std::error::Error>;
 type Result<T> = std::result::Result<T, Error>;
 
 mod common;
+mod config;
 mod database;
 mod derives;
 mod query;
@@ -81,10 +82,25 @@ pub fn derive_from_row(input: TokenStream) -> TokenStream {
 #[cfg(feature = "migrate")]
 #[proc_macro]
 pub fn migrate(input: TokenStream) -> TokenStream {
-    use syn::LitStr;
+    let input = syn::parse_macro_input!(input as migrate::MigrateInput);
+    match migrate::expand_migrator(input) {
+        Ok(ts) => ts.into(),
+        Err(e) => {
+            if let Some(parse_err) = e.downcast_ref::<syn::Error>() {
+                parse_err.to_compile_error().into()
+            } else {
+                let msg = e.to_string();
+                quote!(::std::compile_error!(#msg)).into()
+            }
+        }
+    }
+}
 
-    let input = syn::parse_macro_input!(input as LitStr);
-    match migrate::expand_migrator_from_dir(input) {
+#[cfg(feature = "migrate")]
+#[proc_macro]
+pub fn migrate_check(input: TokenStream) -> TokenStream {
+    let input = syn::parse_macro_input!(input as migrate::MigrateInput);
+    match migrate::expand_migrate_check(input) {
         Ok(ts) => ts.into(),
         Err(e) => {
             if let Some(parse_err) = e.downcast_ref::<syn::Error>() {
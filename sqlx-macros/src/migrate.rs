@@ -2,8 +2,11 @@ use proc_macro2::TokenStream;
 use quote::{quote, ToTokens, TokenStreamExt};
 use sha2::{Digest, Sha384};
 use sqlx_core::migrate::MigrationType;
+use std::collections::HashMap;
 use std::fs;
-use syn::LitStr;
+use syn::parse::{Parse, ParseStream};
+use syn::punctuated::Punctuated;
+use syn::{LitStr, Token};
 
 pub struct QuotedMigrationType(MigrationType);
 
@@ -15,6 +18,7 @@ impl ToTokens for QuotedMigrationType {
             MigrationType::ReversibleDown => {
                 quote! { ::sqlx::migrate::MigrationType::ReversibleDown }
             }
+            MigrationType::Repeatable => quote! { ::sqlx::migrate::MigrationType::Repeatable },
         };
         tokens.append_all(ts.into_iter());
     }
@@ -26,6 +30,7 @@ struct QuotedMigration {
     migration_type: QuotedMigrationType,
     path: String,
     checksum: Vec<u8>,
+    no_tx: bool,
 }
 
 impl ToTokens for QuotedMigration {
@@ -36,6 +41,7 @@ impl ToTokens for QuotedMigration {
             migration_type,
             path,
             checksum,
+            no_tx,
         } = &self;
 
         let ts = quote! {
@@ -48,6 +54,7 @@ impl ToTokens for QuotedMigration {
                 checksum: ::std::borrow::Cow::Borrowed(&[
                     #(#checksum),*
                 ]),
+                no_tx: #no_tx,
             }
         };
 
@@ -55,8 +62,180 @@ impl ToTokens for QuotedMigration {
     }
 }
 
+/// The input of `migrate!`: either a single directory or a bracketed list of directories
+/// whose migrations are merged into one version-ordered set.
+pub struct MigrateInput {
+    dirs: Vec<LitStr>,
+}
+
+impl Parse for MigrateInput {
+    fn parse(input: ParseStream) -> syn::Result<Self> {
+        if input.peek(syn::token::Bracket) {
+            let content;
+            syn::bracketed!(content in input);
+
+            let dirs = Punctuated::<LitStr, Token![,]>::parse_terminated(&content)?;
+
+            Ok(Self {
+                dirs: dirs.into_iter().collect(),
+            })
+        } else {
+            Ok(Self {
+                dirs: vec![input.parse()?],
+            })
+        }
+    }
+}
+
+pub(crate) fn expand_migrator(input: MigrateInput) -> crate::Result<TokenStream> {
+    let mut migrations = Vec::new();
+
+    // (version, is down migration) -> directory it came from
+    let mut seen: HashMap<(i64, bool), String> = HashMap::new();
+
+    for dir in &input.dirs {
+        for migration in resolve_migrations_from_dir(dir)? {
+            let key = (
+                migration.version,
+                matches!(migration.migration_type.0, MigrationType::ReversibleDown),
+            );
+
+            if let Some(previous) = seen.insert(key, dir.value()) {
+                return Err(format!(
+                    "migration version {} is defined in both `{}` and `{}`",
+                    migration.version,
+                    previous,
+                    dir.value(),
+                )
+                .into());
+            }
+
+            migrations.push(migration);
+        }
+    }
+
+    // ensure that we are sorted by `VERSION ASC`
+    migrations.sort_by_key(|m| m.version);
+
+    Ok(quote! {
+        ::sqlx::migrate::Migrator {
+            migrations: ::std::borrow::Cow::Borrowed(&[
+                #(#migrations),*
+            ]),
+            ignore_missing: false,
+            table_name: ::std::borrow::Cow::Borrowed("_sqlx_migrations"),
+            lock_timeout: ::std::option::Option::None,
+            before_each: ::std::vec::Vec::new(),
+            after_each: ::std::vec::Vec::new(),
+        }
+    })
+}
+
+pub(crate) fn expand_migrate_check(input: MigrateInput) -> crate::Result<TokenStream> {
+    // (version, is down migration) -> directory it came from
+    let mut seen: HashMap<(i64, bool), String> = HashMap::new();
+    let mut newest_migration: Option<(std::path::PathBuf, std::time::SystemTime)> = None;
+
+    for dir in &input.dirs {
+        let path = crate::common::resolve_path(&dir.value(), dir.span())?;
+
+        for entry in fs::read_dir(&path)? {
+            let entry = entry?;
+            let metadata = fs::metadata(entry.path())?;
+            if !metadata.is_file() {
+                continue;
+            }
+
+            let file_name = entry.file_name();
+            let file_name = file_name.to_string_lossy();
+
+            // unlike `migrate!()`, which silently skips files it does not recognize,
+            // a file that fails to parse as a migration here fails the build
+            let version = if let Some(rest) = file_name.strip_prefix("R__") {
+                if !rest.ends_with(".sql") {
+                    return Err(format!(
+                        "file `{}` in migrations directory `{}` is not a valid repeatable \
+                         migration; expected the format `R__<DESCRIPTION>.sql`",
+                        file_name,
+                        dir.value(),
+                    )
+                    .into());
+                }
+
+                sqlx_core::migrate::repeatable_version(&rest.trim_end_matches(".sql").replace('_', " "))
+            } else {
+                let parts = file_name.splitn(2, '_').collect::<Vec<_>>();
+
+                if parts.len() != 2 || !parts[1].ends_with(".sql") {
+                    return Err(format!(
+                        "file `{}` in migrations directory `{}` is not a valid migration; \
+                         expected the format `<VERSION>_<DESCRIPTION>.sql`",
+                        file_name,
+                        dir.value(),
+                    )
+                    .into());
+                }
+
+                parts[0].parse().map_err(|_| {
+                    format!(
+                        "file `{}` in migrations directory `{}` does not have a valid version \
+                         number",
+                        file_name,
+                        dir.value(),
+                    )
+                })?
+            };
+
+            let is_down = matches!(
+                MigrationType::from_filename(&file_name),
+                MigrationType::ReversibleDown
+            );
+
+            if let Some(previous) = seen.insert((version, is_down), dir.value()) {
+                return Err(format!(
+                    "migration version {} is defined in both `{}` and `{}`",
+                    version,
+                    previous,
+                    dir.value(),
+                )
+                .into());
+            }
+
+            if let Ok(modified) = metadata.modified() {
+                if newest_migration
+                    .as_ref()
+                    .map_or(true, |(_, newest)| modified > *newest)
+                {
+                    newest_migration = Some((entry.path(), modified));
+                }
+            }
+        }
+    }
+
+    // if offline query data was prepared, migrations added since then indicate drift
+    if let Some((migration, modified)) = newest_migration {
+        let data_file = std::path::Path::new(
+            &std::env::var("CARGO_MANIFEST_DIR").map_err(|_| "`CARGO_MANIFEST_DIR` must be set")?,
+        )
+        .join("sqlx-data.json");
+
+        if let Ok(prepared) = fs::metadata(&data_file).and_then(|m| m.modified()) {
+            if modified > prepared {
+                return Err(format!(
+                    "migration `{}` is newer than `sqlx-data.json`; the offline query data may \
+                     be out of date, re-run `cargo sqlx prepare`",
+                    migration.display(),
+                )
+                .into());
+            }
+        }
+    }
+
+    Ok(quote! { () })
+}
+
 // mostly copied from sqlx-core/src/migrate/source.rs
-pub(crate) fn expand_migrator_from_dir(dir: LitStr) -> crate::Result<TokenStream> {
+fn resolve_migrations_from_dir(dir: &LitStr) -> crate::Result<Vec<QuotedMigration>> {
     let path = crate::common::resolve_path(&dir.value(), dir.span())?;
     let mut migrations = Vec::new();
 
@@ -70,25 +249,44 @@ pub(crate) fn expand_migrator_from_dir(dir: LitStr) -> crate::Result<TokenStream
         let file_name = entry.file_name();
         let file_name = file_name.to_string_lossy();
 
-        let parts = file_name.splitn(2, '_').collect::<Vec<_>>();
+        // repeatable migrations have no version prefix: R__<DESCRIPTION>.sql
+        let (version, migration_type, description) =
+            if let Some(rest) = file_name.strip_prefix("R__") {
+                if !rest.ends_with(".sql") {
+                    continue;
+                }
 
-        if parts.len() != 2 || !parts[1].ends_with(".sql") {
-            // not of the format: <VERSION>_<DESCRIPTION>.sql; ignore
-            continue;
-        }
+                let description = rest.trim_end_matches(".sql").replace('_', " ");
 
-        let version: i64 = parts[0].parse()?;
+                (
+                    sqlx_core::migrate::repeatable_version(&description),
+                    MigrationType::Repeatable,
+                    description,
+                )
+            } else {
+                let parts = file_name.splitn(2, '_').collect::<Vec<_>>();
 
-        let migration_type = MigrationType::from_filename(parts[1]);
-        // remove the `.sql` and replace `_` with ` `
-        let description = parts[1]
-            .trim_end_matches(migration_type.suffix())
-            .replace('_', " ")
-            .to_owned();
+                if parts.len() != 2 || !parts[1].ends_with(".sql") {
+                    // not of the format: <VERSION>_<DESCRIPTION>.sql; ignore
+                    continue;
+                }
+
+                let version: i64 = parts[0].parse()?;
+
+                let migration_type = MigrationType::from_filename(parts[1]);
+                // remove the `.sql` and replace `_` with ` `
+                let description = parts[1]
+                    .trim_end_matches(migration_type.suffix())
+                    .replace('_', " ")
+                    .to_owned();
+
+                (version, migration_type, description)
+            };
 
         let sql = fs::read_to_string(&entry.path())?;
 
         let checksum = Vec::from(Sha384::digest(sql.as_bytes()).as_slice());
+        let no_tx = sqlx_core::migrate::parse_no_tx(&sql);
 
         // canonicalize the path so we can pass it to `include_str!()`
         let path = entry.path().canonicalize()?;
@@ -108,6 +306,7 @@ pub(crate) fn expand_migrator_from_dir(dir: LitStr) -> crate::Result<TokenStream
             migration_type: QuotedMigrationType(migration_type),
             path,
             checksum,
+            no_tx,
         })
     }
 
@@ -127,12 +326,5 @@ pub(crate) fn expand_migrator_from_dir(dir: LitStr) -> crate::Result<TokenStream
         proc_macro::tracked_path::path(path);
     }
 
-    Ok(quote! {
-        ::sqlx::migrate::Migrator {
-            migrations: ::std::borrow::Cow::Borrowed(&[
-                #(#migrations),*
-            ]),
-            ignore_missing: false,
-        }
-    })
+    Ok(migrations)
 }
@@ -1,5 +1,5 @@
 use crate::database::DatabaseExt;
-use crate::query::QueryMacroInput;
+use crate::query::{placeholder_style, PlaceholderStyle, QueryMacroInput};
 use either::Either;
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote, quote_spanned};
@@ -15,7 +15,7 @@ pub fn quote_args<DB: DatabaseExt>(
 ) -> crate::Result<TokenStream> {
     let db_path = DB::db_path();
 
-    if input.arg_exprs.is_empty() {
+    if input.arg_exprs.is_empty() && input.in_list.is_none() {
         return Ok(quote! {
             let query_args = <#db_path as ::sqlx::database::HasArguments>::Arguments::default();
         });
@@ -32,7 +32,7 @@ pub fn quote_args<DB: DatabaseExt>(
         #(let #arg_name = &(#arg_expr);)*
     };
 
-    let args_check = match info.parameters() {
+    let mut args_check = match info.parameters() {
         None | Some(Either::Right(_)) => {
             // all we can do is check arity which we did
             TokenStream::new()
@@ -98,8 +98,79 @@ pub fn quote_args<DB: DatabaseExt>(
         }
     };
 
+    if let (Some(in_list), Some(Either::Left(params))) = (&input.in_list, info.parameters()) {
+        if input.checked {
+            // the collapsed `{...}` placeholder is described after the explicit arguments,
+            // so its type is the last parameter; check every element of the list against it
+            let param_ty = &params[input.arg_exprs.len()];
+
+            let param_ty = DB::param_type_for_id(param_ty)
+                .ok_or_else(|| {
+                    if let Some(feature_gate) = <DB as DatabaseExt>::get_feature_gate(param_ty) {
+                        format!(
+                            "optional feature `{}` required for type {} of the `in_list` elements",
+                            feature_gate, param_ty,
+                        )
+                    } else {
+                        format!("unsupported type {} for the `in_list` elements", param_ty)
+                    }
+                })?
+                .parse::<TokenStream>()
+                .map_err(|_| format!("Rust type mapping for {} not parsable", param_ty))?;
+
+            args_check.extend(quote_spanned!(in_list.expr.span() =>
+                // this shouldn't actually run
+                if false {
+                    use ::sqlx::ty_match::{WrapSameExt as _, MatchBorrowExt as _};
+
+                    for el in ::std::iter::IntoIterator::into_iter(__in_list) {
+                        let expr = ::sqlx::ty_match::dupe_value(el);
+
+                        let ty_check = ::sqlx::ty_match::WrapSame::<#param_ty, _>::new(&expr).wrap_same();
+
+                        let (mut _ty_check, match_borrow) = ::sqlx::ty_match::MatchBorrow::new(ty_check, &expr);
+
+                        _ty_check = match_borrow.match_borrow();
+                    }
+
+                    // this causes move-analysis to effectively ignore this block
+                    ::std::panic!();
+                }
+            ));
+        }
+    }
+
     let args_count = input.arg_exprs.len();
 
+    let (args_count, add_args) = match &input.in_list {
+        Some(in_list) => {
+            // with the positional style the list's placeholders sit between any explicit
+            // placeholders in the prefix and those in the suffix, and arguments bind in
+            // query order; with numbered styles the list is always numbered last
+            let split = match placeholder_style::<DB>() {
+                PlaceholderStyle::Positional => {
+                    in_list.prefix.matches('?').count().min(arg_names.len())
+                }
+                PlaceholderStyle::Numbered | PlaceholderStyle::AtNumbered => arg_names.len(),
+            };
+
+            let before = &arg_names[..split];
+            let after = &arg_names[split..];
+
+            (
+                quote!(#args_count + __in_list_len),
+                quote! {
+                    #(query_args.add(#before);)*
+                    for el in ::std::iter::IntoIterator::into_iter(__in_list) {
+                        query_args.add(el);
+                    }
+                    #(query_args.add(#after);)*
+                },
+            )
+        }
+        None => (quote!(#args_count), quote!(#(query_args.add(#arg_name);)*)),
+    };
+
     Ok(quote! {
         #arg_bindings
 
@@ -110,7 +181,7 @@ pub fn quote_args<DB: DatabaseExt>(
             #args_count,
             0 #(+ ::sqlx::encode::Encode::<#db_path>::size_hint(#arg_name))*
         );
-        #(query_args.add(#arg_name);)*
+        #add_args
     })
 }
 
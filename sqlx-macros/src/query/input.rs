@@ -1,11 +1,15 @@
 use std::fs;
 
-use proc_macro2::{Ident, Span};
+use proc_macro2::{Ident, Span, TokenStream};
+use quote::quote;
 use syn::parse::{Parse, ParseStream};
 use syn::punctuated::Punctuated;
 use syn::{Expr, LitBool, LitStr, Token};
 use syn::{ExprArray, Type};
 
+/// The marker that a runtime-expanded `IN` list replaces in the query string.
+pub(super) const IN_LIST_MARKER: &str = "{...}";
+
 /// Macro input shared by `query!()` and `query_file!()`
 pub struct QueryMacroInput {
     pub(super) sql: String,
@@ -17,11 +21,22 @@ pub struct QueryMacroInput {
 
     pub(super) arg_exprs: Vec<Expr>,
 
+    pub(super) in_list: Option<InList>,
+
     pub(super) checked: bool,
 
     pub(super) file_path: Option<String>,
 }
 
+/// A runtime-sized `IN` list bound to the `{...}` marker; see `query_in!()`.
+pub struct InList {
+    pub(super) expr: Expr,
+
+    /// The query text before and after the `{...}` marker.
+    pub(super) prefix: String,
+    pub(super) suffix: String,
+}
+
 enum QuerySrc {
     String(String),
     File(String),
@@ -30,6 +45,7 @@ enum QuerySrc {
 pub enum RecordType {
     Given(Type),
     Scalar,
+    Tuple,
     Generated,
 }
 
@@ -37,6 +53,7 @@ impl Parse for QueryMacroInput {
     fn parse(input: ParseStream) -> syn::Result<Self> {
         let mut query_src: Option<(QuerySrc, Span)> = None;
         let mut args: Option<Vec<Expr>> = None;
+        let mut in_list_expr: Option<Expr> = None;
         let mut record_type = RecordType::Generated;
         let mut checked = true;
 
@@ -64,15 +81,25 @@ impl Parse for QueryMacroInput {
             } else if key == "args" {
                 let exprs = input.parse::<ExprArray>()?;
                 args = Some(exprs.elems.into_iter().collect())
+            } else if key == "in_list" {
+                in_list_expr = Some(input.parse()?);
             } else if key == "record" {
                 if !matches!(record_type, RecordType::Generated) {
-                    return Err(input.error("colliding `scalar` or `record` key"));
+                    return Err(input.error("colliding `scalar`, `tuple` or `record` key"));
                 }
 
                 record_type = RecordType::Given(input.parse()?);
+            } else if key == "tuple" {
+                if !matches!(record_type, RecordType::Generated) {
+                    return Err(input.error("colliding `scalar`, `tuple` or `record` key"));
+                }
+
+                // like `scalar`, we expect only `tuple = _`
+                input.parse::<syn::Token![_]>()?;
+                record_type = RecordType::Tuple;
             } else if key == "scalar" {
                 if !matches!(record_type, RecordType::Generated) {
-                    return Err(input.error("colliding `scalar` or `record` key"));
+                    return Err(input.error("colliding `scalar`, `tuple` or `record` key"));
                 }
 
                 // we currently expect only `scalar = _`
@@ -98,17 +125,77 @@ impl Parse for QueryMacroInput {
 
         let file_path = src.file_path(src_span)?;
 
+        let sql = src.resolve(src_span)?;
+
+        let in_list = match in_list_expr {
+            Some(expr) => {
+                let start = sql.find(IN_LIST_MARKER).ok_or_else(|| {
+                    syn::Error::new(
+                        src_span,
+                        format!("expected exactly one `{}` marker in the query", IN_LIST_MARKER),
+                    )
+                })?;
+
+                let prefix = sql[..start].to_string();
+                let suffix = sql[start + IN_LIST_MARKER.len()..].to_string();
+
+                if suffix.contains(IN_LIST_MARKER) {
+                    return Err(syn::Error::new(
+                        src_span,
+                        format!("only one `{}` marker is supported per query", IN_LIST_MARKER),
+                    ));
+                }
+
+                Some(InList {
+                    expr,
+                    prefix,
+                    suffix,
+                })
+            }
+            None => {
+                if sql.contains(IN_LIST_MARKER) {
+                    return Err(syn::Error::new(
+                        src_span,
+                        format!(
+                            "the `{}` marker requires a list argument; use `query_in!()`",
+                            IN_LIST_MARKER
+                        ),
+                    ));
+                }
+
+                None
+            }
+        };
+
         Ok(QueryMacroInput {
-            sql: src.resolve(src_span)?,
+            sql,
             src_span,
             record_type,
             arg_exprs,
+            in_list,
             checked,
             file_path,
         })
     }
 }
 
+impl QueryMacroInput {
+    /// The query string to embed in the expansion: the runtime-built `__sql` for
+    /// `query_in!()`, otherwise the source text itself.
+    pub(super) fn quote_sql(&self) -> TokenStream {
+        if self.in_list.is_some() {
+            quote!(__sql)
+        } else if let Some(ref path) = self.file_path {
+            // if this query came from a file, use `include_str!()` to tell the compiler
+            // where it came from
+            quote::quote_spanned! { self.src_span => include_str!(#path) }
+        } else {
+            let sql = &self.sql;
+            quote! { #sql }
+        }
+    }
+}
+
 impl QuerySrc {
     /// If the query source is a file, read it to a string. Otherwise return the query string.
     fn resolve(self, source_span: Span) -> syn::Result<String> {
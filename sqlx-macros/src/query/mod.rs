@@ -24,6 +24,133 @@ mod data;
 mod input;
 mod output;
 
+/// How the database writes bind-parameter placeholders; this decides how a runtime
+/// `IN` list (`in_list = ...`) is expanded.
+#[derive(Clone, Copy, PartialEq)]
+pub(crate) enum PlaceholderStyle {
+    /// `?`, bound in the order placeholders appear in the query
+    Positional,
+    /// `$1`, `$2`, ...
+    Numbered,
+    /// `@p1`, `@p2`, ...
+    AtNumbered,
+}
+
+pub(crate) fn placeholder_style<DB: DatabaseExt>() -> PlaceholderStyle {
+    match DB::NAME {
+        "PostgreSQL" => PlaceholderStyle::Numbered,
+        "MSSQL" => PlaceholderStyle::AtNumbered,
+        _ => PlaceholderStyle::Positional,
+    }
+}
+
+/// The query that is prepared against the database to type-check a macro invocation:
+/// the `{...}` marker, if any, collapses to a single placeholder so that the element
+/// type of the list can be checked like an ordinary parameter.
+fn query_for_describe(input: &QueryMacroInput, style: PlaceholderStyle) -> String {
+    match &input.in_list {
+        Some(in_list) => {
+            let mut sql =
+                String::with_capacity(in_list.prefix.len() + in_list.suffix.len() + 4);
+
+            sql.push_str(&in_list.prefix);
+
+            match style {
+                PlaceholderStyle::Positional => sql.push('?'),
+                PlaceholderStyle::Numbered => {
+                    sql.push_str(&format!("${}", input.arg_exprs.len() + 1))
+                }
+                PlaceholderStyle::AtNumbered => {
+                    sql.push_str(&format!("@p{}", input.arg_exprs.len() + 1))
+                }
+            }
+
+            sql.push_str(&in_list.suffix);
+
+            sql
+        }
+        None => input.sql.clone(),
+    }
+}
+
+/// Returns a tokenstream that evaluates the `in_list` expression into `__in_list`, counts
+/// its elements into `__in_list_len` and builds the final query string as `__sql`.
+///
+/// The built string is interned (one allocation per distinct list length for the lifetime
+/// of the process) so the returned query can borrow it for `'static`.
+fn in_list_tokens<DB: DatabaseExt>(input: &QueryMacroInput) -> TokenStream {
+    let in_list = match &input.in_list {
+        Some(in_list) => in_list,
+        None => return TokenStream::new(),
+    };
+
+    let expr = &in_list.expr;
+    let prefix = &in_list.prefix;
+    let suffix = &in_list.suffix;
+
+    let push_placeholder = match placeholder_style::<DB>() {
+        PlaceholderStyle::Positional => quote! { sql.push('?'); },
+        PlaceholderStyle::Numbered => {
+            let start = input.arg_exprs.len() + 1;
+            quote! {
+                use ::std::fmt::Write as _;
+                let _ = ::std::write!(sql, "${}", #start + i);
+            }
+        }
+        PlaceholderStyle::AtNumbered => {
+            let start = input.arg_exprs.len() + 1;
+            quote! {
+                use ::std::fmt::Write as _;
+                let _ = ::std::write!(sql, "@p{}", #start + i);
+            }
+        }
+    };
+
+    quote! {
+        let __in_list = &(#expr);
+        let __in_list_len = ::std::iter::IntoIterator::into_iter(__in_list).count();
+
+        let __sql: &'static str = {
+            static SQL_CACHE: ::std::sync::Mutex<
+                Option<::std::collections::HashMap<usize, &'static str>>,
+            > = ::std::sync::Mutex::new(None);
+
+            let mut cache = SQL_CACHE.lock().unwrap();
+
+            match cache
+                .get_or_insert_with(::std::collections::HashMap::new)
+                .entry(__in_list_len)
+            {
+                ::std::collections::hash_map::Entry::Occupied(entry) => *entry.get(),
+                ::std::collections::hash_map::Entry::Vacant(entry) => {
+                    let mut sql = ::std::string::String::with_capacity(
+                        #prefix.len() + #suffix.len() + __in_list_len * 4,
+                    );
+
+                    sql.push_str(#prefix);
+
+                    if __in_list_len == 0 {
+                        // `x IN ()` is invalid SQL; `x IN (NULL)` matches no rows
+                        sql.push_str("NULL");
+                    }
+
+                    for i in 0..__in_list_len {
+                        if i > 0 {
+                            sql.push_str(", ");
+                        }
+
+                        #push_placeholder
+                    }
+
+                    sql.push_str(#suffix);
+
+                    *entry.insert(::std::boxed::Box::leak(sql.into_boxed_str()))
+                }
+            }
+        };
+    }
+}
+
 struct Metadata {
     #[allow(unused)]
     manifest_dir: PathBuf,
@@ -125,13 +252,23 @@ pub fn expand_input(input: QueryMacroInput) -> crate::Result<TokenStream> {
 
         #[cfg(feature = "offline")]
         _ => {
-            let data_file_path = METADATA.manifest_dir.join("sqlx-data.json");
+            let file_names = offline_data_file_names();
 
-            if data_file_path.exists() {
+            let data_file_path = file_names
+                .iter()
+                .map(|name| METADATA.manifest_dir.join(name))
+                .find(|path| path.exists());
+
+            if let Some(data_file_path) = data_file_path {
                 expand_from_file(input, data_file_path)
             } else {
-                let workspace_data_file_path = METADATA.workspace_root().join("sqlx-data.json");
-                if workspace_data_file_path.exists() {
+                let workspace_root = METADATA.workspace_root();
+                let workspace_data_file_path = file_names
+                    .iter()
+                    .map(|name| workspace_root.join(name))
+                    .find(|path| path.exists());
+
+                if let Some(workspace_data_file_path) = workspace_data_file_path {
                     expand_from_file(input, workspace_data_file_path)
                 } else {
                     Err(
@@ -166,9 +303,10 @@ fn expand_from_db(input: QueryMacroInput, db_url: &str) -> crate::Result<TokenSt
     match db_url.scheme() {
         #[cfg(feature = "postgres")]
         "postgres" | "postgresql" => {
+            let describe_sql = query_for_describe(&input, PlaceholderStyle::Numbered);
             let data = block_on(async {
                 let mut conn = sqlx_core::postgres::PgConnection::connect(db_url.as_str()).await?;
-                QueryData::from_db(&mut conn, &input.sql).await
+                QueryData::from_db(&mut conn, &describe_sql).await
             })?;
 
             expand_with_data(input, data, false)
@@ -179,9 +317,10 @@ fn expand_from_db(input: QueryMacroInput, db_url: &str) -> crate::Result<TokenSt
 
         #[cfg(feature = "mssql")]
         "mssql" | "sqlserver" => {
+            let describe_sql = query_for_describe(&input, PlaceholderStyle::AtNumbered);
             let data = block_on(async {
                 let mut conn = sqlx_core::mssql::MssqlConnection::connect(db_url.as_str()).await?;
-                QueryData::from_db(&mut conn, &input.sql).await
+                QueryData::from_db(&mut conn, &describe_sql).await
             })?;
 
             expand_with_data(input, data, false)
@@ -192,9 +331,10 @@ fn expand_from_db(input: QueryMacroInput, db_url: &str) -> crate::Result<TokenSt
 
         #[cfg(feature = "mysql")]
         "mysql" | "mariadb" => {
+            let describe_sql = query_for_describe(&input, PlaceholderStyle::Positional);
             let data = block_on(async {
                 let mut conn = sqlx_core::mysql::MySqlConnection::connect(db_url.as_str()).await?;
-                QueryData::from_db(&mut conn, &input.sql).await
+                QueryData::from_db(&mut conn, &describe_sql).await
             })?;
 
             expand_with_data(input, data, false)
@@ -205,9 +345,10 @@ fn expand_from_db(input: QueryMacroInput, db_url: &str) -> crate::Result<TokenSt
 
         #[cfg(feature = "sqlite")]
         "sqlite" => {
+            let describe_sql = query_for_describe(&input, PlaceholderStyle::Positional);
             let data = block_on(async {
                 let mut conn = sqlx_core::sqlite::SqliteConnection::connect(db_url.as_str()).await?;
-                QueryData::from_db(&mut conn, &input.sql).await
+                QueryData::from_db(&mut conn, &describe_sql).await
             })?;
 
             expand_with_data(input, data, false)
@@ -220,10 +361,46 @@ fn expand_from_db(input: QueryMacroInput, db_url: &str) -> crate::Result<TokenSt
     }
 }
 
+/// The offline data files to search, in priority order: queries prepared against a second
+/// backend are kept in a backend-specific file (see `cargo sqlx prepare`), so if
+/// `DATABASE_URL` names a backend we look for its file before the shared `sqlx-data.json`.
+#[cfg(feature = "offline")]
+fn offline_data_file_names() -> Vec<String> {
+    let mut names = Vec::with_capacity(2);
+
+    let kind = METADATA
+        .database_url
+        .as_deref()
+        .and_then(|url| Url::parse(url).ok())
+        .and_then(|url| match url.scheme() {
+            "postgres" | "postgresql" => Some("postgresql"),
+            "mysql" | "mariadb" => Some("mysql"),
+            "sqlite" => Some("sqlite"),
+            "mssql" | "sqlserver" => Some("mssql"),
+            _ => None,
+        });
+
+    if let Some(kind) = kind {
+        names.push(format!("sqlx-data-{}.json", kind));
+    }
+
+    names.push("sqlx-data.json".to_owned());
+
+    names
+}
+
 #[cfg(feature = "offline")]
 pub fn expand_from_file(input: QueryMacroInput, file: PathBuf) -> crate::Result<TokenStream> {
     use data::offline::DynQueryData;
 
+    if input.in_list.is_some() {
+        return Err(
+            "`query_in!()` and its variants require a live database connection \
+             and are not supported in offline mode"
+                .into(),
+        );
+    }
+
     let query_data = DynQueryData::from_data_file(file, &input.sql)?;
     assert!(!query_data.db_name.is_empty());
 
@@ -286,14 +463,17 @@ where
         None => None,
     };
 
+    // the `{...}` marker describes as a single extra parameter
+    let given_parameters = input.arg_exprs.len() + input.in_list.is_some() as usize;
+
     if let Some(num) = num_parameters {
-        if num != input.arg_exprs.len() {
-            return Err(
-                format!("expected {} parameters, got {}", num, input.arg_exprs.len()).into(),
-            );
+        if num != given_parameters {
+            return Err(format!("expected {} parameters, got {}", num, given_parameters).into());
         }
     }
 
+    let in_list_tokens = in_list_tokens::<DB>(&input);
+
     let args_tokens = args::quote_args(&input, &data.describe)?;
 
     let query_args = format_ident!("query_args");
@@ -305,7 +485,7 @@ where
         .all(|it| it.type_info().is_void())
     {
         let db_path = DB::db_path();
-        let sql = &input.sql;
+        let sql = input.quote_sql();
 
         quote! {
             ::sqlx::query_with::<#db_path, _>(#sql, #query_args)
@@ -356,6 +536,11 @@ where
 
                 output::quote_query_as::<DB>(&input, out_ty, &query_args, &columns)
             }
+            RecordType::Tuple => {
+                let columns = output::columns_to_rust::<DB>(&data.describe)?;
+
+                output::quote_query_tuple::<DB>(&input, &query_args, &columns)
+            }
             RecordType::Scalar => {
                 output::quote_query_scalar::<DB>(&input, &query_args, &data.describe)?
             }
@@ -368,6 +553,8 @@ where
             {
                 use ::sqlx::Arguments as _;
 
+                #in_list_tokens
+
                 #args_tokens
 
                 #output
@@ -105,7 +105,8 @@ fn column_to_rust<DB: DatabaseExt>(describe: &Describe<DB>, i: usize) -> crate::
         (ColumnTypeOverride::Wildcard, true) => ColumnType::OptWildcard,
 
         (ColumnTypeOverride::None, _) => {
-            let type_ = get_column_type::<DB>(i, column);
+            let type_ = configured_column_type::<DB>(&decl.ident, column)
+                .unwrap_or_else(|| get_column_type::<DB>(i, column));
             if !nullable {
                 ColumnType::Exact(type_)
             } else {
@@ -129,33 +130,7 @@ pub fn quote_query_as<DB: DatabaseExt>(
     bind_args: &Ident,
     columns: &[RustColumn],
 ) -> TokenStream {
-    let instantiations = columns.iter().enumerate().map(
-        |(
-            i,
-            &RustColumn {
-                ref var_name,
-                ref type_,
-                ..
-            },
-        )| {
-            match (input.checked, type_) {
-                // we guarantee the type is valid so we can skip the runtime check
-                (true, ColumnType::Exact(type_)) => quote! {
-                    // binding to a `let` avoids confusing errors about
-                    // "try expression alternatives have incompatible types"
-                    // it doesn't seem to hurt inference in the other branches
-                    let #var_name = row.try_get_unchecked::<#type_, _>(#i)?;
-                },
-                // type was overridden to be a wildcard so we fallback to the runtime check
-                (true, ColumnType::Wildcard) => quote! ( let #var_name = row.try_get(#i)?; ),
-                (true, ColumnType::OptWildcard) => {
-                    quote! ( let #var_name = row.try_get::<::std::option::Option<_>, _>(#i)?; )
-                }
-                // macro is the `_unchecked!()` variant so this will die in decoding if it's wrong
-                (false, _) => quote!( let #var_name = row.try_get_unchecked(#i)?; ),
-            }
-        },
-    );
+    let instantiations = quote_instantiations(input, columns);
 
     let ident = columns.iter().map(|col| &col.ident);
     let var_name = columns.iter().map(|col| &col.var_name);
@@ -163,13 +138,7 @@ pub fn quote_query_as<DB: DatabaseExt>(
     let db_path = DB::db_path();
     let row_path = DB::row_path();
 
-    // if this query came from a file, use `include_str!()` to tell the compiler where it came from
-    let sql = if let Some(ref path) = &input.file_path {
-        quote::quote_spanned! { input.src_span => include_str!(#path) }
-    } else {
-        let sql = &input.sql;
-        quote! { #sql }
-    };
+    let sql = input.quote_sql();
 
     quote! {
         ::sqlx::query_with::<#db_path, _>(#sql, #bind_args).try_map(|row: #row_path| {
@@ -182,6 +151,67 @@ pub fn quote_query_as<DB: DatabaseExt>(
     }
 }
 
+/// Like [quote_query_as] but maps each row to a tuple of the columns, in order.
+pub fn quote_query_tuple<DB: DatabaseExt>(
+    input: &QueryMacroInput,
+    bind_args: &Ident,
+    columns: &[RustColumn],
+) -> TokenStream {
+    let instantiations = quote_instantiations(input, columns);
+
+    let var_name = columns.iter().map(|col| &col.var_name);
+
+    let db_path = DB::db_path();
+    let row_path = DB::row_path();
+
+    let sql = input.quote_sql();
+
+    quote! {
+        ::sqlx::query_with::<#db_path, _>(#sql, #bind_args).try_map(|row: #row_path| {
+            use ::sqlx::Row as _;
+
+            #(#instantiations)*
+
+            Ok((#(#var_name),*,))
+        })
+    }
+}
+
+/// One `let` binding per column, decoding it from `row` into its `var_name`.
+fn quote_instantiations(input: &QueryMacroInput, columns: &[RustColumn]) -> Vec<TokenStream> {
+    columns
+        .iter()
+        .enumerate()
+        .map(
+            |(
+                i,
+                &RustColumn {
+                    ref var_name,
+                    ref type_,
+                    ..
+                },
+            )| {
+                match (input.checked, type_) {
+                    // we guarantee the type is valid so we can skip the runtime check
+                    (true, ColumnType::Exact(type_)) => quote! {
+                        // binding to a `let` avoids confusing errors about
+                        // "try expression alternatives have incompatible types"
+                        // it doesn't seem to hurt inference in the other branches
+                        let #var_name = row.try_get_unchecked::<#type_, _>(#i)?;
+                    },
+                    // type was overridden to be a wildcard so we fallback to the runtime check
+                    (true, ColumnType::Wildcard) => quote! ( let #var_name = row.try_get(#i)?; ),
+                    (true, ColumnType::OptWildcard) => {
+                        quote! ( let #var_name = row.try_get::<::std::option::Option<_>, _>(#i)?; )
+                    }
+                    // macro is the `_unchecked!()` variant so this will die in decoding if it's wrong
+                    (false, _) => quote!( let #var_name = row.try_get_unchecked(#i)?; ),
+                }
+            },
+        )
+        .collect()
+}
+
 pub fn quote_query_scalar<DB: DatabaseExt>(
     input: &QueryMacroInput,
     bind_args: &Ident,
@@ -212,13 +242,34 @@ pub fn quote_query_scalar<DB: DatabaseExt>(
     };
 
     let db = DB::db_path();
-    let query = &input.sql;
+    let query = input.quote_sql();
 
     Ok(quote! {
         ::sqlx::query_scalar_with::<#db, #ty, _>(#query, #bind_args)
     })
 }
 
+/// A Rust type configured in `sqlx.toml` for this column's name or SQL type, if any;
+/// per-query overrides take precedence by virtue of only being consulted without one.
+fn configured_column_type<DB: DatabaseExt>(ident: &Ident, column: &DB::Column) -> Option<TokenStream> {
+    use sqlx_core::type_info::TypeInfo;
+
+    let name = ident.to_string();
+
+    crate::config::CONFIG
+        .type_overrides
+        .for_column(name.trim_start_matches("r#"), column.type_info().name())
+        .map(|ty| {
+            ty.parse().unwrap_or_else(|_| {
+                syn::Error::new(
+                    Span::call_site(),
+                    format!("configured type override `{}` is not a valid Rust type", ty),
+                )
+                .to_compile_error()
+            })
+        })
+}
+
 fn get_column_type<DB: DatabaseExt>(i: usize, column: &DB::Column) -> TokenStream {
     let type_info = &*column.type_info();
 
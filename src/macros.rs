@@ -273,6 +273,24 @@
 /// | `foo!: T` | Forced not-null | Overridden |
 /// | `foo?: T` | Forced nullable | Overridden |
 ///
+/// ##### Configured Overrides (`sqlx.toml`)
+///
+/// Type overrides that should apply to every query, like a domain newtype for a column that
+/// appears everywhere, can be configured once in a `sqlx.toml` next to `Cargo.toml` instead
+/// of being repeated per query:
+///
+/// ```toml,ignore
+/// [macros.type-overrides.columns]
+/// user_id = "crate::UserId"
+///
+/// [macros.type-overrides.sql-types]
+/// DATETIME = "crate::Timestamp"
+/// ```
+///
+/// Precedence, highest first: a per-query override as above, then the column-name mapping,
+/// then the SQL-type mapping (keyed by the type name the database reports), then the
+/// built-in mapping. Nullability is unaffected and can still be overridden per query.
+///
 /// ## Offline Mode (requires the `offline` feature)
 /// The macros can be configured to not require a live database connection for compilation,
 /// but it requires a couple extra steps:
@@ -317,6 +335,43 @@ macro_rules! query (
     })
 );
 
+/// A variant of [query!] which expands a runtime-sized list into an `IN` clause.
+///
+/// The query must contain exactly one `{...}` marker, usually inside the parentheses of an
+/// `IN (...)` clause; the expression following the query string supplies the list. Anything
+/// that implements `IntoIterator` by reference works, e.g. a `Vec` or a slice:
+///
+/// ```rust,ignore
+/// let ids: Vec<i64> = vec![1, 3, 7];
+///
+/// let rows = sqlx::query_in!("SELECT id, text FROM tweet WHERE id IN ({...})", ids)
+///     .fetch_all(&mut conn)
+///     .await?;
+/// ```
+///
+/// At compile time the query is type-checked with the marker collapsed to a single
+/// placeholder, so the element type of the list is verified against the column just like an
+/// ordinary parameter. At run time the marker is replaced by one placeholder per element and
+/// every element is bound; an empty list expands to `NULL` so the clause matches no rows.
+/// Any further arguments after the list bind to the query's explicit placeholders as with
+/// [query!].
+///
+/// The expanded query string is cached for the lifetime of the process, one small allocation
+/// per distinct list length per call site.
+///
+/// Because the final parameter count is only known at run time, this macro requires a live
+/// database connection at compile time and is not supported in offline mode.
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+macro_rules! query_in (
+    ($query:expr, $list:expr) => ({
+        $crate::sqlx_macros::expand_query!(source = $query, in_list = $list)
+    });
+    ($query:expr, $list:expr, $($args:tt)*) => ({
+        $crate::sqlx_macros::expand_query!(source = $query, in_list = $list, args = [$($args)*])
+    })
+);
+
 /// A variant of [query!] which does not check the input or output types. This still does parse
 /// the query to ensure it's syntactically and semantically valid for the current database.
 #[macro_export]
@@ -654,6 +709,32 @@ macro_rules! query_scalar (
     )
 );
 
+/// A variant of [query!] which maps each row to a tuple of the columns, in order, instead of
+/// generating a `Record` struct.
+///
+/// This is a lightweight option for queries that return a handful of values, like a
+/// multi-value aggregate, where naming a struct is more ceremony than it's worth:
+///
+/// ```rust,ignore
+/// let (count, newest): (i64, Option<i64>) =
+///     sqlx::query_tuple!(r#"select count(*) as "n!", max(id) as max_id from tweet"#)
+///         .fetch_one(&mut conn)
+///         .await?;
+/// ```
+///
+/// The same compile-time checking and column override syntax as [query!] apply; each column
+/// name must still be a valid Rust identifier.
+#[macro_export]
+#[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
+macro_rules! query_tuple (
+    ($query:expr) => (
+        $crate::sqlx_macros::expand_query!(tuple = _, source = $query)
+    );
+    ($query:expr, $($args:tt)*) => (
+        $crate::sqlx_macros::expand_query!(tuple = _, source = $query, args = [$($args)*])
+    )
+);
+
 /// A variant of [query_scalar!] which takes a file path like [query_file!].
 #[macro_export]
 #[cfg_attr(docsrs, doc(cfg(feature = "macros")))]
@@ -788,7 +869,46 @@ macro_rules! migrate {
         $crate::sqlx_macros::migrate!($dir)
     }};
 
+    ([$($dir:literal),+ $(,)?]) => {{
+        $crate::sqlx_macros::migrate!([$($dir),+])
+    }};
+
     () => {{
         $crate::sqlx_macros::migrate!("./migrations")
     }};
 }
+
+/// Validates the migrations directory at compile time, failing the build on problems that
+/// [migrate!] would silently ignore.
+///
+/// Takes the same arguments as [migrate!] (a directory, a bracketed list of directories, or
+/// nothing for `./migrations`) and expands to `()`. The build fails if:
+///
+/// * a file in the directory does not parse as `<VERSION>_<DESCRIPTION>.sql` (or
+///   `R__<DESCRIPTION>.sql` for repeatable migrations),
+/// * the same version is defined more than once, or
+/// * offline query data was prepared (`sqlx-data.json` exists) but a migration file is newer
+///   than it, meaning the prepared schema may no longer match the migrations.
+///
+/// ```rust,ignore
+/// sqlx::migrate_check!("./migrations");
+/// ```
+///
+/// This complements the schema checking done by [query!]: a query can only be verified
+/// against the schema the database (or the offline data) had at compile time, and this macro
+/// catches the migrations directory drifting away from that schema.
+#[cfg(feature = "migrate")]
+#[macro_export]
+macro_rules! migrate_check {
+    ($dir:literal) => {{
+        $crate::sqlx_macros::migrate_check!($dir)
+    }};
+
+    ([$($dir:literal),+ $(,)?]) => {{
+        $crate::sqlx_macros::migrate_check!([$($dir),+])
+    }};
+
+    () => {{
+        $crate::sqlx_macros::migrate_check!("./migrations")
+    }};
+}
@@ -38,6 +38,49 @@ async fn it_executes_with_pool() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_binds_and_decodes_bool() -> anyhow::Result<()> {
+    let mut conn = new::<Any>().await?;
+
+    for expected in [true, false] {
+        let value = sqlx::query("select ?")
+            .bind(expected)
+            .try_map(|row: AnyRow| row.try_get::<bool, _>(0))
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(expected, value);
+    }
+
+    conn.close().await?;
+
+    Ok(())
+}
+
+// NUMERIC/DECIMAL values decode losslessly everywhere except MSSQL, which has
+// no `Decimal` support
+#[cfg(all(feature = "decimal", not(feature = "mssql")))]
+#[sqlx_macros::test]
+async fn it_binds_and_decodes_decimal() -> anyhow::Result<()> {
+    use std::str::FromStr;
+
+    let expected = sqlx::types::Decimal::from_str("12345.6789").unwrap();
+
+    let mut conn = new::<Any>().await?;
+
+    let value = sqlx::query("select ?")
+        .bind(expected)
+        .try_map(|row: AnyRow| row.try_get::<sqlx::types::Decimal, _>(0))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(expected, value);
+
+    conn.close().await?;
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_gets_by_name() -> anyhow::Result<()> {
     let mut conn = new::<Any>().await?;
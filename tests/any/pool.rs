@@ -64,3 +64,19 @@ async fn pool_should_be_returned_failed_transactions() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg(feature = "sqlite")]
+#[sqlx_macros::test]
+async fn pool_reports_backend_name() -> anyhow::Result<()> {
+    let pool = AnyPoolOptions::new()
+        .max_connections(1)
+        .connect("sqlite://:memory:")
+        .await?;
+
+    assert_eq!(pool.backend_name(), "SQLite");
+
+    let conn = pool.acquire().await?;
+    assert_eq!(conn.backend_name(), "SQLite");
+
+    Ok(())
+}
@@ -3,6 +3,763 @@ use std::path::Path;
 
 static EMBEDDED: Migrator = sqlx::migrate!("tests/migrate/migrations");
 
+static EMBEDDED_MULTI: Migrator = sqlx::migrate!([
+    "tests/migrate/migrations_billing",
+    "tests/migrate/migrations",
+]);
+
+#[cfg(feature = "sqlite")]
+#[sqlx_macros::test]
+async fn undo_to_target_version() -> anyhow::Result<()> {
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    let dir = std::env::temp_dir().join(format!("sqlx-undo-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+
+    for (version, name) in [(1, "one"), (2, "two"), (3, "three")].iter() {
+        std::fs::write(
+            dir.join(format!("{}_{}.up.sql", version, name)),
+            format!("CREATE TABLE {} (id INTEGER);", name),
+        )?;
+        std::fs::write(
+            dir.join(format!("{}_{}.down.sql", version, name)),
+            format!("DROP TABLE {};", name),
+        )?;
+    }
+
+    let migrator = Migrator::new(dir.clone()).await?;
+
+    // a single shared in-memory database
+    let pool = SqlitePoolOptions::new()
+        .min_connections(1)
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await?;
+
+    migrator.run(&pool).await?;
+
+    let (applied,): (i64,) = sqlx::query_as("SELECT count(*) FROM _sqlx_migrations")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(applied, 3);
+
+    migrator.undo(&pool, 1).await?;
+
+    let (applied,): (i64,) = sqlx::query_as("SELECT count(*) FROM _sqlx_migrations")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(applied, 1);
+
+    // version 1 is still applied; versions 2 and 3 have been reverted
+    let (tables,): (i64,) = sqlx::query_as(
+        "SELECT count(*) FROM sqlite_master WHERE name IN ('one', 'two', 'three')",
+    )
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(tables, 1);
+
+    pool.close().await;
+    let _ = std::fs::remove_dir_all(&dir);
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlite")]
+#[sqlx_macros::test]
+async fn migrators_with_distinct_table_names_coexist() -> anyhow::Result<()> {
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    let dir = std::env::temp_dir().join(format!("sqlx-table-name-{}", std::process::id()));
+    let core = dir.join("core");
+    let billing = dir.join("billing");
+    std::fs::create_dir_all(&core)?;
+    std::fs::create_dir_all(&billing)?;
+
+    std::fs::write(core.join("1_users.sql"), "CREATE TABLE users (id INTEGER);")?;
+    std::fs::write(
+        billing.join("1_invoices.sql"),
+        "CREATE TABLE invoices (id INTEGER);",
+    )?;
+
+    let core_migrator = Migrator::new(core).await?;
+
+    let mut billing_migrator = Migrator::new(billing).await?;
+    billing_migrator.with_table_name("_billing_migrations")?;
+
+    // injection attempts are rejected up-front
+    assert!(billing_migrator
+        .with_table_name("_billing_migrations; DROP TABLE users")
+        .is_err());
+
+    let pool = SqlitePoolOptions::new()
+        .min_connections(1)
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await?;
+
+    core_migrator.run(&pool).await?;
+    billing_migrator.run(&pool).await?;
+
+    // each migrator tracked exactly its own migration in its own table
+    for table in ["_sqlx_migrations", "_billing_migrations"].iter() {
+        let (applied,): (i64,) = sqlx::query_as(&format!("SELECT count(*) FROM {}", table))
+            .fetch_one(&pool)
+            .await?;
+        assert_eq!(applied, 1);
+    }
+
+    pool.close().await;
+    let _ = std::fs::remove_dir_all(&dir);
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlite")]
+#[sqlx_macros::test]
+async fn repair_updates_checksums_of_edited_migrations() -> anyhow::Result<()> {
+    use sqlx::migrate::MigrateError;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    let dir = std::env::temp_dir().join(format!("sqlx-repair-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+
+    std::fs::write(dir.join("1_one.sql"), "CREATE TABLE one (id INTEGER);")?;
+
+    let pool = SqlitePoolOptions::new()
+        .min_connections(1)
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await?;
+
+    Migrator::new(dir.clone()).await?.run(&pool).await?;
+
+    // editing a comment changes the checksum but not the applied schema
+    std::fs::write(
+        dir.join("1_one.sql"),
+        "-- users\nCREATE TABLE one (id INTEGER);",
+    )?;
+
+    let migrator = Migrator::new(dir.clone()).await?;
+
+    let res = migrator.run(&pool).await;
+    assert!(matches!(res, Err(MigrateError::VersionMismatch(1))));
+
+    assert_eq!(migrator.repair(&pool).await?, vec![1]);
+
+    // the stored checksum now matches; nothing is re-run and nothing is repaired twice
+    migrator.run(&pool).await?;
+    assert!(migrator.repair(&pool).await?.is_empty());
+
+    pool.close().await;
+    let _ = std::fs::remove_dir_all(&dir);
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlite")]
+#[sqlx_macros::test]
+async fn dry_run_reports_pending_without_applying() -> anyhow::Result<()> {
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    let dir = std::env::temp_dir().join(format!("sqlx-dry-run-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+
+    std::fs::write(dir.join("1_one.sql"), "CREATE TABLE one (id INTEGER);")?;
+    std::fs::write(dir.join("2_two.sql"), "CREATE TABLE two (id INTEGER);")?;
+
+    let migrator = Migrator::new(dir.clone()).await?;
+
+    let pool = SqlitePoolOptions::new()
+        .min_connections(1)
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await?;
+
+    let pending = migrator.dry_run(&pool).await?;
+
+    assert_eq!(
+        pending.iter().map(|m| m.version).collect::<Vec<_>>(),
+        vec![1, 2]
+    );
+
+    // nothing was recorded or executed
+    let (applied,): (i64,) = sqlx::query_as("SELECT count(*) FROM _sqlx_migrations")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(applied, 0);
+
+    let (tables,): (i64,) =
+        sqlx::query_as("SELECT count(*) FROM sqlite_master WHERE name IN ('one', 'two')")
+            .fetch_one(&pool)
+            .await?;
+    assert_eq!(tables, 0);
+
+    // after running for real, the plan is empty
+    migrator.run(&pool).await?;
+    assert!(migrator.dry_run(&pool).await?.is_empty());
+
+    pool.close().await;
+    let _ = std::fs::remove_dir_all(&dir);
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlite")]
+#[sqlx_macros::test]
+async fn validate_reports_discrepancies_without_applying() -> anyhow::Result<()> {
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    let dir = std::env::temp_dir().join(format!("sqlx-validate-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+
+    std::fs::write(dir.join("1_one.sql"), "CREATE TABLE one (id INTEGER);")?;
+    std::fs::write(dir.join("2_two.sql"), "CREATE TABLE two (id INTEGER);")?;
+
+    let pool = SqlitePoolOptions::new()
+        .min_connections(1)
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await?;
+
+    let migrator = Migrator::new(dir.clone()).await?;
+
+    let report = migrator.validate(&pool).await?;
+    assert_eq!(report.pending, vec![1, 2]);
+    assert!(!report.is_clean());
+
+    migrator.run(&pool).await?;
+    assert!(migrator.validate(&pool).await?.is_clean());
+
+    // tamper with the stored checksum of version 1 and drop version 2 from the source
+    sqlx::query("UPDATE _sqlx_migrations SET checksum = x'00' WHERE version = 1")
+        .execute(&pool)
+        .await?;
+    std::fs::remove_file(dir.join("2_two.sql"))?;
+    std::fs::write(dir.join("3_three.sql"), "CREATE TABLE three (id INTEGER);")?;
+
+    let migrator = Migrator::new(dir.clone()).await?;
+
+    let report = migrator.validate(&pool).await?;
+    assert_eq!(report.pending, vec![3]);
+    assert_eq!(report.mismatched, vec![1]);
+    assert_eq!(report.missing, vec![2]);
+
+    // nothing was executed while validating
+    let (tables,): (i64,) =
+        sqlx::query_as("SELECT count(*) FROM sqlite_master WHERE name = 'three'")
+            .fetch_one(&pool)
+            .await?;
+    assert_eq!(tables, 0);
+
+    pool.close().await;
+    let _ = std::fs::remove_dir_all(&dir);
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlite")]
+#[sqlx_macros::test]
+async fn baseline_records_migrations_without_running_them() -> anyhow::Result<()> {
+    use sqlx::migrate::MigrateError;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    let dir = std::env::temp_dir().join(format!("sqlx-baseline-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+
+    for (version, name) in [(1, "one"), (2, "two"), (3, "three"), (4, "four")].iter() {
+        std::fs::write(
+            dir.join(format!("{}_{}.sql", version, name)),
+            format!("CREATE TABLE {} (id INTEGER);", name),
+        )?;
+    }
+
+    let migrator = Migrator::new(dir.clone()).await?;
+
+    let pool = SqlitePoolOptions::new()
+        .min_connections(1)
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await?;
+
+    // an unknown version errors before anything is recorded
+    let res = migrator.baseline(&pool, 9).await;
+    assert!(matches!(res, Err(MigrateError::VersionNotPresent(9))));
+
+    assert_eq!(migrator.baseline(&pool, 3).await?, vec![1, 2, 3]);
+
+    // the baselined migrations were recorded but never executed
+    let (tables,): (i64,) = sqlx::query_as(
+        "SELECT count(*) FROM sqlite_master WHERE name IN ('one', 'two', 'three')",
+    )
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(tables, 0);
+
+    // a subsequent run only applies version 4
+    migrator.run(&pool).await?;
+
+    let (tables,): (i64,) = sqlx::query_as(
+        "SELECT count(*) FROM sqlite_master WHERE name IN ('one', 'two', 'three', 'four')",
+    )
+    .fetch_one(&pool)
+    .await?;
+    assert_eq!(tables, 1);
+
+    // baselining again is a no-op
+    assert!(migrator.baseline(&pool, 3).await?.is_empty());
+
+    pool.close().await;
+    let _ = std::fs::remove_dir_all(&dir);
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlite")]
+#[sqlx_macros::test]
+async fn repeatable_migrations_rerun_on_content_change() -> anyhow::Result<()> {
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    let dir = std::env::temp_dir().join(format!("sqlx-repeatable-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+
+    std::fs::write(dir.join("1_one.sql"), "CREATE TABLE runs (name TEXT);")?;
+    std::fs::write(dir.join("R__seed.sql"), "INSERT INTO runs VALUES ('seed');")?;
+    std::fs::write(dir.join("R__views.sql"), "INSERT INTO runs VALUES ('views');")?;
+
+    let pool = SqlitePoolOptions::new()
+        .min_connections(1)
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await?;
+
+    Migrator::new(dir.clone()).await?.run(&pool).await?;
+
+    // repeatable migrations ran once each, after the versioned set, in description order
+    let runs: Vec<(String,)> = sqlx::query_as("SELECT name FROM runs ORDER BY rowid")
+        .fetch_all(&pool)
+        .await?;
+    assert_eq!(runs, vec![("seed".to_owned(),), ("views".to_owned(),)]);
+
+    // unchanged repeatable migrations are skipped on a re-run
+    Migrator::new(dir.clone()).await?.run(&pool).await?;
+
+    let (count,): (i64,) = sqlx::query_as("SELECT count(*) FROM runs")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(count, 2);
+
+    // editing one causes only that one to re-run
+    std::fs::write(
+        dir.join("R__views.sql"),
+        "-- v2\nINSERT INTO runs VALUES ('views');",
+    )?;
+
+    let migrator = Migrator::new(dir.clone()).await?;
+
+    let pending: Vec<_> = migrator
+        .dry_run(&pool)
+        .await?
+        .iter()
+        .map(|m| m.description.clone())
+        .collect();
+    assert_eq!(pending, vec!["views"]);
+
+    migrator.run(&pool).await?;
+
+    let names: Vec<(String, i64)> =
+        sqlx::query_as("SELECT name, count(*) FROM runs GROUP BY name ORDER BY name")
+            .fetch_all(&pool)
+            .await?;
+    assert_eq!(
+        names,
+        vec![("seed".to_owned(), 1), ("views".to_owned(), 2)]
+    );
+
+    pool.close().await;
+    let _ = std::fs::remove_dir_all(&dir);
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlite")]
+#[sqlx_macros::test]
+async fn lock_timeout_does_not_affect_an_uncontended_run() -> anyhow::Result<()> {
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    let dir = std::env::temp_dir().join(format!("sqlx-lock-timeout-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+
+    std::fs::write(dir.join("1_one.sql"), "CREATE TABLE one (id INTEGER);")?;
+
+    let mut migrator = Migrator::new(dir.clone()).await?;
+    migrator.lock_timeout(std::time::Duration::from_secs(5));
+
+    let pool = SqlitePoolOptions::new()
+        .min_connections(1)
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await?;
+
+    // SQLite takes no explicit migration lock, so the timeout is a no-op here; the
+    // contended path (erroring with MigrateError::LockTimeout behind a held lock) is
+    // driver-specific behavior on Postgres/MySQL
+    migrator.run(&pool).await?;
+
+    pool.close().await;
+    let _ = std::fs::remove_dir_all(&dir);
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlite")]
+#[sqlx_macros::test]
+async fn status_reflects_applied_and_pending_migrations() -> anyhow::Result<()> {
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    let dir = std::env::temp_dir().join(format!("sqlx-status-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+
+    std::fs::write(dir.join("1_one.sql"), "CREATE TABLE one (id INTEGER);")?;
+    std::fs::write(dir.join("2_two.sql"), "CREATE TABLE two (id INTEGER);")?;
+
+    let pool = SqlitePoolOptions::new()
+        .min_connections(1)
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await?;
+
+    Migrator::new(dir.clone()).await?.run_to(&pool, 1).await?;
+
+    // version 3 is pending; version 1 is applied; version 2 is applied below
+    std::fs::write(dir.join("3_three.sql"), "CREATE TABLE three (id INTEGER);")?;
+
+    let migrator = Migrator::new(dir.clone()).await?;
+    migrator.run_to(&pool, 2).await?;
+
+    let statuses = migrator.status(&pool).await?;
+    assert_eq!(
+        statuses.iter().map(|s| s.version).collect::<Vec<_>>(),
+        vec![1, 2, 3]
+    );
+
+    for status in &statuses[..2] {
+        assert!(status.applied);
+        assert!(!status.missing);
+        assert!(status.applied_at.as_deref().unwrap().starts_with("20"));
+        assert!(status.execution_time.unwrap() > std::time::Duration::ZERO);
+    }
+
+    assert_eq!(statuses[0].description, "one");
+    assert!(!statuses[2].applied);
+    assert_eq!(statuses[2].applied_at, None);
+
+    // an applied migration that disappears from the source is flagged
+    std::fs::remove_file(dir.join("2_two.sql"))?;
+
+    let statuses = Migrator::new(dir.clone()).await?.status(&pool).await?;
+    assert_eq!(statuses[1].version, 2);
+    assert!(statuses[1].applied && statuses[1].missing);
+    assert_eq!(statuses[1].description, "two");
+
+    pool.close().await;
+    let _ = std::fs::remove_dir_all(&dir);
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlite")]
+#[sqlx_macros::test]
+async fn run_to_stops_at_the_target_version() -> anyhow::Result<()> {
+    use sqlx::migrate::MigrateError;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    let dir = std::env::temp_dir().join(format!("sqlx-run-to-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+
+    for (version, name) in [(1, "one"), (2, "two"), (3, "three"), (4, "four")].iter() {
+        std::fs::write(
+            dir.join(format!("{}_{}.sql", version, name)),
+            format!("CREATE TABLE {} (id INTEGER);", name),
+        )?;
+    }
+
+    let migrator = Migrator::new(dir.clone()).await?;
+
+    let pool = SqlitePoolOptions::new()
+        .min_connections(1)
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await?;
+
+    // an unknown target errors before anything is applied
+    let res = migrator.run_to(&pool, 7).await;
+    assert!(matches!(res, Err(MigrateError::VersionNotPresent(7))));
+
+    migrator.run_to(&pool, 2).await?;
+
+    let versions: Vec<(i64,)> =
+        sqlx::query_as("SELECT version FROM _sqlx_migrations ORDER BY version")
+            .fetch_all(&pool)
+            .await?;
+    assert_eq!(versions, vec![(1,), (2,)]);
+
+    // versions 3 and 4 are still pending
+    let pending: Vec<_> = migrator
+        .dry_run(&pool)
+        .await?
+        .iter()
+        .map(|m| m.version)
+        .collect();
+    assert_eq!(pending, vec![3, 4]);
+
+    // a full run picks up where run_to stopped
+    migrator.run(&pool).await?;
+    assert!(migrator.dry_run(&pool).await?.is_empty());
+
+    pool.close().await;
+    let _ = std::fs::remove_dir_all(&dir);
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlite")]
+#[sqlx_macros::test]
+async fn hooks_fire_around_each_applied_migration() -> anyhow::Result<()> {
+    use sqlx::migrate::MigrateError;
+    use sqlx::sqlite::SqlitePoolOptions;
+    use std::sync::{Arc, Mutex};
+
+    let dir = std::env::temp_dir().join(format!("sqlx-hooks-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+
+    std::fs::write(dir.join("1_one.sql"), "CREATE TABLE one (id INTEGER);")?;
+    std::fs::write(dir.join("2_two.sql"), "CREATE TABLE two (id INTEGER);")?;
+
+    let pool = SqlitePoolOptions::new()
+        .min_connections(1)
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await?;
+
+    let events: Arc<Mutex<Vec<(&str, i64)>>> = Arc::new(Mutex::new(Vec::new()));
+
+    let mut migrator = Migrator::new(dir.clone()).await?;
+
+    let ev = Arc::clone(&events);
+    migrator.before_each(move |migration| {
+        let ev = Arc::clone(&ev);
+        let version = migration.version;
+        Box::pin(async move {
+            ev.lock().unwrap().push(("before", version));
+            Ok(())
+        })
+    });
+
+    let ev = Arc::clone(&events);
+    migrator.after_each(move |migration, elapsed| {
+        let ev = Arc::clone(&ev);
+        let version = migration.version;
+        assert!(elapsed > std::time::Duration::ZERO);
+        Box::pin(async move {
+            ev.lock().unwrap().push(("after", version));
+            Ok(())
+        })
+    });
+
+    migrator.run(&pool).await?;
+
+    assert_eq!(
+        *events.lock().unwrap(),
+        vec![("before", 1), ("after", 1), ("before", 2), ("after", 2)]
+    );
+
+    // hooks only fire for migrations that are actually applied
+    migrator.run(&pool).await?;
+    assert_eq!(events.lock().unwrap().len(), 4);
+
+    pool.close().await;
+
+    // a failing before_each aborts that migration before its SQL runs
+    let pool = SqlitePoolOptions::new()
+        .min_connections(1)
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await?;
+
+    let mut migrator = Migrator::new(dir.clone()).await?;
+    migrator.before_each(|migration| {
+        let version = migration.version;
+        Box::pin(async move {
+            if version == 2 {
+                Err(MigrateError::Source("gated".into()))
+            } else {
+                Ok(())
+            }
+        })
+    });
+
+    assert!(migrator.run(&pool).await.is_err());
+
+    let (applied,): (i64,) = sqlx::query_as("SELECT count(*) FROM _sqlx_migrations")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(applied, 1);
+
+    pool.close().await;
+    let _ = std::fs::remove_dir_all(&dir);
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlite")]
+#[sqlx_macros::test]
+async fn no_transaction_directive_skips_the_wrapper() -> anyhow::Result<()> {
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    let dir = std::env::temp_dir().join(format!("sqlx-no-tx-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+
+    // VACUUM cannot run inside a transaction
+    std::fs::write(dir.join("1_vacuum.sql"), "VACUUM;")?;
+
+    let pool = SqlitePoolOptions::new()
+        .min_connections(1)
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await?;
+
+    let res = Migrator::new(dir.clone()).await?.run(&pool).await;
+    assert!(res.is_err());
+
+    // marked with the directive, the same migration runs outside of a transaction
+    std::fs::write(dir.join("1_vacuum.sql"), "-- sqlx:no-transaction\nVACUUM;")?;
+
+    let migrator = Migrator::new(dir.clone()).await?;
+    assert!(migrator.migrations[0].no_tx);
+
+    migrator.run(&pool).await?;
+
+    let (applied,): (i64,) = sqlx::query_as("SELECT count(*) FROM _sqlx_migrations")
+        .fetch_one(&pool)
+        .await?;
+    assert_eq!(applied, 1);
+
+    pool.close().await;
+    let _ = std::fs::remove_dir_all(&dir);
+
+    Ok(())
+}
+
+#[cfg(feature = "sqlite")]
+#[sqlx_macros::test]
+async fn applies_out_of_order_migrations() -> anyhow::Result<()> {
+    use sqlx::migrate::MigrateError;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    let dir = std::env::temp_dir().join(format!("sqlx-out-of-order-{}", std::process::id()));
+    std::fs::create_dir_all(&dir)?;
+
+    std::fs::write(dir.join("1_one.sql"), "CREATE TABLE one (id INTEGER);")?;
+    std::fs::write(dir.join("3_three.sql"), "CREATE TABLE three (id INTEGER);")?;
+
+    let pool = SqlitePoolOptions::new()
+        .min_connections(1)
+        .max_connections(1)
+        .idle_timeout(None)
+        .max_lifetime(None)
+        .connect("sqlite::memory:")
+        .await?;
+
+    Migrator::new(dir.clone()).await?.run(&pool).await?;
+
+    // version 2 merges late, after 3 is already applied
+    std::fs::write(dir.join("2_two.sql"), "CREATE TABLE two (id INTEGER);")?;
+
+    Migrator::new(dir.clone()).await?.run(&pool).await?;
+
+    let versions: Vec<(i64,)> =
+        sqlx::query_as("SELECT version FROM _sqlx_migrations ORDER BY version")
+            .fetch_all(&pool)
+            .await?;
+    assert_eq!(versions, vec![(1,), (2,), (3,)]);
+
+    // modifying an already-applied migration is still detected
+    std::fs::write(dir.join("2_two.sql"), "CREATE TABLE two (id INTEGER, x);")?;
+
+    let res = Migrator::new(dir.clone()).await?.run(&pool).await;
+    assert!(matches!(res, Err(MigrateError::VersionMismatch(2))));
+
+    pool.close().await;
+    let _ = std::fs::remove_dir_all(&dir);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn multiple_directories_merge_in_version_order() -> anyhow::Result<()> {
+    let runtime = Migrator::new(vec![
+        std::path::PathBuf::from("tests/migrate/migrations_billing"),
+        std::path::PathBuf::from("tests/migrate/migrations"),
+    ])
+    .await?;
+
+    assert_eq!(
+        runtime.migrations.len(),
+        EMBEDDED.migrations.len() + 1,
+        "expected the billing migration to be merged in"
+    );
+
+    // merged output is ordered by version regardless of source directory order
+    let versions: Vec<_> = runtime.iter().map(|m| m.version).collect();
+    let mut sorted = versions.clone();
+    sorted.sort_unstable();
+    assert_eq!(versions, sorted);
+
+    for (e, r) in EMBEDDED_MULTI.iter().zip(runtime.iter()) {
+        assert_eq!(e.version, r.version);
+        assert_eq!(e.description, r.description);
+        assert_eq!(e.sql, r.sql);
+        assert_eq!(e.checksum, r.checksum);
+    }
+
+    // the same version in two directories is a collision
+    let res = Migrator::new(vec![
+        std::path::PathBuf::from("tests/migrate/migrations"),
+        std::path::PathBuf::from("tests/migrate/migrations"),
+    ])
+    .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn same_output() -> anyhow::Result<()> {
     let runtime = Migrator::new(Path::new("tests/migrate/migrations")).await?;
@@ -18,3 +775,9 @@ async fn same_output() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[test]
+fn migrate_check_accepts_valid_directories() {
+    sqlx::migrate_check!("tests/migrate/migrations");
+    sqlx::migrate_check!(["tests/migrate/migrations", "tests/migrate/migrations_billing"]);
+}
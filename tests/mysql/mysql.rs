@@ -43,6 +43,38 @@ async fn it_can_fail_at_querying() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_classifies_constraint_violations() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE violations (id INTEGER UNIQUE, val INTEGER NOT NULL)")
+        .await?;
+
+    conn.execute("INSERT INTO violations VALUES (1, 1)").await?;
+
+    let res: Result<_, sqlx::Error> = sqlx::query("INSERT INTO violations VALUES (1, 2)")
+        .execute(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().into_database_error().unwrap();
+
+    assert!(err.is_unique_violation());
+    assert!(!err.is_foreign_key_violation());
+    assert!(!err.is_check_violation());
+    assert!(!err.is_not_null_violation());
+
+    let res: Result<_, sqlx::Error> = sqlx::query("INSERT INTO violations VALUES (2, NULL)")
+        .execute(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().into_database_error().unwrap();
+
+    assert!(err.is_not_null_violation());
+    assert!(!err.is_unique_violation());
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_executes() -> anyhow::Result<()> {
     let mut conn = new::<MySql>().await?;
@@ -446,3 +478,98 @@ async fn it_can_work_with_transactions() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_reconnects_after_connection_lost() -> anyhow::Result<()> {
+    use sqlx::mysql::MySqlConnectOptions;
+    use std::str::FromStr;
+
+    let options = MySqlConnectOptions::from_str(&dotenv::var("DATABASE_URL")?)?.reconnect(true);
+    let mut conn = MySqlConnection::connect_with(&options).await?;
+
+    // kill our own connection out from under us
+    let _ = conn.execute("KILL CONNECTION_ID()").await;
+
+    // a simple query is transparently retried on a fresh connection
+    let value: i32 = sqlx::query_scalar("SELECT 40 + 2").fetch_one(&mut conn).await?;
+    assert_eq!(value, 42);
+
+    // ... but inside a transaction the error must surface
+    let mut tx = conn.begin().await?;
+    let _ = tx.execute("KILL CONNECTION_ID()").await;
+
+    let res = sqlx::query_scalar::<_, i32>("SELECT 1").fetch_one(&mut tx).await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reports_the_first_id_of_a_multi_row_insert() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    conn.execute(
+        r#"
+CREATE TEMPORARY TABLE heroes (
+    id INT PRIMARY KEY AUTO_INCREMENT,
+    name TEXT NOT NULL
+);
+    "#,
+    )
+    .await?;
+
+    let done = sqlx::query("INSERT INTO heroes ( name ) VALUES ( 'a' ), ( 'b' ), ( 'c' )")
+        .execute(&mut conn)
+        .await?;
+
+    // `last_insert_id` is the id of the *first* row of the batch
+    assert_eq!(done.last_insert_id(), 1);
+    assert_eq!(done.rows_affected(), 3);
+
+    let done = sqlx::query("INSERT INTO heroes ( name ) VALUES ( 'd' ), ( 'e' )")
+        .execute(&mut conn)
+        .await?;
+
+    assert_eq!(done.last_insert_id(), 4);
+    assert_eq!(done.rows_affected(), 2);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_reuse_a_prepared_statement_after_an_error() -> anyhow::Result<()> {
+    let mut conn = new::<MySql>().await?;
+
+    let sql = "SELECT CAST(JSON_EXTRACT(?, '$.a') AS SIGNED)";
+
+    // executing with an argument that is not valid JSON errors at execute time
+    let res = sqlx::query_scalar::<_, i64>(sql)
+        .bind("not json")
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    // the statement is reset (COM_STMT_RESET) before it is executed again
+    let value: i64 = sqlx::query_scalar(sql)
+        .bind(r#"{"a": 42}"#)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 42);
+
+    // an explicit reset of a cached statement is a no-op on a clean statement
+    conn.reset_statement(sql).await?;
+
+    let value: i64 = sqlx::query_scalar(sql)
+        .bind(r#"{"a": 7}"#)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(value, 7);
+
+    // resetting a statement that was never prepared does nothing
+    conn.reset_statement("SELECT 'not prepared'").await?;
+
+    Ok(())
+}
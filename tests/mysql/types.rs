@@ -28,6 +28,11 @@ test_type!(f64(MySql, "3.14159265e0" == 3.14159265_f64));
 //       away from FLOATs.
 test_type!(f32(MySql, "3.1410000324249268e0" == 3.141f32 as f64 as f32));
 
+// filesystem paths round-trip through TEXT via a lossy UTF-8 conversion
+test_type!(path_buf<std::path::PathBuf>(MySql,
+    "'/var/data/file.csv'" == std::path::PathBuf::from("/var/data/file.csv"),
+));
+
 test_type!(string<String>(MySql,
     "'helloworld'" == "helloworld",
     "''" == ""
@@ -42,6 +47,19 @@ test_type!(bytes<Vec<u8>>(MySql,
         == vec![0_u8, 0, 0, 0, 0x52]
 ));
 
+test_type!(ipaddr<std::net::IpAddr>(MySql,
+    "'127.0.0.1'" == "127.0.0.1".parse::<std::net::IpAddr>().unwrap(),
+    "'2001:db8::1'" == "2001:db8::1".parse::<std::net::IpAddr>().unwrap(),
+));
+
+test_type!(ipv4addr<std::net::Ipv4Addr>(MySql,
+    "'127.0.0.1'" == "127.0.0.1".parse::<std::net::Ipv4Addr>().unwrap(),
+));
+
+test_type!(ipv6addr<std::net::Ipv6Addr>(MySql,
+    "'2001:db8::1'" == "2001:db8::1".parse::<std::net::Ipv6Addr>().unwrap(),
+));
+
 #[cfg(feature = "uuid")]
 test_type!(uuid<sqlx::types::Uuid>(MySql,
     "x'b731678f636f4135bc6f19440c13bd19'"
@@ -88,6 +106,30 @@ mod chrono {
             )
     ));
 
+
+    #[sqlx_macros::test]
+    async fn test_type_chrono_datetime6_round_trip() -> anyhow::Result<()> {
+        let mut conn = sqlx_test::new::<MySql>().await?;
+
+        conn.execute("CREATE TEMPORARY TABLE with_micros (dt DATETIME(6) NOT NULL)")
+            .await?;
+
+        let value = NaiveDate::from_ymd(2021, 6, 7).and_hms_micro(14, 30, 15, 123456);
+
+        sqlx::query("INSERT INTO with_micros (dt) VALUES (?)")
+            .bind(value)
+            .execute(&mut conn)
+            .await?;
+
+        let fetched: NaiveDateTime = sqlx::query_scalar("SELECT dt FROM with_micros")
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(fetched, value);
+
+        Ok(())
+    }
+
     #[sqlx_macros::test]
     async fn test_type_chrono_zero_date() -> anyhow::Result<()> {
         let mut conn = sqlx_test::new::<MySql>().await?;
@@ -164,6 +206,78 @@ mod time_tests {
                 .assume_utc()
     ));
 
+
+    #[sqlx_macros::test]
+    async fn test_type_timestamp_in_non_utc_session() -> anyhow::Result<()> {
+        use sqlx::Executor;
+
+        let mut conn = sqlx_test::new::<MySql>().await?;
+
+        conn.execute("SET time_zone = '+02:00'").await?;
+
+        conn.execute("CREATE TEMPORARY TABLE events (at TIMESTAMP(6) NOT NULL)")
+            .await?;
+
+        let value = date!(2021 - 6 - 7).with_time(time!(14:30:15.123456)).assume_utc();
+
+        sqlx::query("INSERT INTO events (at) VALUES (?)")
+            .bind(value)
+            .execute(&mut conn)
+            .await?;
+
+        // `OffsetDateTime` assumes a UTC session; within a single session any fixed
+        // `time_zone` round-trips because encode and decode shift symmetrically
+        let fetched: OffsetDateTime = sqlx::query_scalar("SELECT at FROM events")
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(fetched, value);
+
+        // `DATETIME` is naive and unaffected by the session time zone
+        conn.execute("CREATE TEMPORARY TABLE naive_events (at DATETIME(6) NOT NULL)")
+            .await?;
+
+        let naive = date!(2021 - 6 - 7).with_time(time!(14:30:15.123456));
+
+        sqlx::query("INSERT INTO naive_events (at) VALUES (?)")
+            .bind(naive)
+            .execute(&mut conn)
+            .await?;
+
+        conn.execute("SET time_zone = '+08:00'").await?;
+
+        let fetched: PrimitiveDateTime = sqlx::query_scalar("SELECT at FROM naive_events")
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(fetched, naive);
+
+        Ok(())
+    }
+
+    #[sqlx_macros::test]
+    async fn test_type_time_datetime6_round_trip() -> anyhow::Result<()> {
+        let mut conn = sqlx_test::new::<MySql>().await?;
+
+        conn.execute("CREATE TEMPORARY TABLE with_micros (dt DATETIME(6) NOT NULL)")
+            .await?;
+
+        let value = date!(2021 - 6 - 7).with_time(time!(14:30:15.123456));
+
+        sqlx::query("INSERT INTO with_micros (dt) VALUES (?)")
+            .bind(value)
+            .execute(&mut conn)
+            .await?;
+
+        let fetched: PrimitiveDateTime = sqlx::query_scalar("SELECT dt FROM with_micros")
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(fetched, value);
+
+        Ok(())
+    }
+
     #[sqlx_macros::test]
     async fn test_type_time_zero_date() -> anyhow::Result<()> {
         let mut conn = sqlx_test::new::<MySql>().await?;
@@ -93,3 +93,23 @@ async fn it_describes_composite() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[cfg(feature = "any")]
+#[sqlx_macros::test]
+async fn it_carries_parameter_types_into_any_statement() -> anyhow::Result<()> {
+    use sqlx::any::AnyStatement;
+    use sqlx::Statement;
+
+    let mut conn = new::<Postgres>().await?;
+
+    let statement = conn.prepare("SELECT $1::int8, $2::text").await?;
+
+    let any: AnyStatement<'_> = statement.into();
+    let parameters = any.parameters().unwrap().unwrap_left();
+
+    assert_eq!(parameters.len(), 2);
+    assert_eq!(parameters[0].name(), "INT8");
+    assert_eq!(parameters[1].name(), "TEXT");
+
+    Ok(())
+}
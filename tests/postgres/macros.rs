@@ -346,6 +346,26 @@ async fn test_many_args() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn test_bind_array_to_any() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    let ids = vec![1i64, 3, 5];
+
+    let rows = sqlx::query!(
+        "SELECT id from (VALUES (1::bigint), (2), (3)) accounts(id) where id = ANY($1::bigint[])",
+        &ids[..]
+    )
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].id, Some(1));
+    assert_eq!(rows[1].id, Some(3));
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn test_array_from_slice() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -119,6 +119,50 @@ async fn it_can_inspect_constraint_errors() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_classifies_constraint_violations() -> anyhow::Result<()> {
+    let mut conn = new::<Postgres>().await?;
+
+    conn.execute(
+        "CREATE TEMPORARY TABLE violations (id INTEGER UNIQUE, val INTEGER NOT NULL CHECK (val > 0))",
+    )
+    .await?;
+
+    conn.execute("INSERT INTO violations VALUES (1, 1)").await?;
+
+    let res: Result<_, sqlx::Error> = sqlx::query("INSERT INTO violations VALUES (1, 2)")
+        .execute(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().into_database_error().unwrap();
+
+    assert!(err.is_unique_violation());
+    assert!(!err.is_foreign_key_violation());
+    assert!(!err.is_check_violation());
+    assert!(!err.is_not_null_violation());
+    assert_eq!(err.constraint(), Some("violations_id_key"));
+
+    let res: Result<_, sqlx::Error> = sqlx::query("INSERT INTO violations VALUES (2, NULL)")
+        .execute(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().into_database_error().unwrap();
+
+    assert!(err.is_not_null_violation());
+    assert!(!err.is_unique_violation());
+
+    let res: Result<_, sqlx::Error> = sqlx::query("INSERT INTO violations VALUES (2, -1)")
+        .execute(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().into_database_error().unwrap();
+
+    assert!(err.is_check_violation());
+    assert!(!err.is_unique_violation());
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_executes() -> anyhow::Result<()> {
     let mut conn = new::<Postgres>().await?;
@@ -1018,7 +1062,7 @@ CREATE TABLE heating_bills (
             &self,
             buf: &mut sqlx::postgres::PgArgumentBuffer,
         ) -> sqlx::encode::IsNull {
-            self.0.encode(buf)
+            <i16 as sqlx::Encode<'q, Postgres>>::encode(self.0, buf)
         }
     }
 
@@ -1445,3 +1489,245 @@ CREATE TABLE issue_1254 (id INT4 PRIMARY KEY, pairs PAIR[]);
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn test_listener_listen_all() -> anyhow::Result<()> {
+    use sqlx::postgres::PgListener;
+
+    let mut notify_conn = new::<Postgres>().await?;
+
+    let mut listener = PgListener::connect(&env::var("DATABASE_URL")?).await?;
+
+    // subscribe to all three channels with a single batched `LISTEN`
+    listener
+        .listen_all(vec!["test_chan.one", "test_chan.two", "test_chan.three"])
+        .await?;
+
+    for channel in ["test_chan.one", "test_chan.two", "test_chan.three"] {
+        notify_conn
+            .execute(&*format!(r#"NOTIFY "{}", 'payload'"#, channel))
+            .await?;
+
+        let notification = listener.recv().await?;
+
+        assert_eq!(notification.channel(), channel);
+        assert_eq!(notification.payload(), "payload");
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_listener_into_stream_filtered() -> anyhow::Result<()> {
+    use futures::StreamExt;
+    use sqlx::postgres::PgListener;
+
+    let mut notify_conn = new::<Postgres>().await?;
+
+    let mut listener = PgListener::connect(&env::var("DATABASE_URL")?).await?;
+    listener.listen_all(vec!["wanted", "unwanted"]).await?;
+
+    let mut stream = listener.into_stream_filtered(|channel, _payload| channel == "wanted");
+
+    // interleave notifications; the unwanted ones must be drained without stalling the stream
+    for i in 0..3 {
+        notify_conn
+            .execute(&*format!("NOTIFY unwanted, 'skip {0}'; NOTIFY wanted, 'take {0}'", i))
+            .await?;
+
+        let notification = stream.next().await.unwrap()?;
+
+        assert_eq!(notification.channel(), "wanted");
+        assert_eq!(notification.payload(), format!("take {}", i));
+    }
+
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+#[sqlx_macros::test]
+async fn it_estimates_rows() -> anyhow::Result<()> {
+    use sqlx::postgres::PgArguments;
+    use sqlx::Arguments;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // the planner knows the cardinality of `generate_series` exactly
+    let estimate = conn
+        .estimate_rows("SELECT * FROM generate_series(1, 1000)", PgArguments::default())
+        .await?;
+
+    assert!(
+        (500..=2000).contains(&estimate),
+        "estimate {} not within a reasonable factor of 1000",
+        estimate
+    );
+
+    let mut args = PgArguments::default();
+    args.add(250_i32);
+
+    let estimate = conn
+        .estimate_rows("SELECT * FROM generate_series(1, $1::int4)", args)
+        .await?;
+
+    assert!(estimate > 0);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_can_copy_in_with_options() -> anyhow::Result<()> {
+    use sqlx::postgres::PgCopyOptions;
+
+    let mut conn = new::<Postgres>().await?;
+    conn.execute(
+        r#"
+        CREATE TEMPORARY TABLE copy_opts (id INTEGER NOT NULL, name TEXT);
+    "#,
+    )
+    .await?;
+
+    // pipe-delimited, with the empty string as the NULL token
+    let mut copy = conn
+        .copy_in_raw_with(
+            "COPY copy_opts (id, name) FROM STDIN",
+            PgCopyOptions::new().delimiter('|').null(""),
+        )
+        .await?;
+
+    copy.send("1|one\n2|\n".as_bytes()).await?;
+    let rows = copy.finish().await?;
+    assert_eq!(rows, 2);
+
+    let row = sqlx::query("SELECT name FROM copy_opts WHERE id = 2")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(row.try_get::<Option<String>, _>(0)?, None);
+
+    // a multi-byte delimiter is rejected before the COPY starts
+    let res = conn
+        .copy_in_raw_with(
+            "COPY copy_opts (id, name) FROM STDIN",
+            PgCopyOptions::new().delimiter('é'),
+        )
+        .await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_streams_a_large_object_out_in_chunks() -> anyhow::Result<()> {
+    use futures::TryStreamExt;
+
+    let mut conn = new::<Postgres>().await?;
+    let mut tx = conn.begin().await?;
+
+    // 1 MiB of a repeating pattern, larger than a single read chunk
+    let expected: Vec<u8> = (0..1024 * 1024).map(|i| (i % 251) as u8).collect();
+
+    let oid: u32 = sqlx::query_scalar("SELECT lo_from_bytea(0, $1)")
+        .bind(&expected)
+        .fetch_one(&mut tx)
+        .await?;
+
+    let mut stream = tx.read_large_object(oid).await?;
+
+    let mut actual = Vec::new();
+    let mut chunks = 0;
+
+    while let Some(chunk) = stream.try_next().await? {
+        actual.extend_from_slice(&chunk);
+        chunks += 1;
+    }
+
+    drop(stream);
+
+    assert_eq!(actual, expected);
+    assert!(chunks > 1, "expected more than one chunk, got {}", chunks);
+
+    sqlx::query("SELECT lo_unlink($1)")
+        .bind(oid)
+        .execute(&mut tx)
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_writes_a_large_object_from_an_async_reader() -> anyhow::Result<()> {
+    use futures::TryStreamExt;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // 3 MiB, larger than a single write chunk
+    let expected: Vec<u8> = (0..3 * 1024 * 1024).map(|i| (i % 239) as u8).collect();
+
+    let oid = conn.write_large_object(&expected[..]).await?;
+
+    let mut tx = conn.begin().await?;
+    let mut stream = tx.read_large_object(oid).await?;
+
+    let mut actual = Vec::new();
+
+    while let Some(chunk) = stream.try_next().await? {
+        actual.extend_from_slice(&chunk);
+    }
+
+    drop(stream);
+
+    assert_eq!(actual, expected);
+
+    sqlx::query("SELECT lo_unlink($1)")
+        .bind(oid)
+        .execute(&mut tx)
+        .await?;
+
+    tx.commit().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fetches_a_large_result_in_bounded_batches() -> anyhow::Result<()> {
+    use futures::TryStreamExt;
+
+    let mut conn = new::<Postgres>().await?;
+
+    // ask for at most 32 rows per protocol-level Execute
+    conn.set_fetch_buffer_size(32);
+
+    let mut stream = sqlx::query("SELECT generate_series(1, 10000)").fetch(&mut conn);
+
+    let mut expected = 1_i32;
+
+    while let Some(row) = stream.try_next().await? {
+        let value: i32 = row.get(0);
+        assert_eq!(value, expected);
+        expected += 1;
+    }
+
+    assert_eq!(expected, 10_001);
+
+    drop(stream);
+
+    // dropping a buffered stream mid-portal leaves the connection usable
+    let mut stream = sqlx::query("SELECT generate_series(1, 10000)").fetch(&mut conn);
+    let _ = stream.try_next().await?;
+    drop(stream);
+
+    let one: i32 = sqlx::query_scalar("SELECT 1").fetch_one(&mut conn).await?;
+    assert_eq!(one, 1);
+
+    // and the setting can be turned back off
+    conn.set_fetch_buffer_size(0);
+
+    let count: i64 = sqlx::query_scalar("SELECT count(*) FROM generate_series(1, 100)")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(count, 100);
+
+    Ok(())
+}
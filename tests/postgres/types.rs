@@ -50,6 +50,11 @@ test_type!(str<&str>(Postgres,
     "'more text'::varchar" == "more text",
 ));
 
+// filesystem paths round-trip through TEXT via a lossy UTF-8 conversion
+test_type!(path_buf<std::path::PathBuf>(Postgres,
+    "'/var/data/file.csv'" == std::path::PathBuf::from("/var/data/file.csv"),
+));
+
 test_type!(string<String>(Postgres,
     "'this is foo'" == format!("this is foo"),
 ));
@@ -92,6 +97,12 @@ test_type!(i32_vec<Vec<i32>>(Postgres,
     "'{1,3,-5}'::int[]" == vec![1_i32, 3, -5]
 ));
 
+// NULL elements round-trip through `Vec<Option<T>>`
+test_type!(i32_opt_vec<Vec<Option<i32>>>(Postgres,
+    "array[1,NULL,3]::int[]" == vec![Some(1_i32), None, Some(3)],
+    "array[NULL]::int[]" == vec![None::<i32>],
+));
+
 test_type!(i64(Postgres, "9358295312::bigint" == 9358295312_i64));
 
 test_type!(f32(Postgres, "9419.122::real" == 9419.122_f32));
@@ -139,6 +150,31 @@ test_type!(uuid_vec<Vec<sqlx::types::Uuid>>(Postgres,
         ]
 ));
 
+test_type!(ipaddr<std::net::IpAddr>(Postgres,
+    "'127.0.0.1'::inet"
+        == "127.0.0.1"
+            .parse::<std::net::IpAddr>()
+            .unwrap(),
+    "'2001:4f8:3:ba:2e0:81ff:fe22:d1f1'::inet"
+        == "2001:4f8:3:ba:2e0:81ff:fe22:d1f1"
+            .parse::<std::net::IpAddr>()
+            .unwrap(),
+));
+
+test_type!(ipv4addr<std::net::Ipv4Addr>(Postgres,
+    "'127.0.0.1'::inet"
+        == "127.0.0.1"
+            .parse::<std::net::Ipv4Addr>()
+            .unwrap(),
+));
+
+test_type!(ipv6addr<std::net::Ipv6Addr>(Postgres,
+    "'2001:4f8:3:ba:2e0:81ff:fe22:d1f1'::inet"
+        == "2001:4f8:3:ba:2e0:81ff:fe22:d1f1"
+            .parse::<std::net::Ipv6Addr>()
+            .unwrap(),
+));
+
 #[cfg(feature = "ipnetwork")]
 test_type!(ipnetwork<sqlx::types::ipnetwork::IpNetwork>(Postgres,
     "'127.0.0.1'::inet"
@@ -167,6 +203,29 @@ test_type!(ipnetwork<sqlx::types::ipnetwork::IpNetwork>(Postgres,
             .unwrap(),
 ));
 
+test_type!(macaddr8<sqlx::postgres::types::PgMacAddr8>(Postgres,
+    "'08:00:2b:01:02:03:04:05'::macaddr8"
+        == "08:00:2b:01:02:03:04:05"
+            .parse::<sqlx::postgres::types::PgMacAddr8>()
+            .unwrap(),
+));
+
+#[cfg(feature = "ipnetwork")]
+test_type!(cidr<sqlx::postgres::types::PgCidr>(Postgres,
+    "'192.168.0.0/24'::cidr"
+        == sqlx::postgres::types::PgCidr(
+            "192.168.0.0/24"
+                .parse::<sqlx::types::ipnetwork::IpNetwork>()
+                .unwrap()
+        ),
+    "'2001:4f8:3:ba::/64'::cidr"
+        == sqlx::postgres::types::PgCidr(
+            "2001:4f8:3:ba::/64"
+                .parse::<sqlx::types::ipnetwork::IpNetwork>()
+                .unwrap()
+        ),
+));
+
 #[cfg(feature = "mac_address")]
 test_type!(mac_address<sqlx::types::mac_address::MacAddress>(Postgres,
     "'00:01:02:03:04:05'::macaddr"
@@ -255,7 +314,9 @@ mod chrono {
 
     test_type!(chrono_date_time_tz<DateTime::<FixedOffset>>(Postgres,
         "TIMESTAMPTZ '2019-01-02 05:10:20.115100+06:30'"
-            == FixedOffset::east(60 * 60 * 6 + 1800).ymd(2019, 1, 2).and_hms_micro(5, 10, 20, 115100)
+            == FixedOffset::east(60 * 60 * 6 + 1800).ymd(2019, 1, 2).and_hms_micro(5, 10, 20, 115100),
+        "TIMESTAMPTZ '2019-01-02 05:10:20.115100+05:30'"
+            == FixedOffset::east(60 * 60 * 5 + 1800).ymd(2019, 1, 2).and_hms_micro(5, 10, 20, 115100)
     ));
 
     test_type!(chrono_date_time_tz_vec<Vec<DateTime::<Utc>>>(Postgres,
@@ -359,6 +420,14 @@ mod json {
         "array['\"😎\"'::jsonb, '\"🙋‍♀️\"'::jsonb]::jsonb[]" == vec![json!("😎"), json!("🙋‍♀️")],
     ));
 
+    // each element of a `jsonb[]` carries its own version byte, which must be
+    // stripped per element for the objects to parse
+    test_type!(jsonb_object_array<Vec<JsonValue>>(
+        Postgres,
+        "array['{\"a\": 1}'::jsonb, '{\"b\": [true, null]}'::jsonb]::jsonb[]"
+            == vec![json!({"a": 1}), json!({"b": [true, null]})],
+    ));
+
     #[derive(serde::Deserialize, serde::Serialize, Debug, PartialEq)]
     struct Friend {
         name: String,
@@ -399,6 +468,12 @@ mod json {
 
         assert_eq!(value.get(), "{\"hello\": \"world\"}");
 
+        // `Box<RawValue>` owns the text so it can outlive the row
+        let value: Box<JsonRawValue> = row.try_get(0)?;
+        drop(row);
+
+        assert_eq!(value.get(), "{\"hello\": \"world\"}");
+
         Ok(())
     }
 }
@@ -423,6 +498,12 @@ test_type!(bigdecimal<sqlx::types::BigDecimal>(Postgres,
     "0.0123456789::numeric" == "0.0123456789".parse::<sqlx::types::BigDecimal>().unwrap(),
     "12.34::numeric" == "12.34".parse::<sqlx::types::BigDecimal>().unwrap(),
     "12345.6789::numeric" == "12345.6789".parse::<sqlx::types::BigDecimal>().unwrap(),
+
+    // 50 significant digits round-trip without loss
+    "12345678901234567890123456789012345678901234567890.98765::numeric"
+        == "12345678901234567890123456789012345678901234567890.98765"
+            .parse::<sqlx::types::BigDecimal>()
+            .unwrap(),
 ));
 
 #[cfg(feature = "bigdecimal")]
@@ -502,8 +583,383 @@ test_prepared_type!(interval<PgInterval>(
         },
 ));
 
+#[sqlx_macros::test]
+async fn test_interval_iso8601() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgIntervalIso8601;
+    use sqlx::Row;
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let row = sqlx::query("SELECT '1 year 2 mons 10 days 02:30:00'::interval")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let interval: PgIntervalIso8601 = row.try_get(0)?;
+
+    assert_eq!(interval.to_string(), "P1Y2M10DT2H30M");
+
+    // an ISO-8601 string parses back and encodes as the same interval
+    let row = sqlx::query("SELECT $1 = '1 year 2 mons 10 days 02:30:00'::interval")
+        .bind("P1Y2M10DT2H30M".parse::<PgIntervalIso8601>().unwrap())
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(row.try_get::<bool, _>(0)?);
+
+    Ok(())
+}
+
+test_prepared_type!(std_duration<std::time::Duration>(
+    Postgres,
+    "INTERVAL '90 seconds'" == std::time::Duration::from_secs(90),
+    "INTERVAL '03:10:20.116100'"
+        == std::time::Duration::from_micros((3 * 3_600 + 10 * 60 + 20) * 1_000_000 + 116100),
+));
+
+#[sqlx_macros::test]
+async fn test_std_duration_rejects_month_and_day() -> anyhow::Result<()> {
+    use sqlx::Row;
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    for interval in ["1 month", "1 day", "-1 second"] {
+        let row = sqlx::query("SELECT $1::text::interval")
+            .bind(interval)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert!(row.try_get::<std::time::Duration, _>(0).is_err());
+    }
+
+    Ok(())
+}
+
 test_prepared_type!(money<PgMoney>(Postgres, "123.45::money" == PgMoney(12345)));
 
+#[cfg(feature = "decimal")]
+#[sqlx_macros::test]
+async fn test_money_to_decimal_round_trip() -> anyhow::Result<()> {
+    use sqlx::types::Decimal;
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let money: PgMoney = sqlx::query_scalar("SELECT 123.45::money")
+        .fetch_one(&mut conn)
+        .await?;
+
+    // the scale is determined by the database's `lc_monetary`; the test databases
+    // run with a locale where `frac_digits` is 2
+    assert_eq!(money.to_decimal(2), Decimal::new(12345, 2));
+
+    let same: bool = sqlx::query_scalar("SELECT $1 = 123.45::money")
+        .bind(PgMoney::from_decimal(Decimal::new(12345, 2), 2))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(same);
+
+    Ok(())
+}
+
 test_prepared_type!(money_vec<Vec<PgMoney>>(Postgres,
     "array[123.45,420.00,666.66]::money[]" == vec![PgMoney(12345), PgMoney(42000), PgMoney(66666)],
 ));
+
+#[sqlx_macros::test]
+async fn test_hstore_round_trip() -> anyhow::Result<()> {
+    use sqlx::Row;
+    use std::collections::HashMap;
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let mut map = HashMap::new();
+    map.insert("key".to_string(), Some("value".to_string()));
+    map.insert("missing".to_string(), None);
+
+    let row = sqlx::query("SELECT $1::hstore")
+        .bind(&map)
+        .fetch_one(&mut conn)
+        .await?;
+
+    let returned: HashMap<String, Option<String>> = row.try_get(0)?;
+
+    assert_eq!(map, returned);
+
+    Ok(())
+}
+
+#[cfg(feature = "geo-types")]
+mod geometry {
+    use super::*;
+    use sqlx::postgres::types::PgGeometry;
+    use sqlx::types::geo_types::Point;
+
+    test_prepared_type!(geometry_point<PgGeometry<Point<f64>>>(Postgres,
+        "'SRID=4326;POINT(-71.1 42.3)'::geometry"
+            == PgGeometry {
+                geometry: Point::new(-71.1, 42.3),
+                srid: 4326,
+            },
+        "'POINT(1 2)'::geometry"
+            == PgGeometry {
+                geometry: Point::new(1.0, 2.0),
+                srid: 0,
+            },
+    ));
+
+    // the bare geometry ignores the SRID on decode
+    test_decode_type!(bare_point<Point<f64>>(Postgres,
+        "'SRID=4326;POINT(-71.1 42.3)'::geometry" == Point::new(-71.1, 42.3),
+    ));
+}
+
+#[sqlx_macros::test]
+async fn test_anonymous_record() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgRecord;
+    use sqlx::Row;
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let row = sqlx::query("SELECT ROW(1, 'a', true)")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let record: PgRecord = row.try_get(0)?;
+
+    assert_eq!(record.len(), 3);
+    assert_eq!(record.try_decode::<i32>(0).unwrap(), 1);
+    assert_eq!(record.try_decode::<String>(1).unwrap(), "a");
+    assert!(record.try_decode::<bool>(2).unwrap());
+
+    // out-of-range and mismatched types are errors, not panics
+    assert!(record.try_decode::<i32>(3).is_err());
+    assert!(record.try_decode::<bool>(0).is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_encode_tuple_as_record() -> anyhow::Result<()> {
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    // a bound tuple compares equal to the matching row constructor
+    let matches: bool = sqlx::query_scalar("SELECT (1, 'a'::text, true) = $1")
+        .bind((1_i32, "a", true))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(matches);
+
+    let matches: bool = sqlx::query_scalar("SELECT (2, 'b'::text) = $1")
+        .bind((1_i32, "b"))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert!(!matches);
+
+    // usable as a composite filter: WHERE (a, b) = $1
+    let n: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM (VALUES (1, 'a'::text), (2, 'b')) v(a, b) WHERE (a, b) = $1",
+    )
+    .bind((2_i32, "b".to_string()))
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(n, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_citext() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgCiText;
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    sqlx::query("CREATE EXTENSION IF NOT EXISTS citext")
+        .execute(&mut conn)
+        .await?;
+
+    sqlx::query("CREATE TEMPORARY TABLE users_ci (email citext)")
+        .execute(&mut conn)
+        .await?;
+
+    sqlx::query("INSERT INTO users_ci (email) VALUES ('Hello@Example.COM')")
+        .execute(&mut conn)
+        .await?;
+
+    // a plain text parameter matches case-insensitively, and the stored
+    // value decodes into a `String` without a cast
+    let email: String = sqlx::query_scalar("SELECT email FROM users_ci WHERE email = $1")
+        .bind("hello@example.com")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(email, "Hello@Example.COM");
+
+    // the wrapper binds the parameter as `citext` itself
+    let email: PgCiText = sqlx::query_scalar("SELECT email FROM users_ci WHERE email = $1")
+        .bind(PgCiText("HELLO@example.com".to_owned()))
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(email.to_string(), "Hello@Example.COM");
+
+    // citext arrays round-trip
+    let expected = vec![
+        PgCiText("One".to_owned()),
+        PgCiText("TWO".to_owned()),
+    ];
+
+    let returned: Vec<PgCiText> = sqlx::query_scalar("SELECT $1")
+        .bind(&expected)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(returned, expected);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_fixed_size_byte_array() -> anyhow::Result<()> {
+    use sqlx::Row;
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let digest: [u8; 16] = [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
+        0xFF,
+    ];
+
+    let row = sqlx::query("SELECT $1::bytea")
+        .bind(digest)
+        .fetch_one(&mut conn)
+        .await?;
+
+    let returned: [u8; 16] = row.try_get(0)?;
+
+    assert_eq!(returned, digest);
+
+    // decoding a value of the wrong length is an error, not a truncation
+    let row = sqlx::query("SELECT '\\xDEADBEEF'::bytea")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let err = row.try_get::<[u8; 16], _>(0).unwrap_err();
+
+    assert!(
+        err.to_string().contains("mismatched lengths"),
+        "unexpected error: {}",
+        err
+    );
+
+    Ok(())
+}
+
+#[cfg(feature = "chrono")]
+#[sqlx_macros::test]
+async fn test_timestamp_infinity() -> anyhow::Result<()> {
+    use sqlx::postgres::types::PgTimestamp;
+    use sqlx::types::chrono::{DateTime, Utc};
+    use sqlx::Row;
+
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let row = sqlx::query("SELECT 'infinity'::timestamptz, '-infinity'::timestamptz, now()")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let pos: PgTimestamp<DateTime<Utc>> = row.try_get(0)?;
+    let neg: PgTimestamp<DateTime<Utc>> = row.try_get(1)?;
+    let now: PgTimestamp<DateTime<Utc>> = row.try_get(2)?;
+
+    assert_eq!(pos, PgTimestamp::Infinity);
+    assert_eq!(neg, PgTimestamp::NegInfinity);
+    assert!(matches!(now, PgTimestamp::Value(_)));
+    assert!(neg < now && now < pos);
+
+    // the sentinels encode back to the wire sentinels
+    let same: bool = sqlx::query_scalar(
+        "SELECT $1 = 'infinity'::timestamptz AND $2 = '-infinity'::timestamptz",
+    )
+    .bind(PgTimestamp::<DateTime<Utc>>::Infinity)
+    .bind(PgTimestamp::<DateTime<Utc>>::NegInfinity)
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert!(same);
+
+    // the plain mapping refuses the sentinel and points at the wrapper
+    let row = sqlx::query("SELECT 'infinity'::timestamptz")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let err = row.try_get::<DateTime<Utc>, _>(0).unwrap_err();
+
+    assert!(
+        err.to_string().contains("PgTimestamp"),
+        "unexpected error: {}",
+        err
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn test_decode_multidimensional_array() -> anyhow::Result<()> {
+    let mut conn = sqlx_test::new::<Postgres>().await?;
+
+    let matrix: Vec<Vec<i32>> = sqlx::query_scalar("SELECT '{{1,2,3},{4,5,6}}'::int[][]")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(matrix, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+    // round-trip: send the rows back and rebuild the array server-side
+    let eq: bool =
+        sqlx::query_scalar("SELECT ARRAY[$1::int[], $2::int[]] = '{{1,2,3},{4,5,6}}'::int[][]")
+            .bind(&matrix[0])
+            .bind(&matrix[1])
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert!(eq);
+
+    // a lower bound other than one changes the subscripts, not the elements
+    let matrix: Vec<Vec<i32>> = sqlx::query_scalar("SELECT '[0:1][5:7]={{1,2,3},{4,5,6}}'::int[][]")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(matrix, vec![vec![1, 2, 3], vec![4, 5, 6]]);
+
+    let v: Vec<i32> = sqlx::query_scalar("SELECT '[0:2]={7,8,9}'::int[]")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(v, vec![7, 8, 9]);
+
+    // three dimensions nest once more
+    let cube: Vec<Vec<Vec<i32>>> =
+        sqlx::query_scalar("SELECT '{{{1,2},{3,4}},{{5,6},{7,8}}}'::int[][][]")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(
+        cube,
+        vec![
+            vec![vec![1, 2], vec![3, 4]],
+            vec![vec![5, 6], vec![7, 8]]
+        ]
+    );
+
+    // decoding a multidimensional array into a flat vector is an error
+    let res = sqlx::query_scalar::<_, Vec<i32>>("SELECT '{{1,2,3},{4,5,6}}'::int[][]")
+        .fetch_one(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().to_string();
+    assert!(err.contains("nested vectors"), "{}", err);
+
+    Ok(())
+}
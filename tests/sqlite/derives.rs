@@ -1,5 +1,5 @@
 use sqlx::Sqlite;
-use sqlx_test::test_type;
+use sqlx_test::{new, test_type};
 
 #[derive(Debug, PartialEq, sqlx::Type)]
 #[repr(u32)]
@@ -12,3 +12,27 @@ test_type!(origin_enum<Origin>(Sqlite,
     "1" == Origin::Foo,
     "2" == Origin::Bar,
 ));
+
+#[sqlx_macros::test]
+async fn test_from_row_with_rename_all() -> anyhow::Result<()> {
+    #[derive(Debug, sqlx::FromRow)]
+    #[sqlx(rename_all = "camelCase")]
+    struct AccountKeyword {
+        user_id: i32,
+        user_name: String,
+        user_surname: String,
+    }
+
+    let mut conn = new::<Sqlite>().await?;
+
+    let account: AccountKeyword =
+        sqlx::query_as(r#"SELECT 1 AS "userId", 'foo' AS "userName", 'bar' AS "userSurname""#)
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(1, account.user_id);
+    assert_eq!("foo", account.user_name);
+    assert_eq!("bar", account.user_surname);
+
+    Ok(())
+}
@@ -244,3 +244,56 @@ async fn it_describes_left_join() -> anyhow::Result<()> {
 
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_caches_describe_results_when_enabled() -> anyhow::Result<()> {
+    use sqlx::Connection;
+
+    let mut conn = SqliteConnectOptions::new()
+        .describe_cache_capacity(16)
+        .connect()
+        .await?;
+
+    conn.execute("CREATE TABLE cached (id INTEGER PRIMARY KEY, name TEXT)")
+        .await?;
+
+    let d = conn.describe("SELECT * FROM cached").await?;
+    assert_eq!(d.columns().len(), 2);
+
+    // the table is gone, but the cached result answers without re-preparing
+    conn.execute("DROP TABLE cached").await?;
+
+    let d = conn.describe("SELECT * FROM cached").await?;
+    assert_eq!(d.columns().len(), 2);
+
+    // clearing cached statements also invalidates the describe cache
+    conn.clear_cached_statements().await?;
+
+    assert!(conn.describe("SELECT * FROM cached").await.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_describes_read_only() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let d = conn.describe("SELECT 1 + 1").await?;
+    assert_eq!(d.read_only(), Some(true));
+
+    let d = conn.describe("SELECT * FROM tweet").await?;
+    assert_eq!(d.read_only(), Some(true));
+
+    let d = conn
+        .describe("INSERT INTO tweet (text) VALUES ('hello')")
+        .await?;
+    assert_eq!(d.read_only(), Some(false));
+
+    // a multi-statement query is only read-only if every statement is
+    let d = conn
+        .describe("SELECT id FROM tweet; DELETE FROM tweet")
+        .await?;
+    assert_eq!(d.read_only(), Some(false));
+
+    Ok(())
+}
@@ -16,6 +16,143 @@ async fn macro_select() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn macro_select_from_file() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let account = sqlx::query_file!("tests/sqlite/test-query.sql", 1i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(account.id, 1);
+    assert_eq!(account.name, "Herp Derpinson");
+    assert_eq!(account.is_active, Some(true));
+
+    #[derive(Debug)]
+    struct Account {
+        id: i64,
+        name: String,
+        is_active: Option<bool>,
+    }
+
+    let account = sqlx::query_file_as!(Account, "tests/sqlite/test-query.sql", 1i32)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(account.id, 1);
+    assert_eq!(account.name, "Herp Derpinson");
+    assert_eq!(account.is_active, Some(true));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn macro_select_tuple() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let (count, newest): (i32, Option<i32>) =
+        sqlx::query_tuple!(r#"select count(*) as "n!", max(id) as max_id from tweet"#)
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(count, 1);
+    assert_eq!(newest, Some(1));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn macro_select_named_parameters() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    // named parameters bind in order of first appearance; the reused `:min` only
+    // needs to be bound once
+    let row = sqlx::query!(
+        "select id from tweet where id between :min and :max and :min >= 0",
+        0i64,
+        50i64
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(row.id, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn macro_insert_returning() -> anyhow::Result<()> {
+    use sqlx::Connection;
+
+    let mut conn = new::<Sqlite>().await?;
+    let mut tx = conn.begin().await?;
+
+    let row = sqlx::query!(
+        "insert into tweet (id, text) values (?, ?) returning id, is_sent",
+        99i64,
+        "a probe"
+    )
+    .fetch_one(&mut tx)
+    .await?;
+
+    assert_eq!(row.id, 99);
+    assert_eq!(row.is_sent, true);
+
+    // `returning *` expands to the full column list
+    let row = sqlx::query!(
+        "insert into tweet (id, text) values (?, ?) returning *",
+        100i64,
+        "another probe"
+    )
+    .fetch_one(&mut tx)
+    .await?;
+
+    assert_eq!(row.id, 100);
+    assert_eq!(row.text, "another probe");
+    assert_eq!(row.owner_id, None);
+
+    tx.rollback().await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn macro_select_in_list() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let ids: Vec<i64> = vec![1, 2, 3];
+
+    let tweets = sqlx::query_in!("select id, text from tweet where id in ({...})", ids)
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(tweets.len(), 1);
+    assert_eq!(tweets[0].id, 1);
+    assert_eq!(tweets[0].text, "#sqlx is pretty cool!");
+
+    // an empty list matches no rows instead of generating invalid SQL
+    let no_ids: Vec<i64> = vec![];
+
+    let tweets = sqlx::query_in!("select id, text from tweet where id in ({...})", no_ids)
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert!(tweets.is_empty());
+
+    // explicit arguments bind around the expanded list
+    let tweets = sqlx::query_in!(
+        "select id, text from tweet where is_sent = ? and id in ({...})",
+        ids,
+        true
+    )
+    .fetch_all(&mut conn)
+    .await?;
+
+    assert_eq!(tweets.len(), 1);
+
+    Ok(())
+}
+
 macro_rules! gen_macro_select_concats {
     ($param:literal) => {
         #[sqlx_macros::test]
@@ -217,6 +354,30 @@ async fn test_column_override_nullable() -> anyhow::Result<()> {
     Ok(())
 }
 
+struct NullabilityRecord {
+    owner_id: i64,
+    text: Option<String>,
+}
+
+#[sqlx_macros::test]
+async fn test_column_override_nullability_explicit_record() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    // `owner_id` is reported nullable but `!` forces the field out of `Option`;
+    // `text` is NOT NULL but `?` forces it in
+    let record = sqlx::query_as!(
+        NullabilityRecord,
+        r#"select owner_id as "owner_id!", text as "text?" from tweet"#
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(record.owner_id, 1);
+    assert_eq!(record.text.as_deref(), Some("#sqlx is pretty cool!"));
+
+    Ok(())
+}
+
 #[derive(PartialEq, Eq, Debug, sqlx::Type)]
 #[sqlx(transparent)]
 struct MyInt(i64);
@@ -333,4 +494,162 @@ async fn test_column_override_exact_nullable() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(rename_all = "lowercase")]
+enum TweetKind {
+    Original,
+    Reply,
+}
+
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[repr(i64)]
+enum Flag {
+    Off = 0,
+    On = 1,
+}
+
+#[sqlx_macros::test]
+async fn test_column_override_exact_enum() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    // a text-backed enum
+    let record = sqlx::query!(r#"select 'reply' as "kind: TweetKind""#)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(record.kind, TweetKind::Reply);
+
+    // an integer-backed enum
+    let record = sqlx::query!(r#"select is_sent as "sent: Flag" from tweet"#)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(record.sent, Flag::On);
+
+    // the override also applies to an explicit record type
+    struct KindRecord {
+        kind: TweetKind,
+    }
+
+    let record = sqlx::query_as!(KindRecord, r#"select 'original' as "kind: TweetKind""#)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(record.kind, TweetKind::Original);
+
+    Ok(())
+}
+
+// `#[sqlx(repr = "..")]` works like `#[repr(..)]` without changing the Rust layout
+#[derive(PartialEq, Copy, Clone, Debug, sqlx::Type)]
+#[sqlx(repr = "i16")]
+enum Status {
+    Active = 1,
+    Closed = 2,
+}
+
+#[sqlx_macros::test]
+async fn test_repr_attribute_enum() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    for status in [Status::Active, Status::Closed] {
+        let record = sqlx::query!(r#"select ? as "status!: Status""#, status)
+            .fetch_one(&mut conn)
+            .await?;
+
+        assert_eq!(record.status, status);
+    }
+
+    // an out-of-range discriminant is a decode error naming the bad value
+    let err = sqlx::query!(r#"select 99 as "status!: Status""#)
+        .fetch_one(&mut conn)
+        .await
+        .unwrap_err();
+
+    assert!(err.to_string().contains("99"), "{}", err);
+
+    Ok(())
+}
+
+#[derive(PartialEq, Debug, sqlx::Type)]
+#[sqlx(transparent)]
+struct MyStamp(String);
+
+#[sqlx_macros::test]
+async fn test_configured_type_overrides() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    // `user_id` columns are mapped to `crate::MyInt` in `sqlx.toml`, whatever the query
+    let record = sqlx::query!("select id as user_id from tweet")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(record.user_id, MyInt(1));
+
+    let record = sqlx::query!("select owner_id as user_id from tweet")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(record.user_id, Some(MyInt(1)));
+
+    // `DATETIME` columns are mapped to `crate::MyStamp` by SQL type
+    let record = sqlx::query!("select created_at from events")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(record.created_at, MyStamp("2021-01-01 00:00:00".into()));
+
+    // a per-query override still takes precedence
+    let record = sqlx::query!(r#"select created_at as "created_at: String" from events"#)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(record.created_at, "2021-01-01 00:00:00");
+
+    Ok(())
+}
+
+#[cfg(feature = "json")]
+#[sqlx_macros::test]
+async fn test_json_round_trip() -> anyhow::Result<()> {
+    use serde::{Deserialize, Serialize};
+    use sqlx::types::Json;
+
+    #[derive(PartialEq, Debug, Serialize, Deserialize)]
+    struct Payload {
+        name: String,
+        tags: Vec<String>,
+        nested: Nested,
+    }
+
+    #[derive(PartialEq, Debug, Serialize, Deserialize)]
+    struct Nested {
+        active: bool,
+    }
+
+    let mut conn = new::<Sqlite>().await?;
+
+    let payload = Json(Payload {
+        name: "a tweet".to_owned(),
+        tags: vec!["first".to_owned(), "second".to_owned()],
+        nested: Nested { active: true },
+    });
+
+    // the value is serialized to text on bind and parsed back out via the type override
+    let record = sqlx::query!(r#"select ? as "data!: Json<Payload>""#, payload)
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(record.data.0, payload.0);
+
+    // text that isn't valid JSON surfaces as a decode error
+    let res = sqlx::query!(r#"select 'not json' as "data!: Json<Payload>""#)
+        .fetch_one(&mut conn)
+        .await;
+
+    assert!(res.is_err());
+
+    Ok(())
+}
+
 // we don't emit bind parameter typechecks for SQLite so testing the overrides is redundant
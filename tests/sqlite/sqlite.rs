@@ -204,6 +204,246 @@ async fn it_executes_with_pool() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_classifies_constraint_violations() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute(
+        "CREATE TEMP TABLE violations (id INTEGER UNIQUE, val INTEGER NOT NULL CHECK (val > 0))",
+    )
+    .await?;
+
+    conn.execute("INSERT INTO violations VALUES (1, 1)").await?;
+
+    let res: Result<_, sqlx::Error> = sqlx::query("INSERT INTO violations VALUES (1, 2)")
+        .execute(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().into_database_error().unwrap();
+
+    assert!(err.is_unique_violation());
+    assert!(!err.is_foreign_key_violation());
+    assert!(!err.is_check_violation());
+    assert!(!err.is_not_null_violation());
+
+    let res: Result<_, sqlx::Error> = sqlx::query("INSERT INTO violations VALUES (2, NULL)")
+        .execute(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().into_database_error().unwrap();
+
+    assert!(err.is_not_null_violation());
+    assert!(!err.is_unique_violation());
+
+    let res: Result<_, sqlx::Error> = sqlx::query("INSERT INTO violations VALUES (2, -1)")
+        .execute(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().into_database_error().unwrap();
+
+    assert!(err.is_check_violation());
+    assert!(!err.is_unique_violation());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reports_extended_result_codes() -> anyhow::Result<()> {
+    use sqlx::sqlite::SqliteError;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMP TABLE extended_codes (id INTEGER UNIQUE)")
+        .await?;
+
+    conn.execute("INSERT INTO extended_codes VALUES (1)").await?;
+
+    let res: Result<_, sqlx::Error> = sqlx::query("INSERT INTO extended_codes VALUES (1)")
+        .execute(&mut conn)
+        .await;
+
+    let err = res.unwrap_err().into_database_error().unwrap();
+    let err: Box<SqliteError> = err.downcast();
+
+    // SQLITE_CONSTRAINT_UNIQUE, not the ambiguous primary SQLITE_CONSTRAINT
+    assert_eq!(err.extended_code(), 2067);
+    assert_eq!(err.primary_code(), 19);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_retries_a_transaction_on_a_busy_database() -> anyhow::Result<()> {
+    use sqlx::pool::RetryPolicy;
+    use std::sync::atomic::{AtomicU32, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    let path = std::env::temp_dir().join(format!("sqlx-retry-{}.db", std::process::id()));
+    let url = format!("sqlite://{}", path.display());
+
+    let options: SqliteConnectOptions = url
+        .parse::<SqliteConnectOptions>()?
+        .create_if_missing(true)
+        .busy_timeout(Duration::from_secs(0));
+
+    let mut blocker = SqliteConnection::connect_with(&options).await?;
+    blocker
+        .execute("CREATE TABLE IF NOT EXISTS retry_tweet (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    // hold the write lock so the first attempt fails with SQLITE_BUSY
+    blocker.execute("BEGIN IMMEDIATE").await?;
+    let blocker = Arc::new(Mutex::new(Some(blocker)));
+
+    let pool: SqlitePool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .connect_with(options)
+        .await?;
+
+    let attempts = Arc::new(AtomicU32::new(0));
+
+    {
+        let attempts = Arc::clone(&attempts);
+        let blocker = Arc::clone(&blocker);
+
+        pool.transaction_with_retry(RetryPolicy::new(2), move |tx| {
+            let attempts = Arc::clone(&attempts);
+            let blocker = Arc::clone(&blocker);
+
+            Box::pin(async move {
+                attempts.fetch_add(1, Ordering::SeqCst);
+
+                let res = sqlx::query("INSERT INTO retry_tweet (id) VALUES (NULL)")
+                    .execute(&mut *tx)
+                    .await;
+
+                if res.is_err() {
+                    // release the write lock so the retry can succeed; take the
+                    // connection out of the mutex before awaiting on it
+                    let blocker = blocker.lock().unwrap().take();
+
+                    if let Some(mut blocker) = blocker {
+                        blocker.execute("ROLLBACK").await?;
+                    }
+                }
+
+                res.map(|_| ())
+            })
+        })
+        .await?;
+    }
+
+    assert_eq!(attempts.load(Ordering::SeqCst), 2);
+
+    pool.close().await;
+    let _ = std::fs::remove_file(&path);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_acquires_without_waiting_with_try_acquire() -> anyhow::Result<()> {
+    let pool: SqlitePool = SqlitePoolOptions::new()
+        .min_connections(1)
+        .max_connections(1)
+        .connect(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    // the pool is saturated while its only connection is checked out
+    let conn = pool.acquire().await?;
+    assert!(pool.try_acquire().is_none());
+
+    // returning to the pool happens in a background task; wait for the
+    // connection to actually make it back
+    drop(conn);
+    while pool.num_idle() < 1 {
+        sqlx_rt::yield_now().await;
+    }
+
+    let conn = pool.try_acquire();
+    assert!(conn.is_some());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reuses_the_hottest_connection_with_lifo() -> anyhow::Result<()> {
+    use sqlx::pool::AcquireOrder;
+
+    let pool: SqlitePool = SqlitePoolOptions::new()
+        .max_connections(2)
+        .test_before_acquire(false)
+        .acquire_order(AcquireOrder::Lifo)
+        .connect(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    // mark two distinct connections with temporary tables, then return them
+    // to the pool in order: `first` goes back before `second`
+    let mut first = pool.acquire().await?;
+    first.execute("CREATE TEMP TABLE _lifo_first (id INTEGER)").await?;
+
+    let mut second = pool.acquire().await?;
+    second
+        .execute("CREATE TEMP TABLE _lifo_second (id INTEGER)")
+        .await?;
+
+    // returning to the pool happens in a background task; wait for each
+    // connection to actually make it back so the return order is deterministic
+    drop(first);
+    while pool.num_idle() < 1 {
+        sqlx_rt::yield_now().await;
+    }
+
+    drop(second);
+    while pool.num_idle() < 2 {
+        sqlx_rt::yield_now().await;
+    }
+
+    // LIFO prefers the most recently returned connection
+    for _ in 0..5 {
+        let mut conn = pool.acquire().await?;
+
+        let (is_second,): (bool,) = sqlx::query_as(
+            "SELECT count(*) > 0 FROM sqlite_temp_master WHERE name = '_lifo_second'",
+        )
+        .fetch_one(&mut conn)
+        .await?;
+
+        assert!(is_second);
+
+        drop(conn);
+        while pool.num_idle() < 2 {
+            sqlx_rt::yield_now().await;
+        }
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_pings_idle_connections_with_keepalive() -> anyhow::Result<()> {
+    use std::time::Duration;
+
+    let pool: SqlitePool = SqlitePoolOptions::new()
+        .min_connections(1)
+        .max_connections(1)
+        .test_before_acquire(false)
+        .keepalive_interval(Duration::from_millis(50))
+        .connect(&dotenv::var("DATABASE_URL")?)
+        .await?;
+
+    // let the background task run a few keepalive cycles over the idle connection
+    sqlx_rt::sleep(Duration::from_millis(300)).await;
+
+    // the connection should still be usable
+    let rows = pool.fetch_all("SELECT 1").await?;
+
+    assert_eq!(rows.len(), 1);
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_opens_in_memory() -> anyhow::Result<()> {
     // If the filename is ":memory:", then a private, temporary in-memory database
@@ -474,6 +714,38 @@ async fn it_caches_statements() -> anyhow::Result<()> {
     Ok(())
 }
 
+#[sqlx_macros::test]
+async fn it_survives_schema_change_with_cleared_statement_cache() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE alters (id INTEGER PRIMARY KEY)")
+        .await?;
+
+    // prepare and cache a statement against the original schema
+    let row = sqlx::query("SELECT * FROM alters")
+        .fetch_optional(&mut conn)
+        .await?;
+    assert!(row.is_none());
+
+    // change the schema out from under the cached statement
+    conn.execute("ALTER TABLE alters ADD COLUMN extra TEXT")
+        .await?;
+    conn.execute("INSERT INTO alters (id, extra) VALUES (1, 'hello')")
+        .await?;
+
+    conn.clear_cached_statements().await?;
+
+    // the re-prepared statement sees the new column
+    let row = sqlx::query("SELECT * FROM alters")
+        .fetch_one(&mut conn)
+        .await?;
+
+    let extra: String = row.get("extra");
+    assert_eq!(extra, "hello");
+
+    Ok(())
+}
+
 #[sqlx_macros::test]
 async fn it_can_prepare_then_execute() -> anyhow::Result<()> {
     let mut conn = new::<Sqlite>().await?;
@@ -662,3 +934,935 @@ async fn issue_1467() -> anyhow::Result<()> {
     }
     Ok(())
 }
+
+#[sqlx_macros::test]
+async fn it_binds_an_expanded_in_list() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let ids = [1_i32, 2, 3, 4, 5];
+
+    let sql = format!(
+        "SELECT value FROM (SELECT 3 AS value UNION SELECT 7) WHERE value IN ({})",
+        sqlx::in_placeholders::<Sqlite>(1, ids.len())
+    );
+
+    let values: Vec<i32> = sqlx::query_scalar(&sql)
+        .bind_in(ids)
+        .fetch_all(&mut conn)
+        .await?;
+
+    assert_eq!(values, [3]);
+
+    // an empty list must still produce valid SQL that matches nothing
+    let sql = format!(
+        "SELECT value FROM (SELECT 3 AS value UNION SELECT 7) WHERE value IN ({})",
+        sqlx::in_placeholders::<Sqlite>(1, 0)
+    );
+
+    let values: Vec<i32> = sqlx::query_scalar(&sql).fetch_all(&mut conn).await?;
+
+    assert!(values.is_empty());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_invokes_the_slow_statement_callback() -> anyhow::Result<()> {
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use std::time::Duration;
+
+    let calls = Arc::new(AtomicUsize::new(0));
+
+    let mut options = SqliteConnectOptions::new();
+    options.log_slow_statements(log::LevelFilter::Off, Duration::from_millis(0));
+    options.on_slow_statement({
+        let calls = Arc::clone(&calls);
+        move |sql, elapsed, rows| {
+            // the connection setup PRAGMAs go through the same logger
+            if sql == "SELECT 1 UNION SELECT 2" {
+                assert!(elapsed > Duration::from_secs(0));
+                assert_eq!(rows, 2);
+
+                calls.fetch_add(1, Ordering::SeqCst);
+            }
+        }
+    });
+
+    let mut conn = options.connect().await?;
+
+    conn.fetch_all("SELECT 1 UNION SELECT 2").await?;
+
+    assert_eq!(calls.load(Ordering::SeqCst), 1);
+
+    Ok(())
+}
+
+
+#[sqlx_macros::test]
+async fn it_exposes_the_raw_handle() -> anyhow::Result<()> {
+    use std::os::raw::{c_char, c_int, c_void};
+
+    // `sqlite3_db_readonly()` is linked in via `libsqlite3-sys` but not wrapped by sqlx
+    extern "C" {
+        fn sqlite3_db_readonly(db: *mut c_void, db_name: *const c_char) -> c_int;
+    }
+
+    let mut conn = new::<Sqlite>().await?;
+
+    // while the handle is locked the worker thread makes no FFI calls,
+    // so direct C API calls are safe
+    let mut handle = conn.lock_handle().await?;
+
+    let readonly =
+        unsafe { sqlite3_db_readonly(handle.as_raw_handle().as_ptr().cast(), b"main\0".as_ptr().cast()) };
+
+    // the main database of this connection is open read-write
+    assert_eq!(readonly, 0);
+
+    drop(handle);
+
+    // the connection is usable again afterwards
+    conn.execute("SELECT 1").await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_opens_in_either_thread_mode() -> anyhow::Result<()> {
+    use sqlx::sqlite::SqliteThreadMode;
+
+    for mode in [SqliteThreadMode::MultiThread, SqliteThreadMode::Serialized] {
+        let mut conn = SqliteConnectOptions::new()
+            .filename(":memory:")
+            .thread_mode(mode)
+            .connect()
+            .await?;
+
+        let value: i32 = sqlx::query_scalar("SELECT 40 + 2").fetch_one(&mut conn).await?;
+
+        assert_eq!(value, 42);
+
+        conn.close().await?;
+    }
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_executes_a_batch_of_queries() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE batch (id INTEGER PRIMARY KEY, note TEXT)")
+        .await?;
+
+    let queries = (1..=3).map(|i: i32| {
+        sqlx::query("INSERT INTO batch (id, note) VALUES (?, ?)")
+            .bind(i)
+            .bind(format!("note {}", i))
+    });
+
+    let results: Vec<_> = conn.execute_batch(queries).try_collect().await?;
+
+    assert_eq!(results.len(), 3);
+    assert!(results.iter().all(|done| done.rows_affected() == 1));
+
+    let count: i32 = sqlx::query_scalar("SELECT COUNT(*) FROM batch")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 3);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fetches_in_chunks() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    // 25 rows with a chunk size of 10 should yield chunks of 10, 10, and 5
+    let chunks: Vec<Vec<SqliteRow>> = conn
+        .fetch_chunked(
+            "WITH RECURSIVE series(n) AS (SELECT 1 UNION ALL SELECT n + 1 FROM series WHERE n < 25) SELECT n FROM series",
+            10,
+        )
+        .try_collect()
+        .await?;
+
+    assert_eq!(
+        chunks.iter().map(Vec::len).collect::<Vec<_>>(),
+        vec![10, 10, 5]
+    );
+
+    let last: i32 = chunks.last().unwrap().last().unwrap().get(0);
+    assert_eq!(last, 25);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_prepares_all_statements_up_front() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let statements = ["SELECT 1 + ?", "SELECT text FROM tweet WHERE id = ?"];
+
+    conn.prepare_all(&statements).await?;
+    assert_eq!(conn.cached_statements_size(), 2);
+
+    // executing the same queries re-uses the cached statements
+    let _ = conn.fetch_one(statements[0]).await?;
+    assert_eq!(conn.cached_statements_size(), 2);
+
+    // a batch containing an invalid statement fails and reports it
+    let res = conn.prepare_all(&["SELECT 2", "SELECT * FROM not_found"]).await;
+    assert!(res.is_err());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_shrinks_buffers_on_release() -> anyhow::Result<()> {
+    // `shrink_buffers` is a no-op for SQLite, but the option must still round-trip
+    // connections through the pool unharmed
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .shrink_buffers_on_release(true)
+        .connect("sqlite::memory:")
+        .await?;
+
+    {
+        let mut conn = pool.acquire().await?;
+
+        let rows = conn.fetch_all("SELECT zeroblob(1024 * 1024)").await?;
+        assert_eq!(rows.len(), 1);
+    }
+
+    let answer: i32 = sqlx::query_scalar("SELECT 40 + 2").fetch_one(&pool).await?;
+    assert_eq!(answer, 42);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_drains_the_pool_gracefully() -> anyhow::Result<()> {
+    use std::time::Duration;
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(2)
+        .connect("sqlite::memory:")
+        .await?;
+
+    let mut conn = pool.acquire().await?;
+    conn.execute("CREATE TABLE draining (id INTEGER)").await?;
+
+    let mut tx = conn.begin().await?;
+    sqlx::query("INSERT INTO draining (id) VALUES (1)")
+        .execute(&mut tx)
+        .await?;
+
+    let drain = pool.begin_drain(Duration::from_secs(30));
+
+    // new acquisitions are rejected as soon as the drain begins
+    let err = pool.acquire().await.unwrap_err();
+    assert!(matches!(err, sqlx::Error::PoolDraining), "{:?}", err);
+
+    // ... but in-flight work on checked-out connections still completes
+    tx.commit().await?;
+
+    let count: i32 = sqlx::query_scalar("SELECT COUNT(*) FROM draining")
+        .fetch_one(&mut conn)
+        .await?;
+    assert_eq!(count, 1);
+
+    drop(conn);
+    drain.await;
+
+    assert!(pool.is_closed());
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reports_acquire_wait_times() -> anyhow::Result<()> {
+    use sqlx::pool::AcquireOutcome;
+    use std::sync::{Arc, Mutex};
+    use std::time::Duration;
+
+    let events: Arc<Mutex<Vec<(Duration, AcquireOutcome)>>> = Arc::new(Mutex::new(Vec::new()));
+    let events_ = Arc::clone(&events);
+
+    let pool = SqlitePoolOptions::new()
+        .max_connections(1)
+        .on_acquire(move |wait, outcome| {
+            events_.lock().unwrap().push((wait, outcome));
+        })
+        .connect("sqlite::memory:")
+        .await?;
+
+    // `connect()` pre-establishes one connection, so the first acquisition reuses it
+    let conn = pool.acquire().await?;
+    assert_eq!(events.lock().unwrap().last().unwrap().1, AcquireOutcome::Reused);
+
+    // this acquisition has to wait for the checked-out connection to come back
+    let waiter = sqlx_rt::spawn({
+        let pool = pool.clone();
+        async move { pool.acquire().await.map(drop) }
+    });
+
+    sqlx_rt::sleep(Duration::from_millis(50)).await;
+    drop(conn);
+    waiter.await?.unwrap();
+
+    let (wait, outcome) = *events.lock().unwrap().last().unwrap();
+    assert_eq!(outcome, AcquireOutcome::Reused);
+    assert!(wait >= Duration::from_millis(10), "waited only {:?}", wait);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_fires_the_wal_hook_and_checkpoints() -> anyhow::Result<()> {
+    use sqlx::sqlite::{SqliteJournalMode, SqliteWalHookResult};
+    use std::sync::{Arc, Mutex};
+
+    let path = std::env::temp_dir().join(format!("sqlx-wal-hook-{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut conn = SqliteConnectOptions::new()
+        .filename(&path)
+        .create_if_missing(true)
+        .journal_mode(SqliteJournalMode::Wal)
+        .connect()
+        .await?;
+
+    let pages_log: Arc<Mutex<Vec<i32>>> = Arc::new(Mutex::new(Vec::new()));
+    let log = Arc::clone(&pages_log);
+
+    conn.set_wal_hook(move |db, pages| {
+        assert_eq!(db, "main");
+        log.lock().unwrap().push(pages);
+
+        if pages > 10 {
+            SqliteWalHookResult::Checkpoint
+        } else {
+            SqliteWalHookResult::Continue
+        }
+    })?;
+
+    conn.execute("CREATE TABLE wal_hook_test (id INTEGER PRIMARY KEY, v TEXT)")
+        .await?;
+
+    for i in 0..30_i32 {
+        sqlx::query("INSERT INTO wal_hook_test (v) VALUES (?)")
+            .bind(format!("value {}", i))
+            .execute(&mut conn)
+            .await?;
+    }
+
+    let log = pages_log.lock().unwrap();
+
+    // the hook fired for each committed write
+    assert!(log.len() >= 30);
+
+    // the WAL accumulated past the checkpoint threshold ...
+    let peak = log.iter().copied().max().unwrap();
+    assert!(peak > 10, "peak WAL size was {} pages", peak);
+
+    // ... and a requested checkpoint rewound it
+    assert!(
+        log.windows(2).any(|w| w[1] < w[0]),
+        "WAL never shrank: {:?}",
+        *log
+    );
+
+    drop(log);
+    conn.close().await?;
+    let _ = std::fs::remove_file(&path);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_denies_column_reads_with_an_authorizer() -> anyhow::Result<()> {
+    use sqlx::sqlite::{SqliteAuthActionCode, SqliteAuthorization};
+
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE secrets (id INTEGER PRIMARY KEY, secret TEXT)")
+        .await?;
+
+    sqlx::query("INSERT INTO secrets (secret) VALUES ('hunter2')")
+        .execute(&mut conn)
+        .await?;
+
+    conn.set_authorizer(|action| {
+        if action.code() == SqliteAuthActionCode::Read && action.arg2() == Some("secret") {
+            SqliteAuthorization::Deny
+        } else {
+            SqliteAuthorization::Allow
+        }
+    })?;
+
+    // columns other than the denied one are still readable
+    let id: i32 = sqlx::query_scalar("SELECT id FROM secrets")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(id, 1);
+
+    // a statement referencing the denied column fails to prepare
+    let err = sqlx::query("SELECT secret FROM secrets")
+        .execute(&mut conn)
+        .await
+        .unwrap_err();
+    assert!(
+        err.to_string().contains("secret"),
+        "unexpected error: {}",
+        err
+    );
+
+    // removing the authorizer lifts the restriction
+    conn.remove_authorizer()?;
+
+    let secret: String = sqlx::query_scalar("SELECT secret FROM secrets")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(secret, "hunter2");
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reports_database_stats() -> anyhow::Result<()> {
+    let path = std::env::temp_dir().join(format!("sqlx-db-stats-{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut conn = SqliteConnectOptions::new()
+        .filename(&path)
+        .create_if_missing(true)
+        .connect()
+        .await?;
+
+    conn.execute("CREATE TABLE stats_test (id INTEGER PRIMARY KEY, v BLOB)")
+        .await?;
+
+    for _ in 0..50 {
+        sqlx::query("INSERT INTO stats_test (v) VALUES (zeroblob(4096))")
+            .execute(&mut conn)
+            .await?;
+    }
+
+    let before = conn.database_stats(None).await?;
+
+    assert!(before.page_count() > 0);
+    assert!(before.page_size() > 0);
+    assert_eq!(before.total_bytes(), before.page_count() * before.page_size());
+
+    // the default schema is `main`
+    assert_eq!(conn.database_stats(Some("main")).await?, before);
+
+    // deleting rows moves their pages onto the freelist ...
+    conn.execute("DELETE FROM stats_test").await?;
+
+    let after_delete = conn.database_stats(None).await?;
+
+    assert!(after_delete.freelist_count() > before.freelist_count());
+    assert!(after_delete.free_bytes() > 0);
+
+    // ... and a VACUUM gives them back to the filesystem
+    conn.execute("VACUUM").await?;
+
+    let after_vacuum = conn.database_stats(None).await?;
+
+    assert!(after_vacuum.freelist_count() <= 1);
+    assert!(after_vacuum.page_count() < after_delete.page_count());
+
+    conn.close().await?;
+    let _ = std::fs::remove_file(&path);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_executes_a_script_with_per_statement_results() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE script_test (v INTEGER)")
+        .await?;
+
+    let results = conn
+        .execute_script(
+            "INSERT INTO script_test VALUES (1); \
+             INSERT INTO script_test VALUES (2), (3); \
+             SELECT v FROM script_test",
+        )
+        .await?;
+
+    assert_eq!(results.len(), 3);
+    assert_eq!(results[0].rows_affected(), 1);
+    assert_eq!(results[1].rows_affected(), 2);
+
+    // execution stops at the first failing statement
+    let res = conn
+        .execute_script("SELECT 1; SELECT * FROM does_not_exist; SELECT 2")
+        .await;
+
+    let err = res.unwrap_err();
+    assert!(
+        err.to_string().contains("does_not_exist"),
+        "unexpected error: {}",
+        err
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_opens_a_database_through_a_custom_vfs() -> anyhow::Result<()> {
+    use sqlx::sqlite::{register_vfs, SqliteJournalMode, SqliteVfs, SqliteVfsFile};
+    use std::fs::{File, OpenOptions};
+    use std::io::{Read, Seek, SeekFrom, Write};
+
+    // a thin pass-through to the real filesystem
+    struct PassthroughVfs;
+
+    struct PassthroughFile(File);
+
+    impl SqliteVfsFile for PassthroughFile {
+        fn read(&mut self, offset: u64, buf: &mut [u8]) -> std::io::Result<usize> {
+            self.0.seek(SeekFrom::Start(offset))?;
+
+            let mut total = 0;
+
+            while total < buf.len() {
+                let n = self.0.read(&mut buf[total..])?;
+
+                if n == 0 {
+                    break;
+                }
+
+                total += n;
+            }
+
+            Ok(total)
+        }
+
+        fn write(&mut self, offset: u64, buf: &[u8]) -> std::io::Result<()> {
+            self.0.seek(SeekFrom::Start(offset))?;
+            self.0.write_all(buf)
+        }
+
+        fn truncate(&mut self, size: u64) -> std::io::Result<()> {
+            self.0.set_len(size)
+        }
+
+        fn sync(&mut self, data_only: bool) -> std::io::Result<()> {
+            if data_only {
+                self.0.sync_data()
+            } else {
+                self.0.sync_all()
+            }
+        }
+
+        fn file_size(&mut self) -> std::io::Result<u64> {
+            Ok(self.0.metadata()?.len())
+        }
+    }
+
+    impl SqliteVfs for PassthroughVfs {
+        fn open(
+            &self,
+            path: Option<&str>,
+            _flags: i32,
+        ) -> std::io::Result<Box<dyn SqliteVfsFile>> {
+            let path = path.ok_or_else(|| {
+                std::io::Error::new(std::io::ErrorKind::Other, "anonymous files not supported")
+            })?;
+
+            let file = OpenOptions::new()
+                .read(true)
+                .write(true)
+                .create(true)
+                .open(path)?;
+
+            Ok(Box::new(PassthroughFile(file)))
+        }
+
+        fn delete(&self, path: &str) -> std::io::Result<()> {
+            match std::fs::remove_file(path) {
+                Err(e) if e.kind() != std::io::ErrorKind::NotFound => Err(e),
+                _ => Ok(()),
+            }
+        }
+
+        fn access(&self, path: &str, _flags: i32) -> std::io::Result<bool> {
+            Ok(std::path::Path::new(path).exists())
+        }
+    }
+
+    register_vfs("test_passthrough", PassthroughVfs).unwrap();
+
+    let path = std::env::temp_dir().join(format!("sqlx-vfs-{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let mut conn = SqliteConnectOptions::new()
+        .filename(&path)
+        .create_if_missing(true)
+        // the custom VFS surface has no shared-memory methods, so WAL is unavailable
+        .journal_mode(SqliteJournalMode::Delete)
+        .vfs("test_passthrough")
+        .connect()
+        .await?;
+
+    conn.execute("CREATE TABLE vfs_test (v TEXT)").await?;
+
+    sqlx::query("INSERT INTO vfs_test (v) VALUES ('through the looking glass')")
+        .execute(&mut conn)
+        .await?;
+
+    let v: String = sqlx::query_scalar("SELECT v FROM vfs_test")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(v, "through the looking glass");
+
+    conn.close().await?;
+
+    // the writes really went to the backing file
+    assert!(std::fs::metadata(&path)?.len() > 0);
+
+    let _ = std::fs::remove_file(&path);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_toggles_query_only() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE query_only_test (v INTEGER)")
+        .await?;
+
+    assert!(!conn.query_only());
+
+    conn.set_query_only(true).await?;
+    assert!(conn.query_only());
+
+    // writes are rejected while query_only is on ...
+    let err = conn
+        .execute("INSERT INTO query_only_test VALUES (1)")
+        .await
+        .unwrap_err();
+
+    assert!(
+        err.to_string().contains("readonly"),
+        "unexpected error: {}",
+        err
+    );
+
+    // ... but reads still work
+    let count: i32 = sqlx::query_scalar("SELECT count(*) FROM query_only_test")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(count, 0);
+
+    // and toggling it back off restores writes
+    conn.set_query_only(false).await?;
+    assert!(!conn.query_only());
+
+    conn.execute("INSERT INTO query_only_test VALUES (1)")
+        .await?;
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_counts_rows_affected_with_returning() -> anyhow::Result<()> {
+    use sqlx::Either;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.execute("CREATE TEMPORARY TABLE returning_test (id INTEGER PRIMARY KEY, v TEXT)")
+        .await?;
+
+    let mut returned = Vec::new();
+    let mut rows_affected = None;
+
+    {
+        let mut s = conn.fetch_many(
+            "INSERT INTO returning_test (v) VALUES ('a'), ('b'), ('c') RETURNING id",
+        );
+
+        while let Some(item) = s.try_next().await? {
+            match item {
+                Either::Left(done) => rows_affected = Some(done.rows_affected()),
+                Either::Right(row) => returned.push(row.get::<i64, _>(0)),
+            }
+        }
+    }
+
+    // the RETURNING rows come back as result rows ...
+    assert_eq!(returned, [1, 2, 3]);
+
+    // ... and rows_affected still reflects the DML portion
+    assert_eq!(rows_affected, Some(3));
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_registers_a_custom_fts5_tokenizer() -> anyhow::Result<()> {
+    use sqlx::sqlite::{SqliteFts5Tokenizer, SqliteFts5Tokens};
+
+    // splits camelCase identifiers into lowercased segments
+    struct CamelCase;
+
+    impl SqliteFts5Tokenizer for CamelCase {
+        fn tokenize(
+            &mut self,
+            _flags: i32,
+            text: &str,
+            tokens: &mut SqliteFts5Tokens<'_>,
+        ) -> Result<(), sqlx::Error> {
+            let mut start = 0;
+
+            let flush = |tokens: &mut SqliteFts5Tokens<'_>, start: usize, end: usize| {
+                if start < end {
+                    tokens.push(&text[start..end].to_lowercase(), start..end)
+                } else {
+                    Ok(())
+                }
+            };
+
+            for (i, ch) in text.char_indices() {
+                if !ch.is_alphanumeric() {
+                    flush(tokens, start, i)?;
+                    start = i + ch.len_utf8();
+                } else if ch.is_uppercase() && i > start {
+                    flush(tokens, start, i)?;
+                    start = i;
+                }
+            }
+
+            flush(tokens, start, text.len())
+        }
+    }
+
+    let mut conn = new::<Sqlite>().await?;
+
+    conn.register_fts5_tokenizer("camel", |_args: &[&str]| Ok(CamelCase))
+        .await?;
+
+    conn.execute(
+        "CREATE VIRTUAL TABLE temp.docs_camel USING fts5(content, tokenize = 'camel');
+         CREATE VIRTUAL TABLE temp.docs_plain USING fts5(content);
+         INSERT INTO docs_camel VALUES ('fn resolveHostName does the lookup');
+         INSERT INTO docs_plain VALUES ('fn resolveHostName does the lookup');",
+    )
+    .await?;
+
+    // the default tokenizer indexes `resolveHostName` as a single token
+    let plain: i32 =
+        sqlx::query_scalar("SELECT count(*) FROM docs_plain WHERE docs_plain MATCH 'hostname'")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(plain, 0);
+
+    // the camelCase tokenizer splits it into `resolve`, `host` and `name`
+    let custom: i32 =
+        sqlx::query_scalar("SELECT count(*) FROM docs_camel WHERE docs_camel MATCH 'hostname'")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(custom, 0);
+
+    let custom: i32 =
+        sqlx::query_scalar("SELECT count(*) FROM docs_camel WHERE docs_camel MATCH 'host'")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(custom, 1);
+
+    let phrase: i32 =
+        sqlx::query_scalar("SELECT count(*) FROM docs_camel WHERE docs_camel MATCH 'host + name'")
+            .fetch_one(&mut conn)
+            .await?;
+
+    assert_eq!(phrase, 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_retries_begin_immediate_on_busy() -> anyhow::Result<()> {
+    use sqlx::sqlite::SqliteJournalMode;
+    use std::time::Duration;
+
+    let path = std::env::temp_dir().join(format!("sqlx-begin-retry-{}.db", std::process::id()));
+    let _ = std::fs::remove_file(&path);
+
+    let options = SqliteConnectOptions::new()
+        .filename(&path)
+        .create_if_missing(true)
+        .journal_mode(SqliteJournalMode::Delete)
+        // keep SQLite's own busy handler short so the retry loop does the waiting
+        .busy_timeout(Duration::from_millis(10))
+        .begin_immediate_retry(100, Duration::from_millis(10));
+
+    let mut conn = options.clone().connect().await?;
+
+    conn.execute("CREATE TABLE counters (id INTEGER PRIMARY KEY, n INTEGER NOT NULL)")
+        .await?;
+
+    sqlx::query("INSERT INTO counters (id, n) VALUES (1, 0)")
+        .execute(&mut conn)
+        .await?;
+
+    conn.close().await?;
+
+    let mut writers = Vec::new();
+
+    for _ in 0..2 {
+        let options = options.clone();
+
+        writers.push(sqlx_rt::spawn(async move {
+            let mut conn = options.connect().await?;
+            let mut tx = conn.begin().await?;
+
+            sqlx::query("UPDATE counters SET n = n + 1 WHERE id = 1" )
+                .execute(&mut tx)
+                .await?;
+
+            // hold the write lock long enough for the other writer to hit `SQLITE_BUSY`
+            sqlx_rt::sleep(Duration::from_millis(100)).await;
+
+            tx.commit().await?;
+
+            Result::<_, anyhow::Error>::Ok(())
+        }));
+    }
+
+    for writer in writers {
+        writer.await??;
+    }
+
+    let mut conn = options.connect().await?;
+
+    let n: i32 = sqlx::query_scalar("SELECT n FROM counters WHERE id = 1")
+        .fetch_one(&mut conn)
+        .await?;
+
+    assert_eq!(n, 2);
+
+    conn.close().await?;
+    let _ = std::fs::remove_file(&path);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_reports_connection_metrics() -> anyhow::Result<()> {
+    use std::time::Duration;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    let metrics = conn.metrics();
+
+    // connection setup (`PRAGMA` initialization) already counts as activity;
+    // start from a clean slate
+    metrics.reset();
+
+    assert_eq!(metrics.query_count(), 0);
+    assert!(metrics.last_activity().is_none());
+
+    for _ in 0..3 {
+        conn.execute("SELECT 1 + 1").await?;
+    }
+
+    assert_eq!(metrics.query_count(), 3);
+    assert!(metrics.total_query_time() > Duration::ZERO);
+    assert!(metrics.last_activity().is_some());
+
+    // the counters can be cleared without reconnecting
+    metrics.reset();
+
+    assert_eq!(metrics.query_count(), 0);
+    assert!(metrics.last_activity().is_none());
+
+    conn.execute("SELECT 2 + 2").await?;
+
+    assert_eq!(metrics.query_count(), 1);
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_round_trips_fixed_size_byte_arrays() -> anyhow::Result<()> {
+    let mut conn = new::<Sqlite>().await?;
+
+    let digest: [u8; 16] = [
+        0x00, 0x11, 0x22, 0x33, 0x44, 0x55, 0x66, 0x77, 0x88, 0x99, 0xAA, 0xBB, 0xCC, 0xDD, 0xEE,
+        0xFF,
+    ];
+
+    let row = sqlx::query("SELECT ?1").bind(digest).fetch_one(&mut conn).await?;
+    let returned: [u8; 16] = row.try_get(0)?;
+
+    assert_eq!(returned, digest);
+
+    // decoding a blob of the wrong length is an error, not a truncation
+    let row = sqlx::query("SELECT x'DEADBEEF'").fetch_one(&mut conn).await?;
+    let err = row.try_get::<[u8; 16], _>(0).unwrap_err();
+
+    assert!(
+        err.to_string().contains("mismatched lengths"),
+        "unexpected error: {}",
+        err
+    );
+
+    Ok(())
+}
+
+#[sqlx_macros::test]
+async fn it_applies_worker_thread_name_and_stack_size() -> anyhow::Result<()> {
+    use sqlx::sqlite::SqliteAuthorization;
+    use std::str::FromStr;
+    use std::sync::{Arc, Mutex};
+
+    let mut conn = SqliteConnectOptions::from_str("sqlite://:memory:")?
+        .worker_thread_name("sqlx-named-worker")
+        .worker_stack_size(8 * 1024 * 1024)
+        .connect()
+        .await?;
+
+    // the authorizer runs on the worker thread during prepare, which lets us
+    // observe the thread's name
+    let name = Arc::new(Mutex::new(None));
+    let captured = Arc::clone(&name);
+
+    conn.set_authorizer(move |_| {
+        *captured.lock().unwrap() = std::thread::current().name().map(String::from);
+        SqliteAuthorization::Allow
+    })?;
+
+    conn.execute("SELECT 1").await?;
+
+    assert_eq!(name.lock().unwrap().as_deref(), Some("sqlx-named-worker"));
+
+    conn.remove_authorizer()?;
+
+    // a deep recursive CTE still executes on the enlarged stack
+    let depth: i64 = sqlx::query_scalar(
+        "WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c WHERE x < 100000) \
+         SELECT max(x) FROM c",
+    )
+    .fetch_one(&mut conn)
+    .await?;
+
+    assert_eq!(depth, 100_000);
+
+    Ok(())
+}
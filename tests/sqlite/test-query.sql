@@ -0,0 +1 @@
+select id, name, is_active from accounts where id = ?
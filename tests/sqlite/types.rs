@@ -1,7 +1,10 @@
 use sqlx::sqlite::{Sqlite, SqliteRow};
 use sqlx_core::row::Row;
 use sqlx_test::new;
-use sqlx_test::test_type;
+use sqlx_test::{test_decode_type, test_type};
+
+#[cfg(feature = "decimal")]
+use std::str::FromStr;
 
 test_type!(null<Option<i32>>(Sqlite,
     "NULL" == None::<i32>
@@ -131,6 +134,19 @@ mod git2 {
     ));
 }
 
+test_type!(ipaddr<std::net::IpAddr>(Sqlite,
+    "'127.0.0.1'" == "127.0.0.1".parse::<std::net::IpAddr>().unwrap(),
+    "'2001:db8::1'" == "2001:db8::1".parse::<std::net::IpAddr>().unwrap(),
+));
+
+test_type!(ipv4addr<std::net::Ipv4Addr>(Sqlite,
+    "'127.0.0.1'" == "127.0.0.1".parse::<std::net::Ipv4Addr>().unwrap(),
+));
+
+test_type!(ipv6addr<std::net::Ipv6Addr>(Sqlite,
+    "'2001:db8::1'" == "2001:db8::1".parse::<std::net::Ipv6Addr>().unwrap(),
+));
+
 #[cfg(feature = "uuid")]
 test_type!(uuid<sqlx::types::Uuid>(Sqlite,
     "x'b731678f636f4135bc6f19440c13bd19'"
@@ -139,6 +155,58 @@ test_type!(uuid<sqlx::types::Uuid>(Sqlite,
         == sqlx::types::Uuid::parse_str("00000000-0000-0000-0000-000000000000").unwrap()
 ));
 
+// filesystem paths round-trip through TEXT via a lossy UTF-8 conversion
+test_type!(path_buf<std::path::PathBuf>(Sqlite,
+    "'/tmp/foo.txt'" == std::path::PathBuf::from("/tmp/foo.txt"),
+));
+
+// smart pointers to `str` decode without an intermediate `String`
+test_decode_type!(box_str<Box<str>>(Sqlite, "'foo'" == Box::<str>::from("foo")));
+
+test_decode_type!(arc_str<std::sync::Arc<str>>(Sqlite,
+    "'shared'" == std::sync::Arc::<str>::from("shared"),
+));
+
+#[sqlx_macros::test]
+async fn test_arc_str_is_shareable() -> anyhow::Result<()> {
+    use sqlx::Row;
+    use std::sync::Arc;
+
+    let mut conn = new::<Sqlite>().await?;
+
+    let row = sqlx::query("SELECT 'cached'").fetch_one(&mut conn).await?;
+    let value: Arc<str> = row.try_get(0)?;
+    let shared = Arc::clone(&value);
+    drop(row);
+
+    assert_eq!(&*shared, "cached");
+    assert_eq!(Arc::strong_count(&shared), 2);
+
+    Ok(())
+}
+
+// stored as TEXT; the canonical string form round-trips exactly, scale included
+#[cfg(feature = "decimal")]
+test_type!(decimal<sqlx::types::Decimal>(Sqlite,
+    "'0'" == sqlx::types::Decimal::from_str("0").unwrap(),
+    "'123.456000'" == sqlx::types::Decimal::from_str("123.456000").unwrap(),
+    "'-9999999.99'" == sqlx::types::Decimal::from_str("-9999999.99").unwrap(),
+));
+
+// INTEGER decodes exactly; REAL is a lossy best-effort conversion
+#[cfg(feature = "decimal")]
+test_decode_type!(decimal_from_number<sqlx::types::Decimal>(Sqlite,
+    "12345" == sqlx::types::Decimal::from_str("12345").unwrap(),
+    "1.5" == sqlx::types::Decimal::from_str("1.5").unwrap(),
+));
+
+// a `Uuid` also decodes from hyphenated TEXT storage
+#[cfg(feature = "uuid")]
+test_decode_type!(uuid_from_text<sqlx::types::Uuid>(Sqlite,
+    "'b731678f-636f-4135-bc6f-19440c13bd19'"
+        == sqlx::types::Uuid::parse_str("b731678f-636f-4135-bc6f-19440c13bd19").unwrap()
+));
+
 #[cfg(feature = "uuid")]
 test_type!(uuid_hyphenated<sqlx::types::uuid::adapter::Hyphenated>(Sqlite,
     "'b731678f-636f-4135-bc6f-19440c13bd19'"
@@ -0,0 +1,8 @@
+fn main() {
+    let _ = sqlx::query!(
+        "select id from tweet where id between ? and ?",
+        1i64,
+        2i64,
+        3i64
+    );
+}